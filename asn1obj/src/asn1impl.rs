@@ -2,6 +2,10 @@
 use std::io::{Write};
 use std::error::Error;
 use serde_json;
+use crate::ctx::{Asn1DecodeCtx,Asn1DecodeCtxGuard,Asn1EncodeCtx,Asn1EncodeCtxGuard,Asn1DecodeDepthGuard,asn1obj_is_preserve,asn1obj_is_lossless_string,asn1obj_get_encode_ctx};
+use crate::errors::{asn1obj_error_shift,asn1obj_error_field};
+use crate::dump::{Asn1FieldRecordGuard};
+use crate::base::{asn1obj_tag_len,asn1obj_extract_header};
 
 pub trait Asn1Op {
 	fn equal_asn1(&self, other :&Self) -> bool {
@@ -30,6 +34,208 @@ pub trait Asn1Op {
 	fn init_asn1() -> Self;
 	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>>;
 	fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>>;
+
+	/*
+	    decode from code[offset..] and the error offset is from the start of code
+	    the nested depth is checked with max_depth in the decode context
+	*/
+	fn decode_asn1_at(&mut self,code :&[u8], offset :usize) -> Result<usize,Box<dyn Error>> {
+		let gres = Asn1DecodeDepthGuard::enter();
+		if gres.is_err() {
+			return Err(asn1obj_error_shift(gres.err().unwrap(),offset));
+		}
+		let _guard = gres.unwrap();
+		let ores = self.decode_asn1(&code[offset..]);
+		if ores.is_err() {
+			return Err(asn1obj_error_shift(ores.err().unwrap(),offset));
+		}
+		return ores;
+	}
+
+	/*
+	    decode_asn1_at for the field fname in type tname, the error gets the field path
+	*/
+	fn decode_asn1_field(&mut self,code :&[u8], offset :usize, tname :&str, fname :&str) -> Result<usize,Box<dyn Error>> {
+		let _rec = Asn1FieldRecordGuard::enter(&code[offset..],fname);
+		let ores = self.decode_asn1_at(code,offset);
+		if ores.is_err() {
+			return Err(asn1obj_error_field(ores.err().unwrap(),tname,fname));
+		}
+		return ores;
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		let code = self.encode_asn1()?;
+		Ok(code.len())
+	}
+
+	/*
+	    the length of the identifier octets given by write_asn1,
+	    for the tagged wrapper to get its length without writing
+	*/
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		let code = self.encode_asn1()?;
+		if code.len() == 0 {
+			return Ok(0);
+		}
+		return asn1obj_tag_len(&code);
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		let code = self.encode_asn1()?;
+		iowriter.write_all(&code)?;
+		Ok(code.len())
+	}
+
+	fn decode_asn1_ctx(&mut self,code :&[u8], ctx :&Asn1DecodeCtx) -> Result<usize,Box<dyn Error>> {
+		let _guard = Asn1DecodeCtxGuard::new(ctx);
+		return self.decode_asn1(code);
+	}
+
+	fn decode_asn1_der(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+		return self.decode_asn1_ctx(code,&Asn1DecodeCtx::der());
+	}
+
+	fn encode_asn1_ctx(&self, ctx :&Asn1EncodeCtx) -> Result<Vec<u8>,Box<dyn Error>> {
+		let _guard = Asn1EncodeCtxGuard::new(ctx);
+		return self.encode_asn1();
+	}
+
+	fn encode_asn1_cer(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		return self.encode_asn1_ctx(&Asn1EncodeCtx::cer());
+	}
+
+	/*
+	    the octets consumed by the last decode_asn1, None when not decoded
+	    the signature over a BER encoded value is checked with these octets
+	*/
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		return None;
+	}
+
+	/*
+	    in the preserve encode context, give the raw octets when the value
+	    is the same as decoded from them, otherwise None to encode the value
+	    raw is decoded in the default context, it is what the value was decoded from
+	    whatever the decode context is now
+	*/
+	fn preserved_asn1(&self) -> Result<Option<Vec<u8>>,Box<dyn Error>> where Self :Sized {
+		if !asn1obj_is_preserve() {
+			return Ok(None);
+		}
+		let ores = self.get_raw_asn1();
+		if ores.is_none() {
+			return Ok(None);
+		}
+		let raw = ores.unwrap();
+		let mut ctx = asn1obj_get_encode_ctx();
+		ctx.preserve = false;
+		let _guard = Asn1EncodeCtxGuard::new(&ctx);
+		let curv = self.encode_asn1()?;
+		let mut dctx = Asn1DecodeCtx::new();
+		dctx.lossless_string = asn1obj_is_lossless_string();
+		let _dguard = Asn1DecodeCtxGuard::new(&dctx);
+		let mut origv :Self = Self::init_asn1();
+		let ores = origv.decode_asn1(&raw);
+		if ores.is_err() {
+			return Ok(None);
+		}
+		let origcode = origv.encode_asn1()?;
+		if origcode != curv {
+			return Ok(None);
+		}
+		return Ok(Some(raw));
+	}
+
+	/*
+	    for the constructed value, code is encoded with the children preserved,
+	    so the raw octets are given back when code has the same identifier and contents,
+	    no decode of raw again at every level
+	*/
+	fn preserved_constructed(&self, code :Vec<u8>) -> Result<Vec<u8>,Box<dyn Error>> {
+		if !asn1obj_is_preserve() {
+			return Ok(code);
+		}
+		let ores = self.get_raw_asn1();
+		if ores.is_none() {
+			return Ok(code);
+		}
+		let raw = ores.unwrap();
+		if asn1obj_same_contents(&raw,&code) {
+			return Ok(raw);
+		}
+		return Ok(code);
+	}
+
+	fn encode_asn1_preserve(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		return self.encode_asn1_ctx(&Asn1EncodeCtx::preserve());
+	}
+}
+
+/*
+    the identifier and the contents octets of one value,
+    code is the whole value, so the indefinite contents end before its end-of-contents
+*/
+fn asn1obj_value_contents(code :&[u8]) -> Option<(u64,&[u8])> {
+	let ores = asn1obj_extract_header(code);
+	if ores.is_err() {
+		return None;
+	}
+	let (flag,hdrlen,mut totallen) = ores.unwrap();
+	if totallen == 0 && code[hdrlen - 1] == 0x80 {
+		if code.len() < (hdrlen + 2) || code[code.len() - 2] != 0x0 || code[code.len() - 1] != 0x0 {
+			return None;
+		}
+		totallen = code.len() - hdrlen - 2;
+	}
+	if code.len() < (hdrlen + totallen) {
+		return None;
+	}
+	return Some((flag,&code[hdrlen..(hdrlen + totallen)]));
+}
+
+fn asn1obj_same_contents(raw :&[u8], code :&[u8]) -> bool {
+	/*headers are read in the default context, raw may be BER*/
+	let ctx = Asn1DecodeCtx::new();
+	let _guard = Asn1DecodeCtxGuard::new(&ctx);
+	let rres = asn1obj_value_contents(raw);
+	let cres = asn1obj_value_contents(code);
+	if rres.is_none() || cres.is_none() {
+		return false;
+	}
+	return rres.unwrap() == cres.unwrap();
+}
+
+/*
+    borrowed decoding which keep the slice of the input
+*/
+pub trait Asn1RefOp<'a> {
+	fn decode_asn1(&mut self,code :&'a [u8]) -> Result<usize,Box<dyn Error>>;
+	fn encode_asn1(&self) -> Result<Vec<u8>, Box<dyn Error>>;
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>>;
+	fn init_asn1() -> Self;
+
+	fn decode_asn1_at(&mut self,code :&'a [u8], offset :usize) -> Result<usize,Box<dyn Error>> {
+		let gres = Asn1DecodeDepthGuard::enter();
+		if gres.is_err() {
+			return Err(asn1obj_error_shift(gres.err().unwrap(),offset));
+		}
+		let _guard = gres.unwrap();
+		let ores = self.decode_asn1(&code[offset..]);
+		if ores.is_err() {
+			return Err(asn1obj_error_shift(ores.err().unwrap(),offset));
+		}
+		return ores;
+	}
+
+	fn decode_asn1_field(&mut self,code :&'a [u8], offset :usize, tname :&str, fname :&str) -> Result<usize,Box<dyn Error>> {
+		let _rec = Asn1FieldRecordGuard::enter(&code[offset..],fname);
+		let ores = self.decode_asn1_at(code,offset);
+		if ores.is_err() {
+			return Err(asn1obj_error_field(ores.err().unwrap(),tname,fname));
+		}
+		return ores;
+	}
 }
 
 pub trait Asn1Selector {
@@ -6,8 +6,12 @@ use chrono::prelude::*;
 use crate::asn1impl::{Asn1Op};
 //use crate::consts::{ASN1_PRIMITIVE_TAG,ASN1_CONSTRUCTED,ASN1_INTEGER_FLAG,ASN1_BOOLEAN_FLAG,ASN1_MAX_INT,ASN1_MAX_LONG,ASN1_MAX_INT_1,ASN1_MAX_INT_2,ASN1_MAX_INT_3,ASN1_MAX_INT_4,ASN1_MAX_INT_NEG_1,ASN1_MAX_INT_NEG_2,ASN1_MAX_INT_NEG_3,ASN1_MAX_INT_NEG_4,ASN1_MAX_INT_NEG_5,ASN1_MAX_INT_5,ASN1_BIT_STRING_FLAG,ASN1_OCT_STRING_FLAG,ASN1_NULL_FLAG,ASN1_OBJECT_FLAG,ASN1_ENUMERATED_FLAG,ASN1_UTF8STRING_FLAG,ASN1_PRINTABLE_FLAG,ASN1_UTCTIME_FLAG,ASN1_GENERALTIME_FLAG,ASN1_TIME_DEFAULT_STR,ASN1_OBJECT_DEFAULT_STR,ASN1_PRINTABLE2_FLAG};
 use crate::consts::*;
+use crate::ctx::{asn1obj_is_oid_names,asn1obj_is_der,asn1obj_is_strict_string,asn1obj_is_lossless_string,asn1obj_is_cer,asn1obj_write_by_encode,asn1obj_check_content,Asn1DecodeDepthGuard};
+use crate::errors::{asn1obj_error_shift,asn1obj_error_at};
+use crate::node::{Asn1Node};
+use crate::oids::{asn1obj_oid_to_name,asn1obj_name_to_oid};
 use crate::strop::{asn1_format_line};
-use crate::{asn1obj_error_class,asn1obj_new_error};
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
 
 use std::io::{Write};
 
@@ -20,23 +24,98 @@ use serde_json;
 
 use std::str::FromStr;
 use std::ops::Shr;
-use num_bigint::{BigUint};
+use num_bigint::{BigUint,BigInt,Sign};
 use num_traits::{Zero};
 use std::cmp::PartialEq;
 
 
 asn1obj_error_class!{Asn1ObjBaseError}
 
+/*
+    tag value layout:
+        number < 31  : the identifier octet itself (class | constructed | number)
+        number >= 31 : (number << ASN1_HIGH_TAG_SHIFT) | (class | constructed | 0x1f)
+*/
+pub fn asn1obj_make_tag(flag :u8, number :u64) -> u64 {
+    let fl :u8 = flag & ASN1_IMP_FILTER_MASK;
+    if number < (ASN1_PRIMITIVE_TAG as u64) {
+        return (fl as u64) | number;
+    }
+    return (number << ASN1_HIGH_TAG_SHIFT) | ((fl | ASN1_PRIMITIVE_TAG) as u64);
+}
+
+pub fn asn1obj_tag_number(tag :u64) -> u64 {
+    if tag > 0xff {
+        return tag >> ASN1_HIGH_TAG_SHIFT;
+    }
+    return tag & (ASN1_PRIMITIVE_TAG as u64);
+}
+
+pub fn asn1obj_tag_flag(tag :u64) -> u8 {
+    return ((tag & 0xff) as u8) & ASN1_IMP_FILTER_MASK;
+}
+
+pub fn asn1obj_format_tag(tag :u64) -> Vec<u8> {
+    let mut retv :Vec<u8> = Vec::new();
+    let mut number :u64;
+    let mut stk :Vec<u8> = Vec::new();
+    if (tag & 0xff) == tag {
+        retv.push((tag & 0xff) as u8);
+        return retv;
+    }
+    retv.push(asn1obj_tag_flag(tag) | ASN1_PRIMITIVE_TAG);
+    number = asn1obj_tag_number(tag);
+    stk.push((number & 0x7f) as u8);
+    number >>= 7;
+    while number > 0 {
+        stk.push(((number & 0x7f) as u8) | 0x80);
+        number >>= 7;
+    }
+    while stk.len() > 0 {
+        retv.push(stk.pop().unwrap());
+    }
+    return retv;
+}
+
+pub fn asn1obj_tag_len(code :&[u8]) -> Result<usize,Box<dyn Error>> {
+    let mut llen :usize = 1;
+    if code.len() < 1 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"len [{}] < 1", code.len()}
+    }
+    if (code[0] & ASN1_PRIMITIVE_TAG) != ASN1_PRIMITIVE_TAG {
+        return Ok(llen);
+    }
+    while llen < code.len() && (code[llen] & 0x80) != 0 {
+        llen += 1;
+    }
+    if llen >= code.len() {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"expose [{}] len", code.len()}
+    }
+    llen += 1;
+    Ok(llen)
+}
+
+pub fn asn1obj_replace_tag(code :&[u8], tag :u64) -> Result<Vec<u8>,Box<dyn Error>> {
+    let llen :usize = asn1obj_tag_len(code)?;
+    let mut retv :Vec<u8> = asn1obj_format_tag(tag);
+    for i in llen..code.len() {
+        retv.push(code[i]);
+    }
+    Ok(retv)
+}
+
 
 pub fn asn1obj_extract_header(code :&[u8]) -> Result<(u64,usize,usize),Box<dyn Error>> {
     let flag :u64;
     let mut totallen :usize = 0;
     let mut i :u64;
     let mut llen :usize = 0;
+    let mut tlen :u64;
     let inf :i32;
     let ret :u8;
+    let der :bool = asn1obj_is_der();
     if code.len() < 2 {
-        asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
     }
 
     i = (code[llen]  & ASN1_PRIMITIVE_TAG) as u64;
@@ -44,7 +123,10 @@ pub fn asn1obj_extract_header(code :&[u8]) -> Result<(u64,usize,usize),Box<dyn E
     if i == ASN1_PRIMITIVE_TAG  as u64 {
         llen += 1;
         if code.len() <= llen {
-            asn1obj_new_error!{Asn1ObjBaseError,"expose [{}] len", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"expose [{}] len", code.len()}
+        }
+        if der && code[llen] == 0x80 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER tag number with leading 0x80 octet"}
         }
         i = 0;
         while (code[llen] & 0x80) != 0x0 {
@@ -52,62 +134,78 @@ pub fn asn1obj_extract_header(code :&[u8]) -> Result<(u64,usize,usize),Box<dyn E
             i += (code[llen] & 0x7f) as u64;
             llen += 1;
             if code.len() <= llen {
-                asn1obj_new_error!{Asn1ObjBaseError,"expose [{}] len", code.len()}      
+                asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"expose [{}] len", code.len()}      
             }
-            if i > (ASN1_MAX_INT  >> 7) {
-                asn1obj_new_error!{Asn1ObjBaseError,"[0x{:08x}] expose [0x{:08x}]", i, ASN1_MAX_INT}
+            if i > (ASN1_HIGH_TAG_MAX  >> 7) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[0x{:08x}] expose [0x{:08x}]", i, ASN1_HIGH_TAG_MAX}
             }
         }
         i <<= 7;
         i += (code[llen] & 0x7f) as u64;
         llen += 1;
-        flag = i;
+        if der && i < (ASN1_PRIMITIVE_TAG as u64) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER tag number [{}] must in low tag form", i}
+        }
+        flag = (i << ASN1_HIGH_TAG_SHIFT) | (code[0] as u64);
     } else {
         flag = code[0] as u64;
         llen += 1;
     }
 
     if code.len() <= llen {
-        asn1obj_new_error!{Asn1ObjBaseError,"expose [{}] len", code.len()}
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"expose [{}] len", code.len()}
     }
 
     if code[llen] == 0x80 {
+        if der {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER not allow indefinite length"}
+        }
         inf = 1;
         llen += 1;
     } else {
         inf = 0;
         i = (code[llen] & 0x7f) as u64;
         if (code[llen] & 0x80) != 0 {
+            if code[llen] == 0xff {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"reserved length [0x{:02x}]", code[llen]}
+            }
 
             if code.len() <= (llen + (i as usize)) {
-                asn1obj_new_error!{Asn1ObjBaseError,"llen [0x{:08x}] + [0x{:08x}] >= [0x{:08x}]", llen, i, code.len()}
+                asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"llen [0x{:08x}] + [0x{:08x}] >= [0x{:08x}]", llen, i, code.len()}
             }
             /*skip this one*/
             i -= 1;
             llen += 1;
+            if der && code[llen] == 0x0 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER length with leading zero octet"}
+            }
             while i > 0 && code[llen] == 0x0 {
                 llen += 1;
                 i -= 1;
             }
 
-            if i > 4 {
-                asn1obj_new_error!{Asn1ObjBaseError,"left [{}] > 4", i}
+            if i > 7 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"left [{}] > 7", i}
             }
-            totallen = 0;
+            tlen = 0;
             while i > 0 {
-                totallen <<= 8;
-                totallen += (code[llen]) as usize;
+                tlen <<= 8;
+                tlen += (code[llen]) as u64;
                 asn1obj_log_trace!("code[{}]=[0x{:02x}]",llen,code[llen]);
                 llen += 1;
                 i -= 1;
             }
             /*to add last one*/
-            totallen <<= 8;
-            totallen += (code[llen]) as usize;
+            tlen <<= 8;
+            tlen += (code[llen]) as u64;
             llen += 1;
 
-            if totallen > ASN1_MAX_LONG as usize {
-                asn1obj_new_error!{Asn1ObjBaseError,"totallen [0x{:x}] > [0x{:x}]", totallen, ASN1_MAX_LONG}
+            if tlen > ((usize::MAX - llen) as u64) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [0x{:x}] + [0x{:x}] overflow", tlen, llen}
+            }
+            totallen = tlen as usize;
+            if der && tlen < 0x80 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER length [{}] must in short form", tlen}
             }
         } else {
             totallen = i as usize;
@@ -116,52 +214,438 @@ pub fn asn1obj_extract_header(code :&[u8]) -> Result<(u64,usize,usize),Box<dyn E
     }
 
     if inf != 0 && (ret & ASN1_CONSTRUCTED) == 0 {
-        asn1obj_new_error!{Asn1ObjBaseError,"inf [{}] ASN1_CONSTRUCTED not", inf}
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"inf [{}] ASN1_CONSTRUCTED not", inf}
     }
+    asn1obj_check_content(totallen)?;
     asn1obj_log_trace!("flag [0x{:02x}] llen [0x{:x}] totallen [0x{:x}]", flag, llen,totallen);
     Ok((flag,llen,totallen))
 }
 
-pub fn asn1obj_format_header(tag :u64, length :u64) -> Vec<u8> {
-    let mut retv :Vec<u8> = Vec::new();
-    if (tag & 0xff) == tag {
-        retv.push((tag & 0xff) as u8);
+/*
+    return (flag,hdrlen,totallen,eoclen)
+    for indefinite length totallen is the content length before the end-of-contents octets
+    and eoclen is 2, for definite length eoclen is 0
+*/
+pub fn asn1obj_extract_header_inf(code :&[u8]) -> Result<(u64,usize,usize,usize),Box<dyn Error>> {
+    let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+    if totallen != 0 || code[hdrlen - 1] != 0x80 {
+        return Ok((flag,hdrlen,totallen,0));
+    }
+    let ores = asn1obj_find_eoc(&code[hdrlen..]);
+    if ores.is_err() {
+        return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+    }
+    let clen = ores.unwrap();
+    asn1obj_check_content(clen)?;
+    asn1obj_log_trace!("flag [0x{:02x}] inf hdrlen [0x{:x}] contentlen [0x{:x}]", flag, hdrlen,clen);
+    Ok((flag,hdrlen,clen,2))
+}
+
+/*
+    return the offset of the end-of-contents octets matched the level of code
+*/
+pub fn asn1obj_find_eoc(code :&[u8]) -> Result<usize,Box<dyn Error>> {
+    let mut pos :usize = 0;
+    let mut level :usize = 0;
+    loop {
+        if code.len() < (pos + 2) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"no end-of-contents found in [{}] len", code.len()}
+        }
+        if code[pos] == 0x0 && code[pos+1] == 0x0 {
+            if level == 0 {
+                return Ok(pos);
+            }
+            level -= 1;
+            pos += 2;
+            continue;
+        }
+        let ores = asn1obj_extract_header(&code[pos..]);
+        if ores.is_err() {
+            return Err(asn1obj_error_shift(ores.err().unwrap(),pos));
+        }
+        let (_,hdrlen,totallen) = ores.unwrap();
+        if totallen == 0 && code[pos + hdrlen - 1] == 0x80 {
+            level += 1;
+            pos += hdrlen;
+        } else {
+            if (code.len() - pos) < (hdrlen + totallen) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len() - pos,hdrlen,totallen}
+            }
+            pos += hdrlen + totallen;
+        }
+    }
+}
+
+/*
+    the segments of BIT STRING are in BIT STRING, and the segments of
+    the other strings are in OCTET STRING (X.690 8.23.6)
+*/
+fn asn1obj_segment_flag(primflag :u8) -> u8 {
+    if primflag == ASN1_BIT_STRING_FLAG {
+        return ASN1_BIT_STRING_FLAG;
+    }
+    return ASN1_OCT_STRING_FLAG;
+}
+
+fn asn1obj_collect_segments(code :&[u8], primflag :u8, content :&mut Vec<u8>, unused :&mut u8) -> Result<usize,Box<dyn Error>> {
+    let _guard = Asn1DecodeDepthGuard::enter()?;
+    let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
+    let mut retv :usize;
+    if code.len() < (hdrlen + totallen) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    }
+    if flag == primflag as u64 {
+        asn1obj_check_content(content.len() + totallen)?;
+        if primflag == ASN1_BIT_STRING_FLAG {
+            if totallen < 1 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] < 1", totallen}
+            }
+            if *unused != 0 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"unused bits [{}] not in last segment", *unused}
+            }
+            *unused = code[hdrlen];
+            for i in 1..totallen {
+                content.push(code[hdrlen + i]);
+            }
+        } else {
+            for i in 0..totallen {
+                content.push(code[hdrlen + i]);
+            }
+        }
+        return Ok(hdrlen + totallen);
+    }
+
+    if flag != (primflag | ASN1_CONSTRUCTED) as u64 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != [0x{:02x}]", flag, primflag | ASN1_CONSTRUCTED}
+    }
+    retv = hdrlen;
+    while retv < (hdrlen + totallen) {
+        let ores = asn1obj_collect_segments(&code[retv..(hdrlen + totallen)],asn1obj_segment_flag(primflag),content,unused);
+        if ores.is_err() {
+            return Err(asn1obj_error_shift(ores.err().unwrap(),retv));
+        }
+        retv += ores.unwrap();
+    }
+    retv += eoclen;
+    Ok(retv)
+}
+
+/*
+    to make the constructed (segmented) string into primitive form
+    return (primitive encoded,consumed length)
+*/
+pub fn asn1obj_flatten_string(code :&[u8], primflag :u8) -> Result<(Vec<u8>,usize),Box<dyn Error>> {
+    let mut content :Vec<u8> = Vec::new();
+    let mut unused :u8 = 0;
+    let mut retv :Vec<u8>;
+    if asn1obj_is_der() {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER not allow constructed string [0x{:02x}]", primflag | ASN1_CONSTRUCTED}
+    }
+    let clen = asn1obj_collect_segments(code,primflag,&mut content,&mut unused)?;
+    if primflag == ASN1_BIT_STRING_FLAG {
+        retv = asn1obj_format_header(primflag as u64, (content.len() + 1) as u64)?;
+        retv.push(unused);
+    } else {
+        retv = asn1obj_format_header(primflag as u64, content.len() as u64)?;
+    }
+    for i in 0..content.len() {
+        retv.push(content[i]);
+    }
+    Ok((retv,clen))
+}
+
+pub fn asn1obj_der_check_integer(content :&[u8]) -> Result<(),Box<dyn Error>> {
+    if content.len() < 1 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER integer with no content"}
+    }
+    if content.len() > 1 {
+        if content[0] == 0x0 && (content[1] & 0x80) == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER integer not minimal with leading 0x00"}
+        }
+        if content[0] == 0xff && (content[1] & 0x80) != 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER integer not minimal with leading 0xff"}
+        }
+    }
+    Ok(())
+}
+
+/*content include the first unused bits octet*/
+pub fn asn1obj_der_check_bits(content :&[u8]) -> Result<(),Box<dyn Error>> {
+    if content.len() < 1 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER bit string with no content"}
+    }
+    if content[0] > 7 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER bit string unused bits [{}] > 7", content[0]}
+    }
+    if content.len() == 1 && content[0] != 0 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER empty bit string unused bits [{}] != 0", content[0]}
+    }
+    if content.len() > 1 {
+        let mask :u8 = ((1 << content[0]) - 1) as u8;
+        if (content[content.len() - 1] & mask) != 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER bit string unused bits [{}] not zero in [0x{:02x}]", content[0],content[content.len() - 1]}
+        }
+    }
+    Ok(())
+}
+
+/*
+    compare for SET OF order in DER, the shorter one is padded with trailing zero octets
+*/
+pub fn asn1obj_der_cmp(a :&[u8], b :&[u8]) -> std::cmp::Ordering {
+    let mut idx :usize = 0;
+    let mut ac :u8;
+    let mut bc :u8;
+    while idx < a.len() || idx < b.len() {
+        ac = 0;
+        bc = 0;
+        if idx < a.len() {
+            ac = a[idx];
+        }
+        if idx < b.len() {
+            bc = b[idx];
+        }
+        if ac != bc {
+            return ac.cmp(&bc);
+        }
+        idx += 1;
+    }
+    return std::cmp::Ordering::Equal;
+}
+
+/*
+    to change the definite length constructed encoded into indefinite length for CER
+*/
+pub fn asn1obj_cer_constructed(code :&[u8]) -> Result<Vec<u8>,Box<dyn Error>> {
+    let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+    let mut retv :Vec<u8>;
+    if (code[0] & ASN1_CONSTRUCTED) == 0 || (totallen == 0 && code[hdrlen - 1] == 0x80) {
+        return Ok(code.to_vec());
+    }
+    if code.len() < (hdrlen + totallen) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    }
+    retv = asn1obj_format_tag(flag);
+    retv.push(0x80);
+    for i in hdrlen..(hdrlen + totallen) {
+        retv.push(code[i]);
+    }
+    retv.push(0x0);
+    retv.push(0x0);
+    Ok(retv)
+}
+
+/*
+    to decode the string content strictly by the tag flag,
+    the error offset is the invalid octet in content
+*/
+pub(crate) fn asn1obj_strict_string(flag :u8, content :&[u8]) -> Result<String,Box<dyn Error>> {
+    if flag == ASN1_UTF8STRING_FLAG {
+        let ores = std::str::from_utf8(content);
+        if ores.is_err() {
+            let e = ores.err().unwrap();
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,e.valid_up_to(),"invalid utf8 at [{}]", e.valid_up_to()}
+        }
+        return Ok(ores.unwrap().to_string());
+    } else if flag == ASN1_PRINTABLE_FLAG {
+        for i in 0..content.len() {
+            let c :u8 = content[i];
+            if !c.is_ascii_alphanumeric() && !b" '()+,-./:=?".contains(&c) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,i,"[0x{:02x}] at [{}] not printable", c, i}
+            }
+        }
+    } else if flag == ASN1_PRINTABLE2_FLAG {
+        for i in 0..content.len() {
+            if content[i] >= 0x80 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,i,"[0x{:02x}] at [{}] not ia5", content[i], i}
+            }
+        }
+    } else if flag == ASN1_T61STRING_FLAG {
+        return Asn1TeletexString::decode_chars(content);
+    }
+    return Ok(String::from_utf8_lossy(content).to_string());
+}
+
+/*
+    whether val is the string decoded from bytes, by utf8 or by latin1
+*/
+fn asn1obj_string_bytes_match(bytes :&[u8], val :&str) -> bool {
+    if bytes.len() == 0 {
+        return false;
+    }
+    if String::from_utf8_lossy(bytes) == val {
+        return true;
+    }
+    let latin :String = bytes.iter().map(|b| *b as char).collect();
+    return latin == val;
+}
+
+/*
+    the content octets of the last decode, they are kept even when
+    the strict_string decode rejects them, so nothing of the input is lost.
+    the utf8 of val is given when val is changed since decode
+*/
+fn asn1obj_string_get_bytes(bytes :&[u8], val :&str) -> Vec<u8> {
+    if asn1obj_string_bytes_match(bytes,val) {
+        return bytes.to_vec();
+    }
+    return val.as_bytes().to_vec();
+}
+
+/*
+    to split the primitive string encoded into ASN1_CER_SEGMENT_SIZE segments for CER
+*/
+pub fn asn1obj_cer_string(code :&[u8]) -> Result<Vec<u8>,Box<dyn Error>> {
+    let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+    let mut retv :Vec<u8>;
+    let mut pos :usize;
+    let mut clen :usize;
+    let unused :u8;
+    if (code[0] & ASN1_CONSTRUCTED) != 0 || totallen <= ASN1_CER_SEGMENT_SIZE {
+        return Ok(code.to_vec());
+    }
+    if code.len() < (hdrlen + totallen) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    }
+    retv = asn1obj_format_tag(flag | (ASN1_CONSTRUCTED as u64));
+    retv.push(0x80);
+    if flag == ASN1_BIT_STRING_FLAG as u64 {
+        unused = code[hdrlen];
+        pos = hdrlen + 1;
+        while pos < (hdrlen + totallen) {
+            clen = hdrlen + totallen - pos;
+            if clen > (ASN1_CER_SEGMENT_SIZE - 1) {
+                clen = ASN1_CER_SEGMENT_SIZE - 1;
+            }
+            let hdr = asn1obj_format_header(flag,(clen + 1) as u64)?;
+            retv.extend_from_slice(&hdr);
+            if (pos + clen) == (hdrlen + totallen) {
+                retv.push(unused);
+            } else {
+                retv.push(0x0);
+            }
+            retv.extend_from_slice(&code[pos..(pos+clen)]);
+            pos += clen;
+        }
     } else {
-        retv.push(0x0);
+        pos = hdrlen;
+        while pos < (hdrlen + totallen) {
+            clen = hdrlen + totallen - pos;
+            if clen > ASN1_CER_SEGMENT_SIZE {
+                clen = ASN1_CER_SEGMENT_SIZE;
+            }
+            let hdr = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,clen as u64)?;
+            retv.extend_from_slice(&hdr);
+            retv.extend_from_slice(&code[pos..(pos+clen)]);
+            pos += clen;
+        }
+    }
+    retv.push(0x0);
+    retv.push(0x0);
+    Ok(retv)
+}
+
+pub fn asn1obj_format_header(tag :u64, length :u64) -> Result<Vec<u8>,Box<dyn Error>> {
+    let mut retv :Vec<u8>;
+    let mut cnt :usize = 0;
+    let mut clen :u64 = length;
+    if tag > 0xff && (tag & (ASN1_PRIMITIVE_TAG as u64)) != (ASN1_PRIMITIVE_TAG as u64) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"tag [0x{:x}] not valid high tag", tag}
     }
+    retv = asn1obj_format_tag(tag);
     if length < ASN1_MAX_INT_NEG_1 {
         retv.push((length & 0xff) as u8);
-    } else if length <= ASN1_MAX_INT_1 {
-        retv.push(0x81);
-        retv.push((length & 0xff) as u8);       
-    } else if length <= ASN1_MAX_INT_2 {
-        retv.push(0x82);
-        retv.push(((length >> 8) & 0xff) as u8);
-        retv.push((length & 0xff) as u8);
-    } else if length <= ASN1_MAX_INT_3 {
-        retv.push(0x83);
-        retv.push(((length >> 16) & 0xff) as u8);
-        retv.push(((length >> 8) & 0xff) as u8);
-        retv.push(((length >> 0) & 0xff) as u8);
-    } else if length <= ASN1_MAX_INT_4 {
-        retv.push(0x84);
-        retv.push(((length >> 24) & 0xff) as u8);
-        retv.push(((length >> 16) & 0xff) as u8);
-        retv.push(((length >> 8) & 0xff) as u8);
-        retv.push(((length >> 0) & 0xff) as u8);
-    } else {
-        panic!("can not exceed {} ",length);
+        return Ok(retv);
+    }
+
+    while clen > 0 {
+        cnt += 1;
+        clen >>= 8;
+    }
+    retv.push(0x80 | (cnt as u8));
+    while cnt > 0 {
+        cnt -= 1;
+        retv.push(((length >> (cnt * 8)) & 0xff) as u8);
+    }
+    Ok(retv)
+}
+
+/*
+    the identifier octets length of asn1obj_format_tag, no octets formatted
+*/
+pub fn asn1obj_tag_octets_len(tag :u64) -> usize {
+    let mut retv :usize = 1;
+    let mut number :u64;
+    if (tag & 0xff) == tag {
+        return retv;
+    }
+    number = asn1obj_tag_number(tag);
+    retv += 1;
+    number >>= 7;
+    while number > 0 {
+        retv += 1;
+        number >>= 7;
     }
     return retv;
 }
 
+pub fn asn1obj_header_len(tag :u64, length :u64) -> Result<usize,Box<dyn Error>> {
+    let mut retv :usize;
+    let mut clen :u64 = length;
+    if tag > 0xff && (tag & (ASN1_PRIMITIVE_TAG as u64)) != (ASN1_PRIMITIVE_TAG as u64) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"tag [0x{:x}] not valid high tag", tag}
+    }
+    retv = asn1obj_tag_octets_len(tag) + 1;
+    if length < ASN1_MAX_INT_NEG_1 {
+        return Ok(retv);
+    }
+    while clen > 0 {
+        retv += 1;
+        clen >>= 8;
+    }
+    Ok(retv)
+}
+
+pub fn asn1obj_write_header<W :Write>(iowriter :&mut W, tag :u64, length :u64) -> Result<usize,Box<dyn Error>> {
+    let hdr = asn1obj_format_header(tag,length)?;
+    iowriter.write_all(&hdr)?;
+    Ok(hdr.len())
+}
+
+/*
+    the encode_asn1 way for the length and write, used when the encoding
+    could not be known before (such as CER)
+*/
+pub fn asn1obj_encoded_len_of<T :Asn1Op + ?Sized>(v :&T) -> Result<usize,Box<dyn Error>> {
+    let code = v.encode_asn1()?;
+    Ok(code.len())
+}
+
+pub fn asn1obj_write_encoded<T :Asn1Op + ?Sized, W :Write>(v :&T, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+    let code = v.encode_asn1()?;
+    iowriter.write_all(&code)?;
+    Ok(code.len())
+}
+
 #[derive(Clone)]
 pub struct Asn1Any {
     pub content :Vec<u8>,
     pub tag : u64,
+    raw :Vec<u8>,
 }
 
 
+impl Asn1Any {
+    /*
+        the tlv tree of the value
+    */
+    pub fn to_node(&self) -> Result<Asn1Node,Box<dyn Error>> {
+        let code = self.encode_asn1()?;
+        let (retv,_) = Asn1Node::parse(&code)?;
+        Ok(retv)
+    }
+}
+
 impl Asn1Op for Asn1Any {
 
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
@@ -202,19 +686,19 @@ impl Asn1Op for Asn1Any {
         }
         let ores = vmap.get(ASN1_JSON_TAG);
         if ores.is_none() {
-            asn1obj_new_error!{Asn1ObjBaseError,"no {} found in {}", ASN1_JSON_TAG,key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"no {} found in {}", ASN1_JSON_TAG,key}
         }
         let ores2 = vmap.get(ASN1_JSON_CONTENT);
         if ores2.is_none() {
-            asn1obj_new_error!{Asn1ObjBaseError,"no {} found in {}",ASN1_JSON_CONTENT,key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"no {} found in {}",ASN1_JSON_CONTENT,key}
         }
         let tagv = ores.unwrap();
         let conv = ores2.unwrap();
         if !tagv.is_i64() && !tagv.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not i64", ASN1_JSON_TAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not i64", ASN1_JSON_TAG}
         }
         if !conv.is_array() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not array",ASN1_JSON_CONTENT}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not array",ASN1_JSON_CONTENT}
         }
         if tagv.is_i64() {
             let c = tagv.as_i64().unwrap();
@@ -226,7 +710,7 @@ impl Asn1Op for Asn1Any {
                     self.tag = fi as u64;
                 },
                 Err(e) => {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} val {} error {:?}", ASN1_JSON_TAG,c,e}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} val {} error {:?}", ASN1_JSON_TAG,c,e}
                 }
             }
         }
@@ -240,6 +724,7 @@ impl Asn1Op for Asn1Any {
 
     fn init_asn1() -> Self {
         Asn1Any {
+            raw : Vec::new(),
             tag : 0,
             content : Vec::new(),
         }
@@ -247,13 +732,13 @@ impl Asn1Op for Asn1Any {
 
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
-        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+        let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 
         self.tag = flag ;
 
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         self.content = Vec::new();
@@ -261,19 +746,55 @@ impl Asn1Op for Asn1Any {
             self.content.push(code[hdrlen+i]);
         }
         asn1obj_debug_buffer_trace!(code.as_ptr(), code.len(), "deocde any");
-        retv= hdrlen + totallen;
+        retv= hdrlen + totallen + eoclen;
+        self.raw = code[0..retv].to_vec();
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.raw.len() == 0 {
+            return None;
+        }
+        return Some(self.raw.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let mut retv :Vec<u8>;
-        retv = asn1obj_format_header(self.tag , self.content.len() as u64);
+        retv = asn1obj_format_header(self.tag , self.content.len() as u64)?;
         for i in 0..self.content.len() {
             retv.push(self.content[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_constructed(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let hdrlen = asn1obj_header_len(self.tag, self.content.len() as u64)?;
+        Ok(hdrlen + self.content.len())
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(self.tag));
+    }
+
+    fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_write_encoded(self,iowriter);
+        }
+        let hdrlen = asn1obj_write_header(iowriter,self.tag, self.content.len() as u64)?;
+        iowriter.write_all(&self.content)?;
+        Ok(hdrlen + self.content.len())
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let mut s = asn1_format_line(tab,&(format!("{}: ASN1_ANY tag 0x{:02x} {} ", name, self.tag, self.content.len())));
         let mut idx :usize;
@@ -322,6 +843,28 @@ impl Asn1Op for Asn1Any {
     }
 }
 
+/*
+    the content octets of Asn1Integer and Asn1Enumerated in encode_asn1, no octets formatted
+*/
+fn asn1obj_int_content_len(val :i64) -> Result<usize,Box<dyn Error>> {
+    let limits :[u64;5] = [ASN1_MAX_INT_NEG_1,ASN1_MAX_INT_NEG_2,ASN1_MAX_INT_NEG_3,ASN1_MAX_INT_NEG_4,ASN1_MAX_INT_NEG_5];
+    if val >= 0 {
+        for i in 0..limits.len() {
+            if val < limits[i] as i64 {
+                return Ok(i + 1);
+            }
+        }
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"value [0x{:x}] > [0x{:x}]", val, ASN1_MAX_INT_NEG_4}
+    }
+    let ival :i64 = - val;
+    for i in 0..limits.len() {
+        if ival <= limits[i] as i64 {
+            return Ok(i + 1);
+        }
+    }
+    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"neg value [0x{:x}] >= [0x{:x}]", val as u64, ASN1_MAX_INT_NEG_4}
+}
+
 #[derive(Clone)]
 pub struct Asn1Integer {
     pub val :i64,
@@ -372,7 +915,7 @@ impl Asn1Op for Asn1Integer {
             vmap = serde_json::json!(val.clone());
         }
         if !vmap.is_i64() && !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or i64",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or i64",key}
         }
         if vmap.is_i64() {
             let c = vmap.as_i64().unwrap();
@@ -384,7 +927,7 @@ impl Asn1Op for Asn1Integer {
                     self.val = fi;
                 },
                 Err(e) => {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} val {} error {:?}", key,c,e}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} val {} error {:?}", key,c,e}
                 }
             }
         }
@@ -403,20 +946,26 @@ impl Asn1Op for Asn1Integer {
         let mut ival :i64;
         let mut neg :bool = false;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_INTEGER_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"need 1 length"}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"need 1 length"}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_integer(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
         }
         if (code[hdrlen] & 0x80) != 0 {
             neg = true;
@@ -444,7 +993,7 @@ impl Asn1Op for Asn1Integer {
             } else if uval <= ASN1_MAX_INT_5 {
                 ival = (ASN1_MAX_INT_5 - uval + 1) as i64;
             } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"invalid uval [0x{:x}]", uval}
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"invalid uval [0x{:x}]", uval}
             }
 
             asn1obj_log_trace!("ival {}",ival);
@@ -466,7 +1015,18 @@ impl Asn1Op for Asn1Integer {
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let mut retv :Vec<u8> = Vec::new();
         retv.push(ASN1_INTEGER_FLAG);
         retv.push(8);
@@ -497,7 +1057,7 @@ impl Asn1Op for Asn1Integer {
                 retv.push((self.val & 0xff) as u8);
                 retv[1] = 5;
             } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"value [0x{:x}] > [0x{:x}]", self.val, ASN1_MAX_INT_NEG_4}
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"value [0x{:x}] > [0x{:x}]", self.val, ASN1_MAX_INT_NEG_4}
             }
         } else {
             let ival :i64 = - self.val;
@@ -530,13 +1090,26 @@ impl Asn1Op for Asn1Integer {
                 retv.push((uval & 0xff) as u8);
                 retv[1] = 5;
             } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"neg value [0x{:x}] >= [0x{:x}]", uval, ASN1_MAX_INT_NEG_4}
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"neg value [0x{:x}] >= [0x{:x}]", uval, ASN1_MAX_INT_NEG_4}
             }
             asn1obj_log_trace!("retv {:?}", retv);
         }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = asn1obj_int_content_len(self.val)?;
+        let hdrlen = asn1obj_header_len(ASN1_INTEGER_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_INTEGER_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let s = asn1_format_line(tab,&(format!("{}: ASN1_INTEGER {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
@@ -584,7 +1157,7 @@ impl Asn1Op for Asn1Boolean {
             vmap = val.clone();
         }
         if !vmap.is_boolean()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid bool",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid bool",key}
         }
         let c = vmap.as_bool().unwrap();
         self.val = c ;
@@ -601,20 +1174,24 @@ impl Asn1Op for Asn1Boolean {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_BOOLEAN_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_BOOLEAN_FLAG [0x{:02x}]", flag,ASN1_BOOLEAN_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_BOOLEAN_FLAG [0x{:02x}]", flag,ASN1_BOOLEAN_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen != 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"totallen [{}] != 1", totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] != 1", totallen}
+        }
+
+        if asn1obj_is_der() && code[hdrlen] != 0 && code[hdrlen] != 0xff {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,hdrlen,"DER boolean [0x{:02x}] not 0x00 or 0xff", code[hdrlen]}
         }
 
         if code[hdrlen]  != 0 {
@@ -633,7 +1210,18 @@ impl Asn1Op for Asn1Boolean {
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let mut retv :Vec<u8> = Vec::new();
         retv.push(ASN1_BOOLEAN_FLAG);
         retv.push(1);
@@ -645,6 +1233,19 @@ impl Asn1Op for Asn1Boolean {
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = 1;
+        let hdrlen = asn1obj_header_len(ASN1_BOOLEAN_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_BOOLEAN_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let s = asn1_format_line(tab,&(format!("{}: ASN1_BOOLEAN {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
@@ -685,7 +1286,7 @@ impl Asn1Op for Asn1BitString {
             vmap = val.clone();
         }
         if !vmap.is_string()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string",key}
         }
         let c = vmap.as_str().unwrap();
         self.val = format!("{}",c) ;
@@ -702,20 +1303,35 @@ impl Asn1Op for Asn1BitString {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_BIT_STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_BIT_STRING_FLAG)?;
+            let _ = self.decode_asn1(&flatv)?;
+            self.data = Vec::new();
+            for i in 0..clen {
+                self.data.push(code[i]);
+            }
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_BIT_STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"totallen [{}] < 1", totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] < 1", totallen}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_bits(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
         }
 
         let mut retm = BytesMut::with_capacity(totallen - 1);
@@ -766,13 +1382,24 @@ impl Asn1Op for Asn1BitString {
         }
 
         if bits != checkbits {
-            asn1obj_new_error!{Asn1ObjBaseError,"bits [0x{:x}] != checkbits [0x{:x}]",bits,checkbits}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"bits [0x{:x}] != checkbits [0x{:x}]",bits,checkbits}
         }
 
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let vcode = self.val.as_bytes();
         let llen :u64 = (vcode.len() + 1) as u64;
         let mut retv :Vec<u8>;
@@ -780,10 +1407,10 @@ impl Asn1Op for Asn1BitString {
         let mut idx :usize;
 
         if vcode.len() == 0 {
-            asn1obj_new_error!{Asn1ObjBaseError,"data [0] not valid"}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"data [0] not valid"}
         }
 
-        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen);
+        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen)?;
         idx = vcode.len() - 1;
 
         while idx > 0 {
@@ -817,9 +1444,28 @@ impl Asn1Op for Asn1BitString {
         for i in 0..vcode.len() {
             retv.push(vcode[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        if self.val.len() == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"data [0] not valid"}
+        }
+        let clen :usize = self.val.len() + 1;
+        let hdrlen = asn1obj_header_len(ASN1_BIT_STRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_BIT_STRING_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let s = asn1_format_line(tab,&(format!("{}: ASN1_BIT_STRING {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
@@ -830,6 +1476,7 @@ impl Asn1Op for Asn1BitString {
 #[derive(Clone)]
 pub struct Asn1BitData {
     pub data :Vec<u8>,
+    raw :Vec<u8>,
 }
 
 
@@ -870,7 +1517,7 @@ impl Asn1Op for Asn1BitData {
             vmap = val.clone();
         }
         if !vmap.is_string() && !vmap.is_array()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or array",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or array",key}
         }
         self.data = Vec::new();
         if vmap.is_string() {
@@ -883,7 +1530,7 @@ impl Asn1Op for Asn1BitData {
             let c = vmap.as_array().unwrap();
             for v in c.iter() {
                 if !v.is_i64() {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} invalid element {:?}",key,c}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} invalid element {:?}",key,c}
                 }
                 self.data.push(v.as_u64().unwrap() as u8);
             }
@@ -893,6 +1540,7 @@ impl Asn1Op for Asn1BitData {
 
     fn init_asn1() -> Self {
         Asn1BitData {
+            raw : Vec::new(),
             data : Vec::new(),
         }
     }
@@ -900,7 +1548,13 @@ impl Asn1Op for Asn1BitData {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_BIT_STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_BIT_STRING_FLAG)?;
+            let _ = self.decode_asn1(&flatv)?;
+            self.raw = code[0..clen].to_vec();
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
         let bits :u8;
@@ -909,15 +1563,21 @@ impl Asn1Op for Asn1BitData {
 
         if flag != ASN1_BIT_STRING_FLAG as u64 {
             asn1obj_debug_buffer_trace!(code.as_ptr(),code.len(),"decode buffer error");
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"totallen [{}] < 1", totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] < 1", totallen}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_bits(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
         }
         asn1obj_log_trace!("totallen [{}]",totallen);
 
@@ -961,21 +1621,33 @@ impl Asn1Op for Asn1BitData {
 
         checkbits = code[hdrlen];
         if checkbits != bits {
-            asn1obj_new_error!{Asn1ObjBaseError,"checkbits [0x{:x}] != calcbits [0x{:x}]",checkbits,bits}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"checkbits [0x{:x}] != calcbits [0x{:x}]",checkbits,bits}
         }
 
         asn1obj_debug_buffer_trace!(self.data.as_ptr(), self.data.len(),"Asn1BitData");
         retv = hdrlen + totallen;
+        self.raw = code[0..retv].to_vec();
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.raw.len() == 0 {
+            return None;
+        }
+        return Some(self.raw.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let llen :u64 = (self.data.len() + 1) as u64;
         let mut retv :Vec<u8>;
         let bits :u8;
         let mut idx :usize;
 
-        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen);
+        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen)?;
         if self.data.len() > 0 {
             idx = self.data.len() -1;
             while idx > 0 {
@@ -1012,9 +1684,25 @@ impl Asn1Op for Asn1BitData {
         for i in 0..self.data.len() {
             retv.push(self.data[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.data.len() + 1;
+        let hdrlen = asn1obj_header_len(ASN1_BIT_STRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_BIT_STRING_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let mut s = asn1_format_line(tab,&(format!("{}: ASN1_BIT_DATA len[0x{:x}:{}]", name,self.data.len(),self.data.len())));
         let mut idx :usize = 0;
@@ -1074,6 +1762,7 @@ impl Asn1Op for Asn1BitData {
 pub struct Asn1BitDataFlag {
     pub data :Vec<u8>,
     pub flag :u64,
+    raw :Vec<u8>,
 }
 
 
@@ -1121,12 +1810,12 @@ impl Asn1Op for Asn1BitDataFlag {
         let vmap :serde_json::value::Value;
         let k = nvmap.get(ASN1_JSON_BITDATA);
         if k.is_none() {
-            asn1obj_new_error!{Asn1ObjBaseError,"no [{}] find",ASN1_JSON_BITDATA}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"no [{}] find",ASN1_JSON_BITDATA}
         }
         vmap = k.unwrap().clone();
 
         if !vmap.is_string() && !vmap.is_array()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or array",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or array",key}
         }
         self.data = Vec::new();
         if vmap.is_string() {
@@ -1139,7 +1828,7 @@ impl Asn1Op for Asn1BitDataFlag {
             let c = vmap.as_array().unwrap();
             for v in c.iter() {
                 if !v.is_i64() {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} invalid element {:?}",key,c}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} invalid element {:?}",key,c}
                 }
                 self.data.push(v.as_u64().unwrap() as u8);
             }
@@ -1157,6 +1846,7 @@ impl Asn1Op for Asn1BitDataFlag {
 
     fn init_asn1() -> Self {
         Asn1BitDataFlag {
+            raw : Vec::new(),
             data : Vec::new(),
             flag : 0,
         }
@@ -1165,24 +1855,36 @@ impl Asn1Op for Asn1BitDataFlag {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_BIT_STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_BIT_STRING_FLAG)?;
+            let _ = self.decode_asn1(&flatv)?;
+            self.raw = code[0..clen].to_vec();
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_BIT_STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag,ASN1_BIT_STRING_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"totallen [{}] < 1", totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] < 1", totallen}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_bits(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
         }
 
         asn1obj_log_trace!("totallen [{}]",totallen);
@@ -1194,24 +1896,52 @@ impl Asn1Op for Asn1BitDataFlag {
         }
         asn1obj_debug_buffer_trace!(self.data.as_ptr(), self.data.len(),"Asn1BitData");
         retv = hdrlen + totallen;
+        self.raw = code[0..retv].to_vec();
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.raw.len() == 0 {
+            return None;
+        }
+        return Some(self.raw.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let llen :u64 = (self.data.len() + 1) as u64;
         let mut retv :Vec<u8>;
         let bits :u8;
 
-        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen);
+        retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64,llen)?;
         bits = (self.flag & 0xff) as u8;
 
         retv.push(bits);
         for i in 0..self.data.len() {
             retv.push(self.data[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.data.len() + 1;
+        let hdrlen = asn1obj_header_len(ASN1_BIT_STRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_BIT_STRING_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let mut s = asn1_format_line(tab,&(format!("{}: ASN1_BIT_DATA len[0x{:x}:{}] flag [0x{:x}]", name,self.data.len(),self.data.len(),self.flag)));
         let mut idx :usize = 0;
@@ -1298,7 +2028,7 @@ impl Asn1Op for Asn1OctString {
             vmap = val.clone();
         }
         if !vmap.is_string() && !vmap.is_array()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or array",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or array",key}
         }
         self.val = "".to_string();
         if vmap.is_string() {
@@ -1309,7 +2039,7 @@ impl Asn1Op for Asn1OctString {
             let mut retm = BytesMut::with_capacity(c.len());
             for v in c.iter() {
                 if !v.is_i64() {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} invalid element {:?}",key,c}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} invalid element {:?}",key,c}
                 }
                 retm.put_u8(v.as_u64().unwrap() as u8);
             }
@@ -1330,16 +2060,25 @@ impl Asn1Op for Asn1OctString {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_OCT_STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_OCT_STRING_FLAG)?;
+            let _ = self.decode_asn1(&flatv)?;
+            self.data = Vec::new();
+            for i in 0..clen {
+                self.data.push(code[i]);
+            }
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_OCT_STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_OCT_STRING_FLAG [0x{:02x}]", flag,ASN1_OCT_STRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_OCT_STRING_FLAG [0x{:02x}]", flag,ASN1_OCT_STRING_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
 
@@ -1358,19 +2097,46 @@ impl Asn1Op for Asn1OctString {
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let vcode = self.val.as_bytes();
         let llen :u64 = (vcode.len() ) as u64;
         let mut retv :Vec<u8>;
 
-        retv = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,llen);
+        retv = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,llen)?;
 
         for i in 0..vcode.len() {
             retv.push(vcode[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(ASN1_OCT_STRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_OCT_STRING_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let s = asn1_format_line(tab,&(format!("{}: ASN1_OCT_STRING {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
@@ -1381,6 +2147,7 @@ impl Asn1Op for Asn1OctString {
 #[derive(Clone)]
 pub struct Asn1OctData {
     pub data :Vec<u8>,
+    raw :Vec<u8>,
 }
 
 
@@ -1419,7 +2186,7 @@ impl Asn1Op for Asn1OctData {
             vmap = val.clone();
         }
         if !vmap.is_string() && !vmap.is_array()  {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or array",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or array",key}
         }
         self.data = Vec::new();
         if vmap.is_string() {
@@ -1431,7 +2198,7 @@ impl Asn1Op for Asn1OctData {
             let c = vmap.as_array().unwrap();
             for v in c.iter() {
                 if !v.is_i64() {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} invalid element {:?}",key,c}
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} invalid element {:?}",key,c}
                 }
                 self.data.push(v.as_u64().unwrap() as u8);
             }
@@ -1441,6 +2208,7 @@ impl Asn1Op for Asn1OctData {
 
     fn init_asn1() -> Self {
         Asn1OctData {
+            raw : Vec::new(),
             data : Vec::new(),
         }
     }
@@ -1448,16 +2216,22 @@ impl Asn1Op for Asn1OctData {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_OCT_STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_OCT_STRING_FLAG)?;
+            let _ = self.decode_asn1(&flatv)?;
+            self.raw = code[0..clen].to_vec();
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_OCT_STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_OCT_STRING_FLAG [0x{:02x}]", flag,ASN1_OCT_STRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_OCT_STRING_FLAG [0x{:02x}]", flag,ASN1_OCT_STRING_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         self.data = Vec::new();
@@ -1467,21 +2241,57 @@ impl Asn1Op for Asn1OctData {
         retv= hdrlen + totallen;
 
         asn1obj_debug_buffer_trace!(self.data.as_ptr(),self.data.len(), "Asn1OctData");
+        self.raw = code[0..retv].to_vec();
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.raw.len() == 0 {
+            return None;
+        }
+        return Some(self.raw.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let llen :u64 = (self.data.len() ) as u64;
         let mut retv :Vec<u8>;
 
-        retv = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,llen);
+        retv = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,llen)?;
 
         for i in 0..self.data.len() {
             retv.push(self.data[i]);
         }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let hdrlen = asn1obj_header_len(ASN1_OCT_STRING_FLAG as u64, self.data.len() as u64)?;
+        Ok(hdrlen + self.data.len())
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_OCT_STRING_FLAG as u64));
+    }
+
+    fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_write_encoded(self,iowriter);
+        }
+        let hdrlen = asn1obj_write_header(iowriter,ASN1_OCT_STRING_FLAG as u64, self.data.len() as u64)?;
+        iowriter.write_all(&self.data)?;
+        Ok(hdrlen + self.data.len())
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
         let mut s = asn1_format_line(tab,&(format!("{}: ASN1_OCT_DATA", name)));
         let mut idx :usize = 0;
@@ -1564,7 +2374,7 @@ impl Asn1Op for Asn1Null {
             vmap = val.clone();
         }
         if !vmap.is_null() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid null",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid null",key}
         }
         self.data = Vec::new();
         return Ok(1);
@@ -1579,20 +2389,20 @@ impl Asn1Op for Asn1Null {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_NULL_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_NULL_FLAG [0x{:02x}]", flag,ASN1_NULL_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_NULL_FLAG [0x{:02x}]", flag,ASN1_NULL_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
         if totallen != 0 {
-            asn1obj_new_error!{Asn1ObjBaseError,"totallen [{}] != 0",totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"totallen [{}] != 0",totallen}
         }
 
         asn1obj_log_trace!("Asn1Null");
@@ -1604,14 +2414,38 @@ impl Asn1Op for Asn1Null {
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let retv :Vec<u8>;
-        retv = asn1obj_format_header(ASN1_NULL_FLAG as u64,0);
+        retv = asn1obj_format_header(ASN1_NULL_FLAG as u64,0)?;
         Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_NULL", name)));
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = 0;
+        let hdrlen = asn1obj_header_len(ASN1_NULL_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_NULL_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_NULL", name)));
         iowriter.write(s.as_bytes())?;
         Ok(())
     }
@@ -1620,6 +2454,231 @@ impl Asn1Op for Asn1Null {
 const ULONG_MAX :u64 = 0xffffffffffffffff;
 
 
+/*
+    the subidentifiers of OBJECT IDENTIFIER and RELATIVE-OID to the dotted arcs,
+    absolute is to split the first subidentifier into the first two arcs
+*/
+pub(crate) fn asn1obj_decode_arcs(v8 :&[u8], absolute :bool) -> Result<String,Box<dyn Error>> {
+    let mut rets :String = "".to_string();
+    let mut bn :BigUint = Zero::zero();
+    let mut l :u64;
+    let mut lenv :usize = v8.len();
+    let mut usebn :bool;
+    let mut idx :usize = 0;
+    let mut bfirst :bool = absolute;
+    let mut i :u32;
+
+    while lenv > 0 {
+        l = 0;
+        usebn = false;
+        loop {
+            let c = v8[idx];
+            idx += 1;
+            lenv -= 1;
+            if lenv == 0 && (c & 0x80) != 0 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"c [0x{:02x}] at the end",c}
+            }
+            if usebn {
+                bn += c & 0x7f;
+                asn1obj_log_trace!("bn [{}]",bn);
+            } else {
+                l += (c & 0x7f) as u64;
+                asn1obj_log_trace!("l [{}]", l);
+            }
+
+            if (c & 0x80) == 0 {
+                break;
+            }
+
+            if !usebn && l >( ULONG_MAX >> 7) {
+                bn = Zero::zero();
+                bn += l;
+                usebn = true;
+            }
+
+            if usebn {
+                bn <<= 7;
+            } else {
+                l <<= 7;
+            }
+        }
+
+        if bfirst {
+            bfirst = false;
+            if l >= 80 {
+                i = 2;
+                if usebn {
+                    bn -= 80 as u64;
+                } else {
+                    l -= 80;
+                }
+            } else {
+                i = (l / 40) as u32;
+                l -= (i * 40) as u64;
+            }
+
+            asn1obj_log_trace!("i {}",i);
+            rets.push_str(&format!("{}",i));
+
+        } 
+        if rets.len() > 0 {
+            rets.push_str(".");
+        }
+        if usebn {
+            rets.push_str(&format!("{}",bn));
+        } else {
+            rets.push_str(&format!("{}", l));
+        }
+    }
+
+    Ok(rets)
+}
+
+/*
+    the dotted arcs to the subidentifiers, see asn1obj_decode_arcs
+*/
+pub(crate) fn asn1obj_encode_arcs(val :&str, absolute :bool) -> Result<Vec<u8>,Box<dyn Error>> {
+    let mut retv :Vec<u8> = Vec::new();
+    let mut idx :usize = 0;
+    let sarr :Vec<&str> = val.split(".").collect();
+    if !absolute {
+        /*all the arcs of relative oid are encoded in subidentifier*/
+        idx = 2;
+    }
+    let  mut curn :u64 = 0;
+    for v in sarr.iter() {
+        match u64::from_str_radix(v,10) {
+            Ok(cn) => {
+                if idx < 2 {
+                    if idx == 0 {
+                        curn = cn;
+                    } else {
+                        curn *= 40;
+                        curn += cn;
+
+                        retv.push(curn as u8);
+                        curn = 0;
+                    }
+
+                } else {
+                    let mut maxidx :usize = 0;
+
+                    curn = cn;
+                    loop {
+                        if (curn >> (maxidx * 7))  == 0 {
+                            break;
+                        }
+                        maxidx += 1;
+                    }
+
+                    if maxidx == 0 {
+                        retv.push(0);
+                    } else {
+                        while maxidx > 1 {
+                            let bb :u8 = ((cn >> ((maxidx - 1) * 7)) & 0x7f) as u8;
+                            retv.push(bb | 0x80 );
+                            maxidx -= 1;
+                        }
+                        if maxidx == 1 {
+                            let bb :u8 = (cn & 0x7f) as u8;
+                            retv.push(bb);
+                        }
+                    }
+
+                }
+                idx += 1;
+            },
+            Err(e) => {
+                match BigUint::from_str(v) {
+                    Ok(bn2) => {
+                        if idx < 2 {
+                            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] at [{}] with bigint", val,v}
+                        }
+
+                        let mut maxidx :usize = 0;
+                        loop {
+                            let bn :BigUint = bn2.clone();
+                            let cb :BigUint = bn.shr(maxidx * 7);
+                            let zb :BigUint = Zero::zero();
+                            if cb.eq(&zb) {
+                                break;
+                            }
+                            maxidx += 1;
+                        }
+
+                        if maxidx < 1 {
+                            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"bignum is {} to small", bn2}
+                        } else {
+                            while maxidx > 1 {
+                                let bn :BigUint = bn2.clone();
+                                let cb :BigUint = bn.shr((maxidx - 1) * 7);
+                                let bv :Vec<u8> = cb.to_bytes_le();
+                                let bb :u8 = bv[0] & 0x7f;
+                                retv.push(bb | 0x80);
+                                maxidx -= 1;
+                            }
+
+                            let bv :Vec<u8> = bn2.to_bytes_le();
+                            let bb :u8 = bv[0] & 0x7f;
+                            retv.push(bb);
+                        }
+
+                        idx += 1;
+                    },
+                    Err(_e2) => {
+                        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] at [{}] {:?}", val,v,e}
+                    }
+                }
+            }
+        }
+    }
+    Ok(retv)
+}
+
+/*
+    the octets length of asn1obj_encode_arcs, no subidentifier formatted
+*/
+pub(crate) fn asn1obj_arcs_len(val :&str, absolute :bool) -> Result<usize,Box<dyn Error>> {
+    let mut retv :usize = 0;
+    let mut idx :usize = 0;
+    let mut bits :u64;
+    if !absolute {
+        idx = 2;
+    }
+    for v in val.split(".") {
+        match u64::from_str_radix(v,10) {
+            Ok(cn) => {
+                bits = (64 - cn.leading_zeros()) as u64;
+            },
+            Err(e) => {
+                match BigUint::from_str(v) {
+                    Ok(bn2) => {
+                        if idx < 2 {
+                            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] at [{}] with bigint", val,v}
+                        }
+                        bits = bn2.bits();
+                    },
+                    Err(_e2) => {
+                        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] at [{}] {:?}", val,v,e}
+                    }
+                }
+            }
+        }
+        if idx == 1 {
+            /*the first two arcs in one octet*/
+            retv += 1;
+        } else if idx >= 2 {
+            if bits == 0 {
+                retv += 1;
+            } else {
+                retv += ((bits + 6) / 7) as usize;
+            }
+        }
+        idx += 1;
+    }
+    Ok(retv)
+}
+
 #[derive(Clone)]
 pub struct Asn1Object {
     val :String,
@@ -1644,28 +2703,21 @@ impl PartialEq for Asn1Object {
 
 impl Asn1Object {
     pub fn set_value(&mut self,val :&str) -> Result<String,Box<dyn Error>> {
-        let restr = format!("^([0-9\\.]+)$");
         let oldstr :String;
-        let vo = Regex::new(&restr);
-        if vo.is_err() {
-            let err = vo.err().unwrap();
-            asn1obj_new_error!{Asn1ObjBaseError,"can parse [{}] error [{:?}]", restr,err}
-        }
-        let re = vo.unwrap();
-        if !re.is_match(val) {
-            asn1obj_new_error!{Asn1ObjBaseError,"[{}] not valid for [{}]", val, restr}
+        if val.len() == 0 || !val.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not only digits and dots", val}
         }
         let sarr :Vec<&str> = val.split(".").collect();
         if sarr.len() < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"need at least 1 number"}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"need at least 1 number"}
         }
         if sarr[0] != "1" && sarr[0] != "2" {
-            asn1obj_new_error!{Asn1ObjBaseError,"must start 1. or 2. not [{}.]",sarr[0]}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"must start 1. or 2. not [{}.]",sarr[0]}
         }
 
         for s in sarr.iter() {
             if s.len() == 0 {
-                asn1obj_new_error!{Asn1ObjBaseError,"not allow [] empty on in the [{}]",val}
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not allow [] empty on in the [{}]",val}
             }
         }
 
@@ -1679,179 +2731,18 @@ impl Asn1Object {
     }
 
     fn decode_object(&self,v8 :&[u8]) -> Result<String,Box<dyn Error>> {
-        let mut rets :String = "".to_string();
-        let mut bn :BigUint = Zero::zero();
-        let mut l :u64;
-        let mut lenv :usize = v8.len();
-        let mut usebn :bool;
-        let mut idx :usize = 0;
-        let mut bfirst :bool = true;
-        let mut i :u32;
-
-        while lenv > 0 {
-            l = 0;
-            usebn = false;
-            loop {
-                let c = v8[idx];
-                idx += 1;
-                lenv -= 1;
-                if lenv == 0 && (c & 0x80) != 0 {
-                    asn1obj_new_error!{Asn1ObjBaseError,"c [0x{:02x}] at the end",c}
-                }
-                if usebn {
-                    bn += c & 0x7f;
-                    asn1obj_log_trace!("bn [{}]",bn);
-                } else {
-                    l += (c & 0x7f) as u64;
-                    asn1obj_log_trace!("l [{}]", l);
-                }
-
-                if (c & 0x80) == 0 {
-                    break;
-                }
-
-                if !usebn && l >( ULONG_MAX >> 7) {
-                    bn = Zero::zero();
-                    bn += l;
-                    usebn = true;
-                }
-
-                if usebn {
-                    bn <<= 7;
-                } else {
-                    l <<= 7;
-                }
-            }
-
-            if bfirst {
-                bfirst = false;
-                if l >= 80 {
-                    i = 2;
-                    if usebn {
-                        bn -= 80 as u64;
-                    } else {
-                        l -= 80;
-                    }
-                } else {
-                    i = (l / 40) as u32;
-                    l -= (i * 40) as u64;
-                }
-
-                asn1obj_log_trace!("i {}",i);
-                rets.push_str(&format!("{}",i));
-
-            } 
-            if usebn {
-                rets.push_str(".");
-                rets.push_str(&format!("{}",bn));
-            } else {
-                rets.push_str(".");
-                rets.push_str(&format!("{}", l));
-            }
-        }
-
-        Ok(rets)
+        return asn1obj_decode_arcs(v8,true);
     }
 
     fn encode_object(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let mut retv :Vec<u8> = Vec::new();
-        let mut idx :usize = 0;
-        let sarr :Vec<&str> = self.val.split(".").collect();
-        let  mut curn :u64 = 0;
-        for v in sarr.iter() {
-            match u64::from_str_radix(v,10) {
-                Ok(cn) => {
-                    if idx < 2 {
-                        if idx == 0 {
-                            curn = cn;
-                        } else {
-                            curn *= 40;
-                            curn += cn;
-
-                            retv.push(curn as u8);
-                            curn = 0;
-                        }
-
-                    } else {
-                        let mut maxidx :usize = 0;
-
-                        curn = cn;
-                        loop {
-                            if (curn >> (maxidx * 7))  == 0 {
-                                break;
-                            }
-                            maxidx += 1;
-                        }
-
-                        if maxidx == 0 {
-                            retv.push(0);
-                        } else {
-                            while maxidx > 1 {
-                                let bb :u8 = ((cn >> ((maxidx - 1) * 7)) & 0x7f) as u8;
-                                retv.push(bb | 0x80 );
-                                maxidx -= 1;
-                            }
-                            if maxidx == 1 {
-                                let bb :u8 = (cn & 0x7f) as u8;
-                                retv.push(bb);
-                            }
-                        }
-
-                    }
-                    idx += 1;
-                },
-                Err(e) => {
-                    match BigUint::from_str(v) {
-                        Ok(bn2) => {
-                            if idx < 2 {
-                                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] at [{}] with bigint", self.val,v}
-                            }
-
-                            let mut maxidx :usize = 0;
-                            loop {
-                                let bn :BigUint = bn2.clone();
-                                let cb :BigUint = bn.shr(maxidx * 7);
-                                let zb :BigUint = Zero::zero();
-                                if cb.eq(&zb) {
-                                    break;
-                                }
-                                maxidx += 1;
-                            }
-
-                            if maxidx < 1 {
-                                asn1obj_new_error!{Asn1ObjBaseError ,"bignum is {} to small", bn2}
-                            } else {
-                                while maxidx > 1 {
-                                    let bn :BigUint = bn2.clone();
-                                    let cb :BigUint = bn.shr((maxidx - 1) * 7);
-                                    let bv :Vec<u8> = cb.to_bytes_le();
-                                    let bb :u8 = bv[0] & 0x7f;
-                                    retv.push(bb | 0x80);
-                                    maxidx -= 1;
-                                }
-
-                                let bv :Vec<u8> = bn2.to_bytes_le();
-                                let bb :u8 = bv[0] & 0x7f;
-                                retv.push(bb);
-                            }
-
-                            idx += 1;
-                        },
-                        Err(_e2) => {
-                            asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] at [{}] {:?}", self.val,v,e}
-                        }
-                    }
-                }
-            }
-        }
-        Ok(retv)
+        return asn1obj_encode_arcs(&self.val,true);
     }
 }
 
 
 impl Asn1Op for Asn1Object {
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let setjson = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
+        let setjson = serde_json::json!(self.format_value());
         if key.len() > 0 {
             val[key] = setjson;    
         } else {
@@ -1874,10 +2765,10 @@ impl Asn1Op for Asn1Object {
             vmap = val.clone();
         }
         if !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"[{}] not valid string {:?}",key,vmap}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not valid string {:?}",key,vmap}
         }
         let c = vmap.as_str().unwrap();
-        let _ = self.set_value(c)?;
+        let _ = self.parse_value(c)?;
         return Ok(1);
     }
 
@@ -1891,19 +2782,23 @@ impl Asn1Op for Asn1Object {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_OBJECT_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_OBJECT_FLAG [0x{:02x}]", flag,ASN1_OBJECT_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_OBJECT_FLAG [0x{:02x}]", flag,ASN1_OBJECT_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
-        let s = self.decode_object(&code[hdrlen..(hdrlen+totallen)])?;
+        let ores = self.decode_object(&code[hdrlen..(hdrlen+totallen)]);
+        if ores.is_err() {
+            return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+        }
+        let s = ores.unwrap();
         self.val = s;
         asn1obj_log_trace!("Asn1Object [{}]",self.val);
         self.data = Vec::new();
@@ -1914,249 +2809,350 @@ impl Asn1Op for Asn1Object {
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let mut retv :Vec<u8>;
         if self.val.len() == 0 {
-            asn1obj_new_error!{Asn1ObjBaseError,"not set val yet"}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not set val yet"}
         }
         let vv :Vec<u8> = self.encode_object()?;
-        retv = asn1obj_format_header(ASN1_OBJECT_FLAG as u64,vv.len() as u64);
+        retv = asn1obj_format_header(ASN1_OBJECT_FLAG as u64,vv.len() as u64)?;
         for v in vv.iter() {
             retv.push(*v);
         }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        if self.val.len() == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not set val yet"}
+        }
+        let clen :usize = asn1obj_arcs_len(&self.val,true)?;
+        let hdrlen = asn1obj_header_len(ASN1_OBJECT_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_OBJECT_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_OBJECT {}", name, self.val)));
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_OBJECT {}", name, self.format_value())));
         iowriter.write(s.as_bytes())?;
         Ok(())
     }
 }
 
 
-#[derive(Clone)]
-pub struct Asn1Enumerated {
-    pub val :i64,
-    data :Vec<u8>,
-}
-
-impl Asn1Op for Asn1Enumerated {
-    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let setjson = serde_json::from_str(&format!("{}",self.val)).unwrap();
-        if key.len() > 0 {
-            val[key] = setjson;    
-        } else {
-            *val = setjson;
+impl Asn1Object {
+    /*
+        set by the name in the oid registry, see asn1obj_register_oid
+    */
+    pub fn set_name(&mut self, name :&str) -> Result<String,Box<dyn Error>> {
+        let ores = asn1obj_name_to_oid(name);
+        if ores.is_none() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not registered oid name", name}
         }
-        
-        Ok(1)
+        return self.set_value(&ores.unwrap());
     }
 
-    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let vmap :serde_json::value::Value;
-        if key.len() > 0 {
-            let ores = val.get(key);
-            if ores.is_none() {
-                self.val = 0;
-                self.data = Vec::new();
-                return Ok(0);
+    pub fn get_name(&self) -> Option<String> {
+        return asn1obj_oid_to_name(&self.val);
+    }
+
+    /*
+        "name (oid)" when oid_names in encode ctx and the name registered, otherwise the oid
+    */
+    fn format_value(&self) -> String {
+        if asn1obj_is_oid_names() {
+            let ores = self.get_name();
+            if ores.is_some() {
+                return format!("{} ({})",ores.unwrap(),self.val);
             }
-            vmap = serde_json::json!(ores.unwrap());
-        } else {
-            vmap = val.clone();
-        }
-        if !vmap.is_i64() && !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or i64",key}
         }
-        if vmap.is_i64() {
-            let c = vmap.as_i64().unwrap();
-            self.val = c ;
-        } else if vmap.is_string() {
-            let c = vmap.as_str().unwrap();
-            match c.parse::<i64>() {
-                Ok(fi) => {
-                    self.val = fi;
-                },
-                Err(e) => {
-                    asn1obj_new_error!{Asn1ObjBaseError,"{} val {} error {:?}", key,c,e}
-                }
+        return format!("{}",self.val);
+    }
+
+    /*
+        accept the forms given by format_value and the registered name
+    */
+    fn parse_value(&mut self, val :&str) -> Result<String,Box<dyn Error>> {
+        if val.ends_with(")") {
+            let ores = val.find(" (");
+            if ores.is_some() {
+                let idx = ores.unwrap();
+                return self.set_value(&val[(idx+2)..(val.len()-1)]);
             }
         }
-        return Ok(1);
+        if asn1obj_name_to_oid(val).is_some() {
+            return self.set_name(val);
+        }
+        return self.set_value(val);
     }
 
-    fn init_asn1() -> Self {
-        Asn1Enumerated {
-            val : 0,
-            data : Vec::new(),
-        }
+    /*
+        the full identifier of the relative oid under self
+    */
+    pub fn join_relative(&self, rel :&Asn1RelativeOid) -> Result<Asn1Object,Box<dyn Error>> {
+        let mut retv :Asn1Object = Asn1Object::init_asn1();
+        let _ = retv.set_value(&format!("{}.{}",self.val,rel.get_value()))?;
+        Ok(retv)
     }
 
-    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
-        let retv :usize;
-        let mut ival :i64;
-        let mut neg :bool = false;
-        if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+    /*
+        the relative oid of self under base, error when self is not under base
+    */
+    pub fn relative_to(&self, base :&Asn1Object) -> Result<Asn1RelativeOid,Box<dyn Error>> {
+        let prefix :String = format!("{}.",base.val);
+        if !self.val.starts_with(&prefix) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not under [{}]", self.val, base.val}
         }
-        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+        let mut retv :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+        let _ = retv.set_value(&self.val[prefix.len()..])?;
+        Ok(retv)
+    }
+}
 
-        if flag != ASN1_ENUMERATED_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_ENUMERATED_FLAG [0x{:02x}]", flag,ASN1_ENUMERATED_FLAG}
+impl Asn1Object {
+    /*
+        the arcs in u64, error when any arc is larger
+    */
+    pub fn get_arcs(&self) -> Result<Vec<u64>,Box<dyn Error>> {
+        let mut retv :Vec<u64> = Vec::new();
+        for s in self.val.split(".") {
+            let ores = u64::from_str_radix(s,10);
+            if ores.is_err() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"arc [{}] of [{}] not in u64", s, self.val}
+            }
+            retv.push(ores.unwrap());
         }
+        Ok(retv)
+    }
 
-        if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
-        }
+    pub fn set_arcs(&mut self, arcs :&[u64]) -> Result<String,Box<dyn Error>> {
+        let sarr :Vec<String> = arcs.iter().map(|a| format!("{}",a)).collect();
+        return self.set_value(&sarr.join("."));
+    }
 
-        if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"need 1 length"}
-        }
-        if (code[hdrlen] & 0x80) != 0 {
-            neg = true;
+    pub fn arc_count(&self) -> usize {
+        return self.val.split(".").count();
+    }
+
+    /*
+        the oid without the last arc, None for the oid of two arcs
+        as the first two arcs are encoded in one subidentifier
+    */
+    pub fn parent(&self) -> Option<Asn1Object> {
+        if self.arc_count() <= 2 {
+            return None;
         }
+        let idx = self.val.rfind(".").unwrap();
+        let mut retv :Asn1Object = Asn1Object::init_asn1();
+        retv.val = self.val[0..idx].to_string();
+        return Some(retv);
+    }
 
+    pub fn child(&self, arc :u64) -> Asn1Object {
+        let mut retv :Asn1Object = Asn1Object::init_asn1();
+        retv.val = format!("{}.{}",self.val,arc);
+        return retv;
+    }
 
+    /*
+        compare by arcs, so 1.2.84 does not start with 1.2.8
+    */
+    pub fn starts_with(&self, prefix :&Asn1Object) -> bool {
+        if !self.val.starts_with(&prefix.val) {
+            return false;
+        }
+        let rest :&str = &self.val[prefix.val.len()..];
+        return rest.len() == 0 || rest.starts_with(".");
+    }
 
-        if neg {
-            let mut uval :u64;
-            uval = 0;
-            for i in 0..totallen {
-                uval <<= 8;
-                uval += (code[hdrlen+i]) as u64;
-                asn1obj_log_trace!("[0x{:x}]", uval);
-            }
+    pub fn is_parent_of(&self, other :&Asn1Object) -> bool {
+        return other.arc_count() == (self.arc_count() + 1) && other.starts_with(self);
+    }
 
-            if uval <= ASN1_MAX_INT_1 {
-                ival = (ASN1_MAX_INT_1 - uval + 1) as i64;
-            } else if uval <= ASN1_MAX_INT_2 {
-                ival = (ASN1_MAX_INT_2 - uval + 1) as i64;
-            } else if uval <= ASN1_MAX_INT_3 {
-                ival = (ASN1_MAX_INT_3 - uval + 1) as i64;
-            } else if uval <= ASN1_MAX_INT_4 {
-                ival = (ASN1_MAX_INT_4 - uval + 1) as i64;
-            } else if uval <= ASN1_MAX_INT_5 {
-                ival = (ASN1_MAX_INT_5 - uval + 1) as i64;
+    pub fn is_child_of(&self, other :&Asn1Object) -> bool {
+        return other.is_parent_of(self);
+    }
+
+    /*
+        compare with the content octets of OBJECT IDENTIFIER without allocation,
+        the non-minimal subidentifier is not equal
+    */
+    pub fn eq_content(&self, content :&[u8]) -> bool {
+        let mut pos :usize = 0;
+        let mut idx :usize = 0;
+        let mut first :u64 = 0;
+        for s in self.val.split(".") {
+            let ores = u64::from_str_radix(s,10);
+            if ores.is_err() {
+                /*the big arc goes the slow way*/
+                let ores = self.encode_object();
+                if ores.is_err() {
+                    return false;
+                }
+                return ores.unwrap() == content;
+            }
+            let cn :u64 = ores.unwrap();
+            let want :u64;
+            if idx == 0 {
+                first = cn;
+                idx += 1;
+                continue;
+            } else if idx == 1 {
+                let wres = first.checked_mul(40).and_then(|v| v.checked_add(cn));
+                if wres.is_none() {
+                    return false;
+                }
+                want = wres.unwrap();
             } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"invalid uval [0x{:x}]", uval}
+                want = cn;
             }
-
-            asn1obj_log_trace!("ival {}",ival);
-            ival = -ival;
-        } else {                
-            ival = 0;
-            for i in 0..totallen {
-                ival <<= 8;
-                ival += (code[hdrlen+i]) as i64;
+            idx += 1;
+            if pos >= content.len() || content[pos] == 0x80 {
+                return false;
+            }
+            let mut got :u64 = 0;
+            loop {
+                if pos >= content.len() || got > (u64::MAX >> 7) {
+                    return false;
+                }
+                let c :u8 = content[pos];
+                pos += 1;
+                got = (got << 7) | ((c & 0x7f) as u64);
+                if (c & 0x80) == 0 {
+                    break;
+                }
+            }
+            if got != want {
+                return false;
             }
         }
-        self.val = ival;
-        asn1obj_log_trace!("Asn1Enumerated {}",self.val);
-        self.data = Vec::new();
-        for i in 0..(hdrlen + totallen) {
-            self.data.push(code[i]);
+        return pos == content.len();
+    }
+
+    /*
+        compare with the whole encoded OBJECT IDENTIFIER with tag and length
+    */
+    pub fn eq_encoded(&self, code :&[u8]) -> bool {
+        let ores = asn1obj_extract_header(code);
+        if ores.is_err() {
+            return false;
         }
-        retv= hdrlen + totallen;
-        Ok(retv)
+        let (flag,hdrlen,totallen) = ores.unwrap();
+        if flag != ASN1_OBJECT_FLAG as u64 || code.len() != (hdrlen + totallen) {
+            return false;
+        }
+        return self.eq_content(&code[hdrlen..]);
     }
+}
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let mut retv :Vec<u8> = Vec::new();
-        retv.push(ASN1_ENUMERATED_FLAG);
-        retv.push(8);
-        if self.val >= 0 {
-            if self.val < ASN1_MAX_INT_NEG_1 as i64 {
-                retv.push((self.val & 0xff) as u8);
-                retv[1] = 1;
-            } else if self.val < ASN1_MAX_INT_NEG_2 as i64 {
-                retv.push(((self.val >> 8) & 0xff) as u8);
-                retv.push((self.val & 0xff) as u8);
-                retv[1] = 2;
-            } else if self.val < ASN1_MAX_INT_NEG_3 as i64 {
-                retv.push(((self.val >> 16) & 0xff) as u8);
-                retv.push(((self.val >> 8) & 0xff) as u8);
-                retv.push((self.val & 0xff) as u8);
-                retv[1] = 3;
-            } else if self.val < ASN1_MAX_INT_NEG_4 as i64 {
-                retv.push(((self.val >> 24) & 0xff) as u8);
-                retv.push(((self.val >> 16) & 0xff) as u8);
-                retv.push(((self.val >> 8) & 0xff) as u8);
-                retv.push((self.val & 0xff) as u8);
-                retv[1] = 4;
-            } else if self.val < ASN1_MAX_INT_NEG_5 as i64 {
-                retv.push(((self.val >> 32) & 0xff) as u8);
-                retv.push(((self.val >> 24) & 0xff) as u8);
-                retv.push(((self.val >> 16) & 0xff) as u8);
-                retv.push(((self.val >> 8) & 0xff) as u8);
-                retv.push((self.val & 0xff) as u8);
-                retv[1] = 5;
-            } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"value [0x{:x}] > [0x{:x}]", self.val, ASN1_MAX_INT_NEG_4}
-            }
-        } else {
-            let ival :i64 = - self.val;
-            let mut uval :u64 = self.val as u64;
-            uval = uval ^ 0;
-            asn1obj_log_trace!("ival [{}] uval [{}]",ival,uval);
-            if ival <= ASN1_MAX_INT_NEG_1 as i64 {
-                retv.push((uval & 0xff) as u8);
-                retv[1] = 1;
-            } else if ival <= ASN1_MAX_INT_NEG_2 as i64 {
-                retv.push(((uval >> 8) & 0xff) as u8);
-                retv.push((uval & 0xff) as u8);
-                retv[1] = 2;
-            } else if ival <= ASN1_MAX_INT_NEG_3 as i64 {
-                retv.push(((uval >> 16) & 0xff) as u8);
-                retv.push(((uval >> 8) & 0xff) as u8);
-                retv.push((uval & 0xff) as u8);
-                retv[1] = 3;
-            } else if ival <= ASN1_MAX_INT_NEG_4 as i64 {
-                retv.push(((uval >> 24) & 0xff) as u8);
-                retv.push(((uval >> 16) & 0xff) as u8);
-                retv.push(((uval >> 8) & 0xff) as u8);
-                retv.push((uval & 0xff) as u8);
-                retv[1] = 4;
-            } else if ival <= ASN1_MAX_INT_NEG_5 as i64 {
-                retv.push(((uval >> 32) & 0xff ) as u8);
-                retv.push(((uval >> 24) & 0xff) as u8);
-                retv.push(((uval >> 16) & 0xff) as u8);
-                retv.push(((uval >> 8) & 0xff) as u8);
-                retv.push((uval & 0xff) as u8);
-                retv[1] = 5;
-            } else {
-                asn1obj_new_error!{Asn1ObjBaseError,"neg value [0x{:x}] >= [0x{:x}]", uval, ASN1_MAX_INT_NEG_4}
-            }
-            asn1obj_log_trace!("retv {:?}", retv);
+/*
+    the object identifier checked and encoded at compile time by oid!("1.2.840.113549"),
+    code is the content octets without tag and length
+*/
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub struct Asn1StaticOid {
+    val :&'static str,
+    code :&'static [u8],
+}
+
+impl Asn1StaticOid {
+    /*only for oid!, the code is not checked with val*/
+    #[doc(hidden)]
+    pub const fn from_encoded(val :&'static str, code :&'static [u8]) -> Self {
+        Asn1StaticOid {
+            val : val,
+            code : code,
         }
-        Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_ENUMERATED {}", name, self.val)));
-        iowriter.write(s.as_bytes())?;
-        Ok(())
+    pub fn get_value(&self) -> &'static str {
+        return self.val;
+    }
+
+    pub fn get_content(&self) -> &'static [u8] {
+        return self.code;
+    }
+
+    pub fn to_object(&self) -> Asn1Object {
+        let mut retv :Asn1Object = Asn1Object::init_asn1();
+        retv.val = self.val.to_string();
+        return retv;
+    }
+}
+
+impl PartialEq<Asn1StaticOid> for Asn1Object {
+    fn eq(&self, other :&Asn1StaticOid) -> bool {
+        return self.eq_content(other.code);
+    }
+}
+
+impl PartialEq<Asn1Object> for Asn1StaticOid {
+    fn eq(&self, other :&Asn1Object) -> bool {
+        return other.eq_content(self.code);
     }
 }
 
+impl From<Asn1StaticOid> for Asn1Object {
+    fn from(v :Asn1StaticOid) -> Asn1Object {
+        return v.to_object();
+    }
+}
 
 #[derive(Clone)]
-pub struct Asn1String {
-    pub val :String,
+pub struct Asn1RelativeOid {
+    val :String,
     data :Vec<u8>,
 }
 
+impl PartialEq for Asn1RelativeOid {
+    fn eq(&self, other :&Self) -> bool {
+        return self.val.eq(&other.val);
+    }
+}
+
+impl Asn1RelativeOid {
+    pub fn set_value(&mut self,val :&str) -> Result<String,Box<dyn Error>> {
+        if !val.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not only digits and dots", val}
+        }
+        if val.split(".").any(|a| a.len() == 0) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] has empty arc", val}
+        }
+        let oldstr = format!("{}",self.val);
+        self.val = val.to_string();
+        Ok(oldstr)
+    }
 
+    pub fn get_value(&self) -> String {
+        return format!("{}",self.val);
+    }
+}
 
-impl Asn1Op for Asn1String {
+impl Asn1Op for Asn1RelativeOid {
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let setjson = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
+        let setjson = serde_json::json!(self.val);
         if key.len() > 0 {
-            val[key] = setjson;    
+            val[key] = setjson;
         } else {
             *val = setjson;
         }
-        
         Ok(1)
     }
 
@@ -2165,7 +3161,7 @@ impl Asn1Op for Asn1String {
         if key.len() > 0 {
             let ores = val.get(key);
             if ores.is_none() {
-                self.val = "".to_string();
+                let _ = self.set_value(ASN1_RELATIVE_OID_DEFAULT_STR)?;
                 self.data = Vec::new();
                 return Ok(0);
             }
@@ -2173,20 +3169,17 @@ impl Asn1Op for Asn1String {
         } else {
             vmap = val.clone();
         }
-        if  !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string",key}
-        }
-
-        if vmap.is_string() {
-            let c = vmap.as_str().unwrap();
-            self.val = format!("{}",c);
+        if !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not valid string {:?}",key,vmap}
         }
+        let _ = self.set_value(vmap.as_str().unwrap())?;
+        self.data = Vec::new();
         return Ok(1);
     }
 
     fn init_asn1() -> Self {
-        Asn1String {
-            val : "".to_string(),
+        Asn1RelativeOid {
+            val : ASN1_RELATIVE_OID_DEFAULT_STR.to_string(),
             data : Vec::new(),
         }
     }
@@ -2194,74 +3187,160 @@ impl Asn1Op for Asn1String {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
-        if flag != ASN1_UTF8STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_UTF8STRING_FLAG [0x{:02x}]", flag,ASN1_UTF8STRING_FLAG}
+        if flag != ASN1_RELATIVE_OID_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_RELATIVE_OID_FLAG [0x{:02x}]", flag,ASN1_RELATIVE_OID_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+        if totallen < 1 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"need 1 length"}
         }
 
-
-        let mut retm = BytesMut::with_capacity(totallen);
-        for i in 0..totallen {
-            retm.put_u8(code[hdrlen + i]);
+        let ores = asn1obj_decode_arcs(&code[hdrlen..(hdrlen+totallen)],false);
+        if ores.is_err() {
+            return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
         }
-        let a = retm.freeze();
-        self.val = String::from_utf8_lossy(&a).to_string();
-        asn1obj_log_trace!("Asn1String [{}]",self.val);
-        self.data = Vec::new();
+        self.val = ores.unwrap();
+        asn1obj_log_trace!("Asn1RelativeOid [{}]",self.val);
         retv = hdrlen + totallen;
-        for i in 0..retv {
-            self.data.push(code[i]);
-        }
+        self.data = code[0..retv].to_vec();
         Ok(retv)
     }
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let vcode = self.val.as_bytes();
-        let llen :u64 = (vcode.len() ) as u64;
-        let mut retv :Vec<u8>;
-
-        retv = asn1obj_format_header(ASN1_UTF8STRING_FLAG as u64,llen);
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
 
-        for i in 0..vcode.len() {
-            retv.push(vcode[i]);
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        if self.val.len() == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not set val yet"}
         }
+        let vv :Vec<u8> = asn1obj_encode_arcs(&self.val,false)?;
+        let mut retv :Vec<u8> = asn1obj_format_header(ASN1_RELATIVE_OID_FLAG as u64,vv.len() as u64)?;
+        retv.extend_from_slice(&vv);
         Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_STRING {}", name, self.val)));
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        if self.val.len() == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not set val yet"}
+        }
+        let clen :usize = asn1obj_arcs_len(&self.val,false)?;
+        let hdrlen = asn1obj_header_len(ASN1_RELATIVE_OID_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_RELATIVE_OID_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_RELATIVE_OID {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
         Ok(())
     }
 }
 
+/*
+    the arcs of OID-IRI and RELATIVE-OID-IRI are the unicode labels split by /,
+    OID-IRI starts with / and RELATIVE-OID-IRI does not
+*/
+fn asn1obj_check_iri(val :&str, relative :bool) -> Result<(),Box<dyn Error>> {
+    let mut c :&str = val;
+    if !relative {
+        if !c.starts_with("/") {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not start with /", val}
+        }
+        c = &c[1..];
+    }
+    for l in c.split("/") {
+        if l.len() == 0 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"empty arc in [{}]", val}
+        }
+        for ch in l.chars() {
+            if ch.is_whitespace() || ch.is_control() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"arc [{}] not valid in [{}]", l, val}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn asn1obj_decode_iri(code :&[u8], tag :u64, relative :bool) -> Result<(String,usize),Box<dyn Error>> {
+    if code.len() < 2 {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+    }
+    let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+    if flag != tag {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != [0x{:02x}]", flag,tag}
+    }
+    if code.len() < (hdrlen + totallen) {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    }
+    let ores = std::str::from_utf8(&code[hdrlen..(hdrlen+totallen)]);
+    if ores.is_err() {
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"not valid utf8 {:?}", ores.err().unwrap()}
+    }
+    let s = ores.unwrap();
+    let ores = asn1obj_check_iri(s,relative);
+    if ores.is_err() {
+        return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+    }
+    Ok((s.to_string(),hdrlen + totallen))
+}
+
 #[derive(Clone)]
-pub struct Asn1PrintableString {
-    pub val :String,
-    pub flag :u8,
+pub struct Asn1OidIri {
+    val :String,
     data :Vec<u8>,
 }
 
-impl Asn1Op for Asn1PrintableString {
+impl Asn1OidIri {
+    pub fn set_value(&mut self,val :&str) -> Result<String,Box<dyn Error>> {
+        asn1obj_check_iri(val,false)?;
+        let oldstr = format!("{}",self.val);
+        self.val = val.to_string();
+        Ok(oldstr)
+    }
+
+    pub fn get_value(&self) -> String {
+        return format!("{}",self.val);
+    }
+
+    /*
+        the full iri of the relative iri under self
+    */
+    pub fn join_relative(&self, rel :&Asn1RelativeOidIri) -> Result<Asn1OidIri,Box<dyn Error>> {
+        let mut retv :Asn1OidIri = Asn1OidIri::init_asn1();
+        let _ = retv.set_value(&format!("{}/{}",self.val,rel.get_value()))?;
+        Ok(retv)
+    }
+}
+
+impl Asn1Op for Asn1OidIri {
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
-        let cs = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
-        let ci = serde_json::from_str(&format!("{}",self.flag)).unwrap();
-        setjson[ASN1_JSON_PRINTABLE_STRING] = cs;
-        setjson[ASN1_JSON_INNER_FLAG] = ci;
+        let setjson = serde_json::json!(self.val);
         if key.len() > 0 {
-            val[key] = setjson;    
+            val[key] = setjson;
         } else {
             *val = setjson;
         }
-        
         Ok(1)
     }
 
@@ -2270,7 +3349,7 @@ impl Asn1Op for Asn1PrintableString {
         if key.len() > 0 {
             let ores = val.get(key);
             if ores.is_none() {
-                self.val = "".to_string();
+                let _ = self.set_value(ASN1_OID_IRI_DEFAULT_STR)?;
                 self.data = Vec::new();
                 return Ok(0);
             }
@@ -2278,118 +3357,176 @@ impl Asn1Op for Asn1PrintableString {
         } else {
             vmap = val.clone();
         }
-        if  !vmap.is_string() && !vmap.is_object() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or object",key}
-        }
-
-        if vmap.is_string() {
-            let c = vmap.as_str().unwrap();
-            self.val = format!("{}",c);
-            self.flag = ASN1_PRINTABLE_FLAG;
-        } else if vmap.is_object() {
-            let c = vmap.as_object().unwrap();
-            let k = c.get(ASN1_JSON_PRINTABLE_STRING);
-            if k.is_none() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_PRINTABLE_STRING}
-            } 
-            let k = k.unwrap();
-            if !k.is_string() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not string",key,ASN1_JSON_PRINTABLE_STRING}
-            }
-            self.val = format!("{}",k.as_str().unwrap());
-            self.flag = ASN1_PRINTABLE_FLAG;
-            let k = c.get(ASN1_JSON_INNER_FLAG);
-            if k.is_some()  {
-                let k = k.unwrap();
-                if k.is_i64() {
-                    let ival = k.as_i64().unwrap() as u8;
-                    if ival != ASN1_PRINTABLE_FLAG && ival != ASN1_PRINTABLE2_FLAG  && ival != ASN1_UTF8STRING_FLAG && ival != ASN1_T61STRING_FLAG {
-                        asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
-                    }
-                    self.flag = ival;                    
-                }
-            }
+        if !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not valid string {:?}",key,vmap}
         }
+        let _ = self.set_value(vmap.as_str().unwrap())?;
+        self.data = Vec::new();
         return Ok(1);
     }
 
     fn init_asn1() -> Self {
-        Asn1PrintableString {
-            val : "".to_string(),
-            flag : ASN1_PRINTABLE_FLAG,
+        Asn1OidIri {
+            val : ASN1_OID_IRI_DEFAULT_STR.to_string(),
             data : Vec::new(),
         }
     }
 
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
-        let retv :usize;
-        if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
-        }
-        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+        let (s,retv) = asn1obj_decode_iri(code,asn1obj_make_tag(0,ASN1_OID_IRI_TAG),false)?;
+        self.val = s;
+        self.data = code[0..retv].to_vec();
+        Ok(retv)
+    }
 
-        if flag != ASN1_PRINTABLE_FLAG as u64 && flag != ASN1_PRINTABLE2_FLAG as u64  && flag != ASN1_UTF8STRING_FLAG as u64 && flag != ASN1_T61STRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != (ASN1_PRINTABLE_FLAG [0x{:02x}] || ASN1_PRINTABLE2_FLAG [0x{:02x}] || ASN1_UTF8STRING_FLAG [0x{:02x}] || ASN1_T61STRING_FLAG[0x{:02x}] )", flag,ASN1_PRINTABLE_FLAG,ASN1_PRINTABLE2_FLAG,ASN1_UTF8STRING_FLAG,ASN1_T61STRING_FLAG}
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
         }
+        return Some(self.data.clone());
+    }
 
-        self.flag = flag as u8;
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let mut retv :Vec<u8> = asn1obj_format_header(asn1obj_make_tag(0,ASN1_OID_IRI_TAG),self.val.len() as u64)?;
+        retv.extend_from_slice(self.val.as_bytes());
+        Ok(retv)
+    }
 
-        if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
         }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(asn1obj_make_tag(0,ASN1_OID_IRI_TAG), clen as u64)?;
+        Ok(hdrlen + clen)
+    }
 
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(0,ASN1_OID_IRI_TAG)));
+    }
 
-        let mut retm = BytesMut::with_capacity(totallen);
-        for i in 0..totallen {
-            retm.put_u8(code[hdrlen + i]);
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_OID_IRI {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Asn1RelativeOidIri {
+    val :String,
+    data :Vec<u8>,
+}
+
+impl Asn1RelativeOidIri {
+    pub fn set_value(&mut self,val :&str) -> Result<String,Box<dyn Error>> {
+        asn1obj_check_iri(val,true)?;
+        let oldstr = format!("{}",self.val);
+        self.val = val.to_string();
+        Ok(oldstr)
+    }
+
+    pub fn get_value(&self) -> String {
+        return format!("{}",self.val);
+    }
+}
+
+impl Asn1Op for Asn1RelativeOidIri {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let setjson = serde_json::json!(self.val);
+        if key.len() > 0 {
+            val[key] = setjson;
+        } else {
+            *val = setjson;
         }
-        let a = retm.freeze();
-        self.val = String::from_utf8_lossy(&a).to_string();
-        asn1obj_log_trace!("Asn1PrintableString [{}]",self.val);
+        Ok(1)
+    }
+
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                let _ = self.set_value(ASN1_RELATIVE_OID_IRI_DEFAULT_STR)?;
+                self.data = Vec::new();
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not valid string {:?}",key,vmap}
+        }
+        let _ = self.set_value(vmap.as_str().unwrap())?;
         self.data = Vec::new();
-        retv = hdrlen + totallen;
-        for i in 0..retv {
-            self.data.push(code[i]);
+        return Ok(1);
+    }
+
+    fn init_asn1() -> Self {
+        Asn1RelativeOidIri {
+            val : ASN1_RELATIVE_OID_IRI_DEFAULT_STR.to_string(),
+            data : Vec::new(),
         }
-        Ok(retv)
     }
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let vcode = self.val.as_bytes();
-        let llen :u64 = (vcode.len() ) as u64;
-        let mut retv :Vec<u8>;
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let (s,retv) = asn1obj_decode_iri(code,asn1obj_make_tag(0,ASN1_RELATIVE_OID_IRI_TAG),true)?;
+        self.val = s;
+        self.data = code[0..retv].to_vec();
+        Ok(retv)
+    }
 
-        retv = asn1obj_format_header(self.flag as u64,llen);
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
 
-        for i in 0..vcode.len() {
-            retv.push(vcode[i]);
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
         }
+        let mut retv :Vec<u8> = asn1obj_format_header(asn1obj_make_tag(0,ASN1_RELATIVE_OID_IRI_TAG),self.val.len() as u64)?;
+        retv.extend_from_slice(self.val.as_bytes());
         Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_PRINTABLE_STRING {}", name, self.val)));
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(asn1obj_make_tag(0,ASN1_RELATIVE_OID_IRI_TAG), clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(0,ASN1_RELATIVE_OID_IRI_TAG)));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_RELATIVE_OID_IRI {}", name, self.val)));
         iowriter.write(s.as_bytes())?;
         Ok(())
     }
 }
 
-
 #[derive(Clone)]
-pub struct Asn1IA5String {
-    pub val :String,
-    pub flag :u8,
+pub struct Asn1Enumerated {
+    pub val :i64,
     data :Vec<u8>,
 }
 
-
-impl Asn1Op for Asn1IA5String {
+impl Asn1Op for Asn1Enumerated {
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
-        let cs = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
-        let ci = serde_json::from_str(&format!("{}",self.flag)).unwrap();
-        setjson[ASN1_JSON_IA5STRING] = cs;
-        setjson[ASN1_JSON_INNER_FLAG] = ci;
+        let setjson = serde_json::from_str(&format!("{}",self.val)).unwrap();
         if key.len() > 0 {
             val[key] = setjson;    
         } else {
@@ -2404,7 +3541,7 @@ impl Asn1Op for Asn1IA5String {
         if key.len() > 0 {
             let ores = val.get(key);
             if ores.is_none() {
-                self.val = "".to_string();
+                self.val = 0;
                 self.data = Vec::new();
                 return Ok(0);
             }
@@ -2412,35 +3549,20 @@ impl Asn1Op for Asn1IA5String {
         } else {
             vmap = val.clone();
         }
-        if  !vmap.is_string() && !vmap.is_object() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or object",key}
+        if !vmap.is_i64() && !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or i64",key}
         }
-
-        if vmap.is_string() {
+        if vmap.is_i64() {
+            let c = vmap.as_i64().unwrap();
+            self.val = c ;
+        } else if vmap.is_string() {
             let c = vmap.as_str().unwrap();
-            self.val = format!("{}",c);
-            self.flag = ASN1_PRINTABLE_FLAG;
-        } else if vmap.is_object() {
-            let c = vmap.as_object().unwrap();
-            let k = c.get(ASN1_JSON_IA5STRING);
-            if k.is_none() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_IA5STRING}
-            } 
-            let k = k.unwrap();
-            if !k.is_string() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not string",key,ASN1_JSON_IA5STRING}
-            }
-            self.val = format!("{}",k.as_str().unwrap());
-            self.flag = ASN1_PRINTABLE2_FLAG;
-            let k = c.get(ASN1_JSON_INNER_FLAG);
-            if k.is_some()  {
-                let k = k.unwrap();
-                if k.is_i64() {
-                    let ival = k.as_i64().unwrap() as u8;
-                    if ival != ASN1_PRINTABLE2_FLAG  {
-                        asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
-                    }
-                    self.flag = ival;                    
+            match c.parse::<i64>() {
+                Ok(fi) => {
+                    self.val = fi;
+                },
+                Err(e) => {
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} val {} error {:?}", key,c,e}
                 }
             }
         }
@@ -2448,473 +3570,1928 @@ impl Asn1Op for Asn1IA5String {
     }
 
     fn init_asn1() -> Self {
-        Asn1IA5String {
-            val : "".to_string(),
-            flag : ASN1_PRINTABLE2_FLAG,
+        Asn1Enumerated {
+            val : 0,
             data : Vec::new(),
         }
     }
 
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
+        let mut ival :i64;
+        let mut neg :bool = false;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
-        if flag != ASN1_PRINTABLE2_FLAG as u64   {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != (ASN1_PRINTABLE2_FLAG [0x{:02x}])", flag,ASN1_PRINTABLE2_FLAG}
+        if flag != ASN1_ENUMERATED_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_ENUMERATED_FLAG [0x{:02x}]", flag,ASN1_ENUMERATED_FLAG}
         }
 
-        self.flag = flag as u8;
-
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
-
-        let mut retm = BytesMut::with_capacity(totallen);
-        for i in 0..totallen {
-            retm.put_u8(code[hdrlen + i]);
+        if totallen < 1 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"need 1 length"}
         }
-        let a = retm.freeze();
-        self.val = String::from_utf8_lossy(&a).to_string();
-        asn1obj_log_trace!("Asn1IA5String [{}]",self.val);
-        self.data = Vec::new();
-        retv = hdrlen + totallen;
-        for i in 0..retv {
-            self.data.push(code[i]);
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_integer(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
+        }
+        if (code[hdrlen] & 0x80) != 0 {
+            neg = true;
         }
-        Ok(retv)
-    }
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let vcode = self.val.as_bytes();
-        let llen :u64 = (vcode.len() ) as u64;
-        let mut retv :Vec<u8>;
 
-        retv = asn1obj_format_header(self.flag as u64,llen);
 
-        for i in 0..vcode.len() {
-            retv.push(vcode[i]);
+        if neg {
+            let mut uval :u64;
+            uval = 0;
+            for i in 0..totallen {
+                uval <<= 8;
+                uval += (code[hdrlen+i]) as u64;
+                asn1obj_log_trace!("[0x{:x}]", uval);
+            }
+
+            if uval <= ASN1_MAX_INT_1 {
+                ival = (ASN1_MAX_INT_1 - uval + 1) as i64;
+            } else if uval <= ASN1_MAX_INT_2 {
+                ival = (ASN1_MAX_INT_2 - uval + 1) as i64;
+            } else if uval <= ASN1_MAX_INT_3 {
+                ival = (ASN1_MAX_INT_3 - uval + 1) as i64;
+            } else if uval <= ASN1_MAX_INT_4 {
+                ival = (ASN1_MAX_INT_4 - uval + 1) as i64;
+            } else if uval <= ASN1_MAX_INT_5 {
+                ival = (ASN1_MAX_INT_5 - uval + 1) as i64;
+            } else {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"invalid uval [0x{:x}]", uval}
+            }
+
+            asn1obj_log_trace!("ival {}",ival);
+            ival = -ival;
+        } else {                
+            ival = 0;
+            for i in 0..totallen {
+                ival <<= 8;
+                ival += (code[hdrlen+i]) as i64;
+            }
         }
+        self.val = ival;
+        asn1obj_log_trace!("Asn1Enumerated {}",self.val);
+        self.data = Vec::new();
+        for i in 0..(hdrlen + totallen) {
+            self.data.push(code[i]);
+        }
+        retv= hdrlen + totallen;
         Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s = asn1_format_line(tab,&(format!("{}: ASN1_IA5STRING {}", name, self.val)));
-        iowriter.write(s.as_bytes())?;
-        Ok(())
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
     }
-}
 
-#[derive(Clone)]
-pub struct Asn1Time {
-    val :String,
-    origval : String,
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let mut retv :Vec<u8> = Vec::new();
+        retv.push(ASN1_ENUMERATED_FLAG);
+        retv.push(8);
+        if self.val >= 0 {
+            if self.val < ASN1_MAX_INT_NEG_1 as i64 {
+                retv.push((self.val & 0xff) as u8);
+                retv[1] = 1;
+            } else if self.val < ASN1_MAX_INT_NEG_2 as i64 {
+                retv.push(((self.val >> 8) & 0xff) as u8);
+                retv.push((self.val & 0xff) as u8);
+                retv[1] = 2;
+            } else if self.val < ASN1_MAX_INT_NEG_3 as i64 {
+                retv.push(((self.val >> 16) & 0xff) as u8);
+                retv.push(((self.val >> 8) & 0xff) as u8);
+                retv.push((self.val & 0xff) as u8);
+                retv[1] = 3;
+            } else if self.val < ASN1_MAX_INT_NEG_4 as i64 {
+                retv.push(((self.val >> 24) & 0xff) as u8);
+                retv.push(((self.val >> 16) & 0xff) as u8);
+                retv.push(((self.val >> 8) & 0xff) as u8);
+                retv.push((self.val & 0xff) as u8);
+                retv[1] = 4;
+            } else if self.val < ASN1_MAX_INT_NEG_5 as i64 {
+                retv.push(((self.val >> 32) & 0xff) as u8);
+                retv.push(((self.val >> 24) & 0xff) as u8);
+                retv.push(((self.val >> 16) & 0xff) as u8);
+                retv.push(((self.val >> 8) & 0xff) as u8);
+                retv.push((self.val & 0xff) as u8);
+                retv[1] = 5;
+            } else {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"value [0x{:x}] > [0x{:x}]", self.val, ASN1_MAX_INT_NEG_4}
+            }
+        } else {
+            let ival :i64 = - self.val;
+            let mut uval :u64 = self.val as u64;
+            uval = uval ^ 0;
+            asn1obj_log_trace!("ival [{}] uval [{}]",ival,uval);
+            if ival <= ASN1_MAX_INT_NEG_1 as i64 {
+                retv.push((uval & 0xff) as u8);
+                retv[1] = 1;
+            } else if ival <= ASN1_MAX_INT_NEG_2 as i64 {
+                retv.push(((uval >> 8) & 0xff) as u8);
+                retv.push((uval & 0xff) as u8);
+                retv[1] = 2;
+            } else if ival <= ASN1_MAX_INT_NEG_3 as i64 {
+                retv.push(((uval >> 16) & 0xff) as u8);
+                retv.push(((uval >> 8) & 0xff) as u8);
+                retv.push((uval & 0xff) as u8);
+                retv[1] = 3;
+            } else if ival <= ASN1_MAX_INT_NEG_4 as i64 {
+                retv.push(((uval >> 24) & 0xff) as u8);
+                retv.push(((uval >> 16) & 0xff) as u8);
+                retv.push(((uval >> 8) & 0xff) as u8);
+                retv.push((uval & 0xff) as u8);
+                retv[1] = 4;
+            } else if ival <= ASN1_MAX_INT_NEG_5 as i64 {
+                retv.push(((uval >> 32) & 0xff ) as u8);
+                retv.push(((uval >> 24) & 0xff) as u8);
+                retv.push(((uval >> 16) & 0xff) as u8);
+                retv.push(((uval >> 8) & 0xff) as u8);
+                retv.push((uval & 0xff) as u8);
+                retv[1] = 5;
+            } else {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"neg value [0x{:x}] >= [0x{:x}]", uval, ASN1_MAX_INT_NEG_4}
+            }
+            asn1obj_log_trace!("retv {:?}", retv);
+        }
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = asn1obj_int_content_len(self.val)?;
+        let hdrlen = asn1obj_header_len(ASN1_ENUMERATED_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_ENUMERATED_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_ENUMERATED {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+
+#[derive(Clone)]
+pub struct Asn1String {
+    pub val :String,
     data :Vec<u8>,
-    utag :u8,
+    bytes :Vec<u8>,
 }
 
 
-impl Asn1Time {
 
-    fn parse_value(&self, s :&str) -> Result<i64,Box<dyn Error>> {
-        match i64::from_str_radix(s,10) {
-            Ok(v) => {              
-                return Ok(v);
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"parse [{}] error[{:?}]",s,e}
-            }
+impl Asn1String {
+    pub fn get_bytes(&self) -> Vec<u8> {
+        return asn1obj_string_get_bytes(&self.bytes,&self.val);
+    }
+}
+
+impl Asn1Op for Asn1String {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let setjson = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
+        if key.len() > 0 {
+            val[key] = setjson;    
+        } else {
+            *val = setjson;
         }
+        
+        Ok(1)
     }
 
-    fn format_time_str(&self, year :i64, mon :i64,mday :i64,hour :i64, min :i64,sec :i64) -> String {
-        return format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year,mon,mday,hour,min,sec);
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = "".to_string();
+                self.data = Vec::new();
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if  !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string",key}
+        }
+
+        if vmap.is_string() {
+            let c = vmap.as_str().unwrap();
+            self.val = format!("{}",c);
+        }
+        return Ok(1);
     }
 
-    fn get_time_val(&self, times :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
-        let mut year :i64;
-        let mut mon :i64;
-        let mut mday :i64;
-        let mut hour :i64;
-        let mut min :i64;
-        let mut sec :i64;
-        asn1obj_log_trace!("times [{}]", times);
+    fn init_asn1() -> Self {
+        Asn1String {
+            val : "".to_string(),
+            data : Vec::new(),
+            bytes : Vec::new(),
+        }
+    }
 
-        if times.len() == 10 {
-            year = self.parse_value(&times[0..4])?;
-            mon = self.parse_value(&times[4..6])?;
-            mday = self.parse_value(&times[6..8])?;
-            hour = self.parse_value(&times[8..10])?;
-            min = 0;
-            sec = 0;
-            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
-            if ov.is_ok() {
-                return Ok((year,mon,mday,hour,min,sec));
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_UTF8STRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_UTF8STRING_FLAG)?;
+            let ores = self.decode_asn1(&flatv);
+            self.data = code[0..clen].to_vec();
+            if ores.is_err() {
+                return Err(asn1obj_error_at(ores.err().unwrap(),0));
             }
+            return Ok(clen);
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
-            /**/
-            year = self.parse_value(&times[0..2])?;
-            if year < 70 {
-                year += 2000; 
-            } else {
-                year += 1900;
-            }
-            mon = self.parse_value(&times[2..4])?;
-            mday = self.parse_value(&times[4..6])?;
-            hour = self.parse_value(&times[6..8])?;
-            min = self.parse_value(&times[8..10])?;
-            sec = 0;
-            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
-            if ov.is_err() {
-                let e = ov.err().unwrap();
-                return Err(e);
-            }
-            return Ok((year,mon,mday,hour,min,sec));
-        } 
+        if flag != ASN1_UTF8STRING_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_UTF8STRING_FLAG [0x{:02x}]", flag,ASN1_UTF8STRING_FLAG}
+        }
 
-        if times.len() == 12 {
-            year = self.parse_value(&times[0..4])?;
-            mon = self.parse_value(&times[4..6])?;
-            mday = self.parse_value(&times[6..8])?;
-            hour = self.parse_value(&times[8..10])?;
-            min = self.parse_value(&times[10..12])?;
-            sec = 0;
-            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
-            if ov.is_ok() {
-                return Ok((year,mon,mday,hour,min,sec));
-            }
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
 
-            /**/
-            year = self.parse_value(&times[0..2])?;
-            if year < 70 {
-                year += 2000; 
-            } else {
-                year += 1900;
-            }
-            mon = self.parse_value(&times[2..4])?;
-            mday = self.parse_value(&times[4..6])?;
-            hour = self.parse_value(&times[6..8])?;
-            min = self.parse_value(&times[8..10])?;
-            sec = self.parse_value(&times[10..12])?;
-            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
-            if ov.is_err() {
-                let e = ov.err().unwrap();
-                return Err(e);
+
+        retv = hdrlen + totallen;
+        self.bytes = code[hdrlen..retv].to_vec();
+        self.data = code[0..retv].to_vec();
+        if asn1obj_is_strict_string() {
+            let ores = asn1obj_strict_string(ASN1_UTF8STRING_FLAG,&self.bytes);
+            if ores.is_err() {
+                self.val = String::from_utf8_lossy(&self.bytes).to_string();
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
             }
-            return Ok((year,mon,mday,hour,min,sec));
+            self.val = ores.unwrap();
+        } else {
+            self.val = String::from_utf8_lossy(&self.bytes).to_string();
         }
+        asn1obj_log_trace!("Asn1String [{}]",self.val);
+        Ok(retv)
+    }
 
-        if times.len() >= 14 {
-            year = self.parse_value(&times[0..4])?;
-            mon = self.parse_value(&times[4..6])?;
-            mday = self.parse_value(&times[6..8])?;
-            hour = self.parse_value(&times[8..10])?;
-            min = self.parse_value(&times[10..12])?;
-            sec = self.parse_value(&times[12..14])?;
-            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
-            if ov.is_err() {
-                let e = ov.err().unwrap();
-                return Err(e);
-            }
-            return Ok((year,mon,mday,hour,min,sec));
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let vcode = self.val.as_bytes();
+        let llen :u64 = (vcode.len() ) as u64;
+        let mut retv :Vec<u8>;
+
+        retv = asn1obj_format_header(ASN1_UTF8STRING_FLAG as u64,llen)?;
+
+        for i in 0..vcode.len() {
+            retv.push(vcode[i]);
+        }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
         }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(ASN1_UTF8STRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_UTF8STRING_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_STRING {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Asn1PrintableString {
+    pub val :String,
+    pub flag :u8,
+    data :Vec<u8>,
+    bytes :Vec<u8>,
+}
+
+impl Asn1PrintableString {
+    pub fn get_bytes(&self) -> Vec<u8> {
+        return asn1obj_string_get_bytes(&self.bytes,&self.val);
+    }
+}
+
+impl Asn1Op for Asn1PrintableString {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
+        let cs = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
+        let ci = serde_json::from_str(&format!("{}",self.flag)).unwrap();
+        setjson[ASN1_JSON_PRINTABLE_STRING] = cs;
+        setjson[ASN1_JSON_INNER_FLAG] = ci;
+        if key.len() > 0 {
+            val[key] = setjson;    
+        } else {
+            *val = setjson;
+        }
+        
+        Ok(1)
+    }
+
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = "".to_string();
+                self.data = Vec::new();
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if  !vmap.is_string() && !vmap.is_object() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or object",key}
+        }
+
+        if vmap.is_string() {
+            let c = vmap.as_str().unwrap();
+            self.val = format!("{}",c);
+            self.flag = ASN1_PRINTABLE_FLAG;
+        } else if vmap.is_object() {
+            let c = vmap.as_object().unwrap();
+            let k = c.get(ASN1_JSON_PRINTABLE_STRING);
+            if k.is_none() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_PRINTABLE_STRING}
+            } 
+            let k = k.unwrap();
+            if !k.is_string() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not string",key,ASN1_JSON_PRINTABLE_STRING}
+            }
+            self.val = format!("{}",k.as_str().unwrap());
+            self.flag = ASN1_PRINTABLE_FLAG;
+            let k = c.get(ASN1_JSON_INNER_FLAG);
+            if k.is_some()  {
+                let k = k.unwrap();
+                if k.is_i64() {
+                    let ival = k.as_i64().unwrap() as u8;
+                    if ival != ASN1_PRINTABLE_FLAG && ival != ASN1_PRINTABLE2_FLAG  && ival != ASN1_UTF8STRING_FLAG && ival != ASN1_T61STRING_FLAG {
+                        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
+                    }
+                    self.flag = ival;                    
+                }
+            }
+        }
+        return Ok(1);
+    }
+
+    fn init_asn1() -> Self {
+        Asn1PrintableString {
+            val : "".to_string(),
+            flag : ASN1_PRINTABLE_FLAG,
+            data : Vec::new(),
+            bytes : Vec::new(),
+        }
+    }
+
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && (code[0] == (ASN1_PRINTABLE_FLAG | ASN1_CONSTRUCTED) || code[0] == (ASN1_PRINTABLE2_FLAG | ASN1_CONSTRUCTED) || code[0] == (ASN1_UTF8STRING_FLAG | ASN1_CONSTRUCTED) || code[0] == (ASN1_T61STRING_FLAG | ASN1_CONSTRUCTED)) {
+            let (flatv,clen) = asn1obj_flatten_string(code,code[0] & (!ASN1_CONSTRUCTED))?;
+            let ores = self.decode_asn1(&flatv);
+            self.data = code[0..clen].to_vec();
+            if ores.is_err() {
+                return Err(asn1obj_error_at(ores.err().unwrap(),0));
+            }
+            return Ok(clen);
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if flag != ASN1_PRINTABLE_FLAG as u64 && flag != ASN1_PRINTABLE2_FLAG as u64  && flag != ASN1_UTF8STRING_FLAG as u64 && flag != ASN1_T61STRING_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != (ASN1_PRINTABLE_FLAG [0x{:02x}] || ASN1_PRINTABLE2_FLAG [0x{:02x}] || ASN1_UTF8STRING_FLAG [0x{:02x}] || ASN1_T61STRING_FLAG[0x{:02x}] )", flag,ASN1_PRINTABLE_FLAG,ASN1_PRINTABLE2_FLAG,ASN1_UTF8STRING_FLAG,ASN1_T61STRING_FLAG}
+        }
+
+        self.flag = flag as u8;
+
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+
+        retv = hdrlen + totallen;
+        self.bytes = code[hdrlen..retv].to_vec();
+        self.data = code[0..retv].to_vec();
+        if asn1obj_is_strict_string() {
+            let ores = asn1obj_strict_string(self.flag,&self.bytes);
+            if ores.is_err() {
+                self.val = String::from_utf8_lossy(&self.bytes).to_string();
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
+            self.val = ores.unwrap();
+        } else {
+            self.val = String::from_utf8_lossy(&self.bytes).to_string();
+        }
+        asn1obj_log_trace!("Asn1PrintableString [{}]",self.val);
+        Ok(retv)
+    }
+
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let vcode = self.val.as_bytes();
+        let llen :u64 = (vcode.len() ) as u64;
+        let mut retv :Vec<u8>;
+
+        retv = asn1obj_format_header(self.flag as u64,llen)?;
+
+        for i in 0..vcode.len() {
+            retv.push(vcode[i]);
+        }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(self.flag as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(self.flag as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_PRINTABLE_STRING {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+
+#[derive(Clone)]
+pub struct Asn1IA5String {
+    pub val :String,
+    pub flag :u8,
+    data :Vec<u8>,
+    bytes :Vec<u8>,
+}
+
+
+impl Asn1IA5String {
+    pub fn get_bytes(&self) -> Vec<u8> {
+        return asn1obj_string_get_bytes(&self.bytes,&self.val);
+    }
+}
+
+impl Asn1Op for Asn1IA5String {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
+        let cs = serde_json::from_str(&format!("\"{}\"",self.val)).unwrap();
+        let ci = serde_json::from_str(&format!("{}",self.flag)).unwrap();
+        setjson[ASN1_JSON_IA5STRING] = cs;
+        setjson[ASN1_JSON_INNER_FLAG] = ci;
+        if key.len() > 0 {
+            val[key] = setjson;    
+        } else {
+            *val = setjson;
+        }
+        
+        Ok(1)
+    }
+
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = "".to_string();
+                self.data = Vec::new();
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if  !vmap.is_string() && !vmap.is_object() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or object",key}
+        }
+
+        if vmap.is_string() {
+            let c = vmap.as_str().unwrap();
+            self.val = format!("{}",c);
+            self.flag = ASN1_PRINTABLE_FLAG;
+        } else if vmap.is_object() {
+            let c = vmap.as_object().unwrap();
+            let k = c.get(ASN1_JSON_IA5STRING);
+            if k.is_none() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_IA5STRING}
+            } 
+            let k = k.unwrap();
+            if !k.is_string() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not string",key,ASN1_JSON_IA5STRING}
+            }
+            self.val = format!("{}",k.as_str().unwrap());
+            self.flag = ASN1_PRINTABLE2_FLAG;
+            let k = c.get(ASN1_JSON_INNER_FLAG);
+            if k.is_some()  {
+                let k = k.unwrap();
+                if k.is_i64() {
+                    let ival = k.as_i64().unwrap() as u8;
+                    if ival != ASN1_PRINTABLE2_FLAG  {
+                        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
+                    }
+                    self.flag = ival;                    
+                }
+            }
+        }
+        return Ok(1);
+    }
+
+    fn init_asn1() -> Self {
+        Asn1IA5String {
+            val : "".to_string(),
+            flag : ASN1_PRINTABLE2_FLAG,
+            data : Vec::new(),
+            bytes : Vec::new(),
+        }
+    }
+
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_PRINTABLE2_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_PRINTABLE2_FLAG)?;
+            let ores = self.decode_asn1(&flatv);
+            self.data = code[0..clen].to_vec();
+            if ores.is_err() {
+                return Err(asn1obj_error_at(ores.err().unwrap(),0));
+            }
+            return Ok(clen);
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if flag != ASN1_PRINTABLE2_FLAG as u64   {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != (ASN1_PRINTABLE2_FLAG [0x{:02x}])", flag,ASN1_PRINTABLE2_FLAG}
+        }
+
+        self.flag = flag as u8;
+
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+
+        retv = hdrlen + totallen;
+        self.bytes = code[hdrlen..retv].to_vec();
+        self.data = code[0..retv].to_vec();
+        if asn1obj_is_strict_string() {
+            let ores = asn1obj_strict_string(self.flag,&self.bytes);
+            if ores.is_err() {
+                self.val = String::from_utf8_lossy(&self.bytes).to_string();
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
+            self.val = ores.unwrap();
+        } else {
+            self.val = String::from_utf8_lossy(&self.bytes).to_string();
+        }
+        asn1obj_log_trace!("Asn1IA5String [{}]",self.val);
+        Ok(retv)
+    }
+
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let vcode = self.val.as_bytes();
+        let llen :u64 = (vcode.len() ) as u64;
+        let mut retv :Vec<u8>;
+
+        retv = asn1obj_format_header(self.flag as u64,llen)?;
+
+        for i in 0..vcode.len() {
+            retv.push(vcode[i]);
+        }
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.val.len();
+        let hdrlen = asn1obj_header_len(self.flag as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(self.flag as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s = asn1_format_line(tab,&(format!("{}: ASN1_IA5STRING {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+/*
+    restricted character string selected by the universal TAG, the alphabet is checked
+    on both encode and decode:
+        NumericString   : digits and space
+        VisibleString   : 0x20 - 0x7e
+        TeletexString VideotexString : latin1 without the C0 controls other than \t \n \x0c \r \x1b
+        GraphicString   : latin1 without any control
+        GeneralString   : latin1
+        UniversalString : UCS-4 big endian
+    the latin1 octets map to the chars U+0000 - U+00FF one by one, so the String is lossless
+*/
+#[derive(Clone)]
+pub struct Asn1CharString<const TAG :u8> {
+    pub val :String,
+    data :Vec<u8>,
+}
+
+pub type Asn1NumericString = Asn1CharString<ASN1_NUMERICSTRING_FLAG>;
+pub type Asn1VisibleString = Asn1CharString<ASN1_VISIBLESTRING_FLAG>;
+pub type Asn1TeletexString = Asn1CharString<ASN1_T61STRING_FLAG>;
+pub type Asn1VideotexString = Asn1CharString<ASN1_VIDEOTEXSTRING_FLAG>;
+pub type Asn1GraphicString = Asn1CharString<ASN1_GRAPHICSTRING_FLAG>;
+pub type Asn1GeneralString = Asn1CharString<ASN1_GENERALSTRING_FLAG>;
+pub type Asn1UniversalString = Asn1CharString<ASN1_UNIVERSALSTRING_FLAG>;
+
+impl<const TAG :u8> Asn1CharString<TAG> {
+    pub fn set_value(&mut self, val :&str) -> Result<String,Box<dyn Error>> {
+        let _ = Self::encode_chars(val)?;
+        let retv = format!("{}",self.val);
+        self.val = val.to_string();
+        Ok(retv)
+    }
+
+    pub fn get_value(&self) -> String {
+        return format!("{}",self.val);
+    }
+
+    fn type_name() -> &'static str {
+        match TAG {
+            ASN1_NUMERICSTRING_FLAG => "ASN1_NUMERICSTRING",
+            ASN1_VISIBLESTRING_FLAG => "ASN1_VISIBLESTRING",
+            ASN1_T61STRING_FLAG => "ASN1_TELETEXSTRING",
+            ASN1_VIDEOTEXSTRING_FLAG => "ASN1_VIDEOTEXSTRING",
+            ASN1_GRAPHICSTRING_FLAG => "ASN1_GRAPHICSTRING",
+            ASN1_GENERALSTRING_FLAG => "ASN1_GENERALSTRING",
+            ASN1_UNIVERSALSTRING_FLAG => "ASN1_UNIVERSALSTRING",
+            _ => "ASN1_CHARSTRING",
+        }
+    }
+
+    fn valid_char(c :u32) -> bool {
+        match TAG {
+            ASN1_NUMERICSTRING_FLAG => {
+                return c == 0x20 || (c >= 0x30 && c <= 0x39);
+            },
+            ASN1_VISIBLESTRING_FLAG => {
+                return c >= 0x20 && c <= 0x7e;
+            },
+            ASN1_T61STRING_FLAG | ASN1_VIDEOTEXSTRING_FLAG => {
+                if c < 0x20 {
+                    return c == 0x09 || c == 0x0a || c == 0x0c || c == 0x0d || c == 0x1b;
+                }
+                return c <= 0xff;
+            },
+            ASN1_GRAPHICSTRING_FLAG => {
+                return (c >= 0x20 && c <= 0x7e) || (c >= 0xa0 && c <= 0xff);
+            },
+            ASN1_GENERALSTRING_FLAG => {
+                return c <= 0xff;
+            },
+            ASN1_UNIVERSALSTRING_FLAG => {
+                return char::from_u32(c).is_some();
+            },
+            _ => {
+                return false;
+            }
+        }
+    }
+
+    fn encode_chars(val :&str) -> Result<Vec<u8>,Box<dyn Error>> {
+        let mut retv :Vec<u8> = Vec::new();
+        for (i,c) in val.chars().enumerate() {
+            let cv :u32 = c as u32;
+            if !Self::valid_char(cv) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"char [{}] U+{:04X} at [{}] not valid for {}", c, cv, i, Self::type_name()}
+            }
+            if TAG == ASN1_UNIVERSALSTRING_FLAG {
+                retv.extend_from_slice(&cv.to_be_bytes());
+            } else {
+                retv.push(cv as u8);
+            }
+        }
+        Ok(retv)
+    }
+
+    fn chars_len(val :&str) -> Result<usize,Box<dyn Error>> {
+        let mut retv :usize = 0;
+        for (i,c) in val.chars().enumerate() {
+            let cv :u32 = c as u32;
+            if !Self::valid_char(cv) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"char [{}] U+{:04X} at [{}] not valid for {}", c, cv, i, Self::type_name()}
+            }
+            if TAG == ASN1_UNIVERSALSTRING_FLAG {
+                retv += 4;
+            } else {
+                retv += 1;
+            }
+        }
+        Ok(retv)
+    }
+
+    /*
+        the error offset is the invalid char in content
+    */
+    fn decode_chars(content :&[u8]) -> Result<String,Box<dyn Error>> {
+        let mut retv :String = "".to_string();
+        if TAG == ASN1_UNIVERSALSTRING_FLAG {
+            if (content.len() % 4) != 0 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"len {} % 4 != 0",content.len()}
+            }
+            let mut i :usize = 0;
+            while i < content.len() {
+                let cv :u32 = u32::from_be_bytes([content[i],content[i+1],content[i+2],content[i+3]]);
+                if !Self::valid_char(cv) {
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,i,"[0x{:08x}] not valid for {}", cv, Self::type_name()}
+                }
+                retv.push(char::from_u32(cv).unwrap());
+                i += 4;
+            }
+            return Ok(retv);
+        }
+        for i in 0..content.len() {
+            let cv :u32 = content[i] as u32;
+            if !Self::valid_char(cv) {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,i,"[0x{:02x}] not valid for {}", cv, Self::type_name()}
+            }
+            retv.push(char::from_u32(cv).unwrap());
+        }
+        Ok(retv)
+    }
+}
+
+impl<const TAG :u8> Asn1Op for Asn1CharString<TAG> {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let setjson = serde_json::json!(self.val);
+        if key.len() > 0 {
+            val[key] = setjson;
+        } else {
+            *val = setjson;
+        }
+        Ok(1)
+    }
+
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = "".to_string();
+                self.data = Vec::new();
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string",key}
+        }
+        let _ = self.set_value(vmap.as_str().unwrap())?;
+        self.data = Vec::new();
+        return Ok(1);
+    }
+
+    fn init_asn1() -> Self {
+        Asn1CharString {
+            val : "".to_string(),
+            data : Vec::new(),
+        }
+    }
+
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (TAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,TAG)?;
+            let ores = self.decode_asn1(&flatv);
+            if ores.is_err() {
+                return Err(asn1obj_error_at(ores.err().unwrap(),0));
+            }
+            self.data = code[0..clen].to_vec();
+            return Ok(clen);
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if flag != TAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != {} [0x{:02x}]", flag,Self::type_name(),TAG}
+        }
+
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+        let ores = Self::decode_chars(&code[hdrlen..(hdrlen+totallen)]);
+        if ores.is_err() {
+            return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+        }
+        self.val = ores.unwrap();
+        asn1obj_log_trace!("{} [{}]",Self::type_name(),self.val);
+        retv = hdrlen + totallen;
+        self.data = code[0..retv].to_vec();
+        Ok(retv)
+    }
+
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let vcode = Self::encode_chars(&self.val)?;
+        let mut retv :Vec<u8> = asn1obj_format_header(TAG as u64,vcode.len() as u64)?;
+        retv.extend_from_slice(&vcode);
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = Self::chars_len(&self.val)?;
+        let hdrlen = asn1obj_header_len(TAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(TAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let s = asn1_format_line(tab,&(format!("{}: {} {}", name, Self::type_name(), self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+/*count the formatted octets without the String*/
+struct Asn1FmtCounter {
+    cnt :usize,
+}
+
+impl std::fmt::Write for Asn1FmtCounter {
+    fn write_str(&mut self, s :&str) -> std::fmt::Result {
+        self.cnt += s.len();
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Asn1Time {
+    val :String,
+    origval : String,
+    data :Vec<u8>,
+    utag :u8,
+}
+
+
+impl Asn1Time {
+
+    fn parse_value(&self, s :&str) -> Result<i64,Box<dyn Error>> {
+        match i64::from_str_radix(s,10) {
+            Ok(v) => {              
+                return Ok(v);
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"parse [{}] error[{:?}]",s,e}
+            }
+        }
+    }
+
+    fn format_time_str(&self, year :i64, mon :i64,mday :i64,hour :i64, min :i64,sec :i64) -> String {
+        return format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year,mon,mday,hour,min,sec);
+    }
+
+    fn get_time_val(&self, times :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
+        let mut year :i64;
+        let mut mon :i64;
+        let mut mday :i64;
+        let mut hour :i64;
+        let mut min :i64;
+        let mut sec :i64;
+        asn1obj_log_trace!("times [{}]", times);
+
+        if times.len() == 10 {
+            year = self.parse_value(&times[0..4])?;
+            mon = self.parse_value(&times[4..6])?;
+            mday = self.parse_value(&times[6..8])?;
+            hour = self.parse_value(&times[8..10])?;
+            min = 0;
+            sec = 0;
+            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
+            if ov.is_ok() {
+                return Ok((year,mon,mday,hour,min,sec));
+            }
+
+            /**/
+            year = self.parse_value(&times[0..2])?;
+            if year < 70 {
+                year += 2000; 
+            } else {
+                year += 1900;
+            }
+            mon = self.parse_value(&times[2..4])?;
+            mday = self.parse_value(&times[4..6])?;
+            hour = self.parse_value(&times[6..8])?;
+            min = self.parse_value(&times[8..10])?;
+            sec = 0;
+            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
+            if ov.is_err() {
+                let e = ov.err().unwrap();
+                return Err(e);
+            }
+            return Ok((year,mon,mday,hour,min,sec));
+        } 
+
+        if times.len() == 12 {
+            year = self.parse_value(&times[0..4])?;
+            mon = self.parse_value(&times[4..6])?;
+            mday = self.parse_value(&times[6..8])?;
+            hour = self.parse_value(&times[8..10])?;
+            min = self.parse_value(&times[10..12])?;
+            sec = 0;
+            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
+            if ov.is_ok() {
+                return Ok((year,mon,mday,hour,min,sec));
+            }
+
+            /**/
+            year = self.parse_value(&times[0..2])?;
+            if year < 70 {
+                year += 2000; 
+            } else {
+                year += 1900;
+            }
+            mon = self.parse_value(&times[2..4])?;
+            mday = self.parse_value(&times[4..6])?;
+            hour = self.parse_value(&times[6..8])?;
+            min = self.parse_value(&times[8..10])?;
+            sec = self.parse_value(&times[10..12])?;
+            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
+            if ov.is_err() {
+                let e = ov.err().unwrap();
+                return Err(e);
+            }
+            return Ok((year,mon,mday,hour,min,sec));
+        }
+
+        if times.len() >= 14 {
+            year = self.parse_value(&times[0..4])?;
+            mon = self.parse_value(&times[4..6])?;
+            mday = self.parse_value(&times[6..8])?;
+            hour = self.parse_value(&times[8..10])?;
+            min = self.parse_value(&times[10..12])?;
+            sec = self.parse_value(&times[12..14])?;
+            let ov = self.check_data_valid(year,mon,mday,hour,min,sec);
+            if ov.is_err() {
+                let e = ov.err().unwrap();
+                return Err(e);
+            }
+            return Ok((year,mon,mday,hour,min,sec));
+        }
+
+        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not valid [{}] times", times}
+
+    }
+
+    #[allow(deprecated)]
+    fn extract_encode_value(&self, s :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
+        let mut year :i64;
+        let mut mon :i64;
+        let mut mday :i64;
+        let mut hour :i64;
+        let mut min :i64;
+        let mut sec :i64;
+        let mut dt :DateTime<Utc>;
+
+        let c :String = "^(([0-9]+)(\\.[0-9]+)?(([-\\+])([0-9]+))?([Z|X]?))$".to_string();
+        let ro = Regex::new(&c);
+        if ro.is_err() {
+            let e = ro.err().unwrap();
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"regex [{}] error[{:?}]", c,e}
+        }
+        let reex = ro.unwrap();
+        let co = reex.captures(s);
+        if co.is_none() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"regex [{}] capture [{}] none", c,s}
+        }
+
+        asn1obj_log_trace!("encoded value [{}]",s);
+
+        let v = co.unwrap();
+        let times :String;
+        let zs :String;
+        let diffs :String;
+
+        times = format!("{}",v.get(2).map_or("", |m| m.as_str()));
+        if times.len() < 10 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] first part less < 10", s}
+        }
+
+        zs = format!("{}",v.get(7).map_or("", |m| m.as_str()));
+        diffs = format!("{}",v.get(4).map_or("", |m| m.as_str()));
+
+        (year,mon,mday,hour,min,sec) = self.get_time_val(&times)?;
+
+        if diffs.len() > 0  {
+            let plusorminus :String = format!("{}",v.get(5).map_or("", |m| m.as_str()));
+            let offstr :String = format!("{}",v.get(6).map_or("", |m| m.as_str()));
+            if zs.len() > 0 {
+                if zs == "Z" {
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not valid time string [{}]",s} 
+                }               
+            }
+
+            if offstr.len() != 4 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"offstr [{}] != 4", offstr}
+            }
+
+            let voff = self.parse_value(&offstr[0..2])?;
+            if voff > 12 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not valid offset [{}]",offstr}
+            }
+
+            dt = Utc.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
+            if plusorminus == "+" {
+                dt = dt - Duration::hours(voff);
+            } else {
+                dt = dt + Duration::hours(voff);
+            }
+            year = dt.year() as i64;
+            mon = dt.month() as i64;
+            mday = dt.day() as i64;
+            hour = dt.hour() as i64;
+            min = dt.minute() as i64;
+            sec= dt.second() as i64;
+        }
+
+        if zs.len() != 0 {
+            if zs != "Z" && zs != "X" {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"not valid time [{}]",s}
+            }
+        }
+
+        Ok((year,mon,mday,hour,min,sec))
+
+
+    }
+
+    /*the content length of encode_asn1, the time is counted not formatted*/
+    fn time_len(&self) -> Result<usize,Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
+        if self.origval.len() > 0 {
+            return Ok(self.origval.len());
+        }
+        let mut cnt :Asn1FmtCounter = Asn1FmtCounter { cnt : 0 };
+        if self.utag == ASN1_GENERALTIME_FLAG {
+            std::fmt::Write::write_fmt(&mut cnt,format_args!("{:04}{:02}{:02}{:02}{:02}{:02}Z",year,mon,mday,hour,min,sec))?;
+        } else {
+            std::fmt::Write::write_fmt(&mut cnt,format_args!("{:02}{:02}{:02}{:02}{:02}{:02}Z",year % 100,mon,mday,hour,min,sec))?;
+        }
+        Ok(cnt.cnt)
+    }
+
+    fn extract_date_value(&self,s :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
+        let c :String = "([0-9]{4})-([0-9]{2})-([0-9]{2}) ([0-9]{2}):([0-9]{2})(:([0-9]{2}))?".to_string();
+        let ro = Regex::new(&c);
+        if ro.is_err() {
+            let e = ro.err().unwrap();
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"regex [{}] error[{:?}]", c,e}
+        }
+        let reex = ro.unwrap();
+        let co = reex.captures(s);
+        if co.is_none() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"regex [{}] capture [{}] default [{}] none", c,s, ASN1_TIME_DEFAULT_STR}
+        }
+        let v = co.unwrap();
+        if v.len() < 8 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"regex [{}] capture [{}] default [{}] {:?} < 8", c,s, ASN1_TIME_DEFAULT_STR,v}
+        }
+
+        let year :i64;
+        let mon :i64;
+        let mday :i64;
+        let hour :i64;
+        let min :i64;
+        let sec :i64;
+        let mut cc :String;
+
+        cc = format!("{}",v.get(1).map_or("", |m| m.as_str()));
+        match i64::from_str_radix(&cc,10) {
+            Ok(v) => {
+                year = v;
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            }
+        }
+
+        cc = format!("{}",v.get(2).map_or("", |m| m.as_str()));
+        match i64::from_str_radix(&cc,10) {
+            Ok(v) => {
+                mon = v;
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            }
+        }
+
+        cc = format!("{}",v.get(3).map_or("", |m| m.as_str()));
+        match i64::from_str_radix(&cc,10) {
+            Ok(v) => {
+                mday = v;
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            }
+        }
+
+        cc = format!("{}",v.get(4).map_or("", |m| m.as_str()));
+        match i64::from_str_radix(&cc,10) {
+            Ok(v) => {
+                hour = v;
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            }
+        }
+
+        cc = format!("{}",v.get(5).map_or("", |m| m.as_str()));
+        match i64::from_str_radix(&cc,10) {
+            Ok(v) => {
+                min = v;
+            },
+            Err(e) => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            }
+        }
+
+        cc = format!("{}", v.get(7).map_or("", |m| m.as_str()));
+        if cc.len() > 0 {
+            match i64::from_str_radix(&cc,10) {
+                Ok(v) => {
+                    sec = v;
+                },
+                Err(e) => {
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+                }
+            }
+        } else {
+            sec = 0;
+        }
+
+        Ok((year,mon,mday,hour,min,sec))
+    }
+
+    fn check_data_valid(&self, year :i64, mon :i64,mday :i64,hour :i64, min :i64,sec :i64) -> Result<(),Box<dyn Error>> {
+        if year < 1900  ||  year > 2100 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"year [{}] < 1900" ,year}
+        }
+        if mon < 1 || mon > 12 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mon {} not valid ", mon}
+        }
+
+        if mday < 1 || mday > 31 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid", mday}
+        }
+
+        if hour < 0 || hour > 23 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"hour {} not valid", hour}  
+        }
+
+        if min < 0 || min > 59 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"min {} not valid", min}
+        }
+
+        if sec < 0 || sec > 59 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"sec {} not valid", sec}    
+        }
+
+        if (mon == 4 || mon == 6 || mon == 9 || mon == 11) && mday > 30 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}    
+        }
+
+        if mon == 2 {
+            if (year % 4) != 0 && mday > 28 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}    
+            } else if (year % 4) == 0 && (year % 100) != 0 && mday > 29 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}    
+            } else if (year % 4) == 0 && (year % 100) == 0 && (year % 400) != 0 && mday > 28 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}    
+            } else if (year % 4) == 0 && (year % 400) == 0 && mday > 29  {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}
+            } else if mday > 28 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"mday {} not valid in mon {}", mday,mon}
+            }           
+        }
+        Ok(())
+    }
+
+    pub fn set_value_str(&mut self, s :&str) -> Result<(),Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = self.extract_date_value(s)?;
+        let _ = self.check_data_valid(year,mon,mday,hour,min,sec)?;
+        self.val = self.format_time_str(year,mon,mday,hour,min,sec);
+        self.origval = "".to_string();
+        Ok(())
+    }
+
+    pub fn get_value_str(&self) -> String {
+        return format!("{}",self.val);
+    }
+
+    pub fn set_value_time_local(&mut self,dt :&DateTime<Local>) -> Result<(),Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = (dt.year(),dt.month(),dt.day(),dt.hour(),dt.minute(), dt.second());
+        let _ = self.check_data_valid(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64)?;
+        self.val = self.format_time_str(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64);
+        self.utag = ASN1_GENERALTIME_FLAG;
+        self.origval = "".to_string();
+        Ok(())
+    }
+
+    pub fn set_value_time(&mut self,dt :&DateTime<Utc>) -> Result<(),Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = (dt.year(),dt.month(),dt.day(),dt.hour(),dt.minute(), dt.second());
+        let _ = self.check_data_valid(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64)?;
+        self.val = self.format_time_str(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64);
+        self.utag = ASN1_UTCTIME_FLAG;
+        self.origval = "".to_string();
+        Ok(())
+    }
+
+    #[allow(deprecated)]
+    pub fn get_value_time(&self) -> Result<DateTime<Utc>,Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
+        let dt :DateTime<Utc> = Utc.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
+        Ok(dt)
+    }
+
+    #[allow(deprecated)]
+    pub fn get_value_time_local(&self) -> Result<DateTime<Local>,Box<dyn Error>> {
+        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
+        let dt :DateTime<Local> = Local.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
+        Ok(dt)
+    }
+
+
+    pub fn set_utag(&mut self,utag :u8) -> Result<u8,Box<dyn Error>> {
+        let rettag :u8 = self.utag;
+        if utag != ASN1_UTCTIME_FLAG && utag != ASN1_GENERALTIME_FLAG {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"utag {} not valid ", utag}
+        }
+        self.utag = utag;
+        Ok(rettag)
+    }
+
+    pub fn get_utag(&self) -> u8 {
+        return self.utag;
+    }
+}
+
+
+impl Asn1Op for Asn1Time {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
+        setjson[ASN1_JSON_TIME] = serde_json::from_str(&format!("\"{}\"",self.get_value_str())).unwrap();
+        setjson[ASN1_JSON_INNER_FLAG] = serde_json::from_str(&format!("{}",self.utag)).unwrap();
+        if key.len() > 0 {
+            val[key] = setjson;    
+        } else {
+            *val = setjson;
+        }
+        
+        Ok(1)
+    }
+
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = ASN1_TIME_DEFAULT_STR.to_string();
+                self.origval = "".to_string();
+                self.data = Vec::new();
+                self.utag = ASN1_UTCTIME_FLAG;
+                return Ok(0);
+            }
+            vmap = serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if  !vmap.is_string() && !vmap.is_object() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or object",key}
+        }
+        self.utag = ASN1_UTCTIME_FLAG;
+        self.origval = "".to_string();
+
+        if vmap.is_string() {
+            let c = vmap.as_str().unwrap();
+            let _ = self.set_value_str(c)?;
+        } else if vmap.is_object() {
+            let c = vmap.as_object().unwrap();
+            let k = c.get(ASN1_JSON_TIME);
+            if k.is_none() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_TIME}
+            } 
+            let k = k.unwrap();
+            if !k.is_string() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not string",key,ASN1_JSON_TIME}
+            }
+            let _ = self.set_value_str(k.as_str().unwrap());
+            let k = c.get(ASN1_JSON_INNER_FLAG);
+            if k.is_some()  {
+                let k = k.unwrap();
+                if k.is_i64() {
+                    let ival = k.as_i64().unwrap() as u8;
+                    if ival != ASN1_UTCTIME_FLAG  && ival != ASN1_GENERALTIME_FLAG {
+                        asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
+                    }
+                    self.utag = ival;                    
+                }
+            }
+        }
+        return Ok(1);
+    }
+
+    fn init_asn1() -> Self {
+        Asn1Time {
+            val : ASN1_TIME_DEFAULT_STR.to_string(),
+            origval : "".to_string(),
+            data : Vec::new(),
+            utag : ASN1_UTCTIME_FLAG,
+        }
+    }
+
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        let (year,mon,mday,hour,min,sec):(i64,i64,i64,i64,i64,i64);
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if (flag as u8)  != ASN1_GENERALTIME_FLAG && (flag as u8) != ASN1_UTCTIME_FLAG {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}]  != ASN1_UTCTIME_FLAG [0x{:02x}] or ASN1_GENERALTIME_FLAG [0x{:02x}]", flag,ASN1_UTCTIME_FLAG,ASN1_GENERALTIME_FLAG}
+        }
+
+        self.utag = flag as u8;
+
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+        let mut retm = BytesMut::with_capacity(totallen);
+        for i in 0..totallen {
+            retm.put_u8(code[hdrlen + i]);
+        }
+        let a = retm.freeze();
+
+        let s = String::from_utf8_lossy(&a).to_string();
+        if s.len() < 12 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"not valid string [{}]",s}
+        }
+
+        (year,mon,mday,hour,min,sec) = self.extract_encode_value(&s)?;
+        let _ = self.check_data_valid(year,mon,mday,hour,min,sec)?;
+        self.origval = format!("{}",s);
+        self.val = self.format_time_str(year,mon,mday,hour,min,sec);
+        asn1obj_log_trace!("Asn1Time {}",self.val);
+        self.data = Vec::new();
+        retv = hdrlen + totallen;
+        for i in 0..retv {
+            self.data.push(code[i]);
+        }
+        Ok(retv)
+    }
+
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let llen :u64;
+        let mut retv :Vec<u8>;
+        let vcode :Vec<u8>;
+
+
+        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
+        let s;
+        if self.origval.len() == 0 {
+            if self.utag == ASN1_GENERALTIME_FLAG {
+                s = format!("{:04}{:02}{:02}{:02}{:02}{:02}Z",year,mon,mday,hour,min,sec);    
+            } else {
+                s = format!("{:02}{:02}{:02}{:02}{:02}{:02}Z",year % 100,mon,mday,hour,min,sec);
+            }
+            
+        } else {
+            s = format!("{}",self.origval);
+        }        
+        vcode = s.as_bytes().to_vec();
+        llen = vcode.len() as u64;
 
-        asn1obj_new_error!{Asn1ObjBaseError,"not valid [{}] times", times}
+        retv = asn1obj_format_header(self.utag as u64,llen)?;
 
+        for i in 0..vcode.len() {
+            retv.push(vcode[i]);
+        }
+        Ok(retv)
     }
 
-    #[allow(deprecated)]
-    fn extract_encode_value(&self, s :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
-        let mut year :i64;
-        let mut mon :i64;
-        let mut mday :i64;
-        let mut hour :i64;
-        let mut min :i64;
-        let mut sec :i64;
-        let mut dt :DateTime<Utc>;
-
-        let c :String = "^(([0-9]+)(\\.[0-9]+)?(([-\\+])([0-9]+))?([Z|X]?))$".to_string();
-        let ro = Regex::new(&c);
-        if ro.is_err() {
-            let e = ro.err().unwrap();
-            asn1obj_new_error!{Asn1ObjBaseError,"regex [{}] error[{:?}]", c,e}
-        }
-        let reex = ro.unwrap();
-        let co = reex.captures(s);
-        if co.is_none() {
-            asn1obj_new_error!{Asn1ObjBaseError,"regex [{}] capture [{}] none", c,s}
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
         }
+        let clen :usize = self.time_len()?;
+        let hdrlen = asn1obj_header_len(self.utag as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
 
-        asn1obj_log_trace!("encoded value [{}]",s);
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(self.utag as u64));
+    }
 
-        let v = co.unwrap();
-        let times :String;
-        let zs :String;
-        let diffs :String;
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
+        let s :String;
+        s = asn1_format_line(tab,&(format!("{}: ASN1_TIME {}", name, self.val)));
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
 
-        times = format!("{}",v.get(2).map_or("", |m| m.as_str()));
-        if times.len() < 10 {
-            asn1obj_new_error!{Asn1ObjBaseError,"[{}] first part less < 10", s}
-        }
 
-        zs = format!("{}",v.get(7).map_or("", |m| m.as_str()));
-        diffs = format!("{}",v.get(4).map_or("", |m| m.as_str()));
+/*
+    the octets length of to_signed_bytes_be for the value of the sign neg and the magnitude mag
+*/
+fn asn1obj_signed_octets_len(neg :bool, mag :&BigUint) -> usize {
+    let bits :u64 = mag.bits();
+    if neg && bits > 0 && (bits % 8) == 0 && mag.trailing_zeros() == Some(bits - 1) {
+        /*-0x80 -0x8000 ... have no more octet for the sign*/
+        return (bits / 8) as usize;
+    }
+    return (bits / 8) as usize + 1;
+}
 
-        (year,mon,mday,hour,min,sec) = self.get_time_val(&times)?;
+/*
+    val is the magnitude and neg is the sign,
+    the content with the high bit set is decoded as the two's complement negative
+*/
+#[derive(Clone)]
+pub struct Asn1BigNum {
+    pub val :BigUint,
+    pub neg :bool,
+    data :Vec<u8>,
+}
 
-        if diffs.len() > 0  {
-            let plusorminus :String = format!("{}",v.get(5).map_or("", |m| m.as_str()));
-            let offstr :String = format!("{}",v.get(6).map_or("", |m| m.as_str()));
-            if zs.len() > 0 {
-                if zs == "Z" {
-                    asn1obj_new_error!{Asn1ObjBaseError,"not valid time string [{}]",s} 
-                }               
-            }
+impl Asn1BigNum {
+    pub fn set_value(&mut self, val :&[u8]) -> Vec<u8> {
+        let retv = self.val.to_bytes_be().to_vec();
+        self.val = BigUint::from_bytes_be(val);
+        self.neg = false;
+        return retv;
+    }
 
-            if offstr.len() != 4 {
-                asn1obj_new_error!{Asn1ObjBaseError, "offstr [{}] != 4", offstr}
-            }
+    pub fn is_negative(&self) -> bool {
+        return self.neg && !self.val.is_zero();
+    }
 
-            let voff = self.parse_value(&offstr[0..2])?;
-            if voff > 12 {
-                asn1obj_new_error!{Asn1ObjBaseError,"not valid offset [{}]",offstr}
-            }
+    pub fn to_bigint(&self) -> BigInt {
+        if self.neg {
+            return BigInt::from_biguint(Sign::Minus,self.val.clone());
+        }
+        return BigInt::from_biguint(Sign::Plus,self.val.clone());
+    }
 
-            dt = Utc.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
-            if plusorminus == "+" {
-                dt = dt - Duration::hours(voff);
-            } else {
-                dt = dt + Duration::hours(voff);
-            }
-            year = dt.year() as i64;
-            mon = dt.month() as i64;
-            mday = dt.day() as i64;
-            hour = dt.hour() as i64;
-            min = dt.minute() as i64;
-            sec= dt.second() as i64;
+    pub fn set_bigint(&mut self, val :&BigInt) -> BigInt {
+        let retv = self.to_bigint();
+        self.val = val.magnitude().clone();
+        self.neg = val.sign() == Sign::Minus;
+        return retv;
+    }
+}
+
+impl Asn1Op for Asn1BigNum {
+    fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let s :String;
+        if self.is_negative() {
+            s = format!("\"-{}\"",self.val.to_str_radix(16));
+        } else {
+            s = format!("\"{}\"",self.val.to_str_radix(16));
+        }
+        let setjson :serde_json::value::Value = serde_json::from_str(&s).unwrap();
+        if key.len() > 0 {
+            val[key] = setjson;    
+        } else {
+            *val = setjson;
         }
+        
+        Ok(1)
+    }
 
-        if zs.len() != 0 {
-            if zs != "Z" && zs != "X" {
-                asn1obj_new_error!{Asn1ObjBaseError,"not valid time [{}]",s}
+    fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+        let vmap :serde_json::value::Value;
+        if key.len() > 0 {
+            let ores = val.get(key);
+            if ores.is_none() {
+                self.val = BigUint::parse_bytes(b"0",16).unwrap();
+                self.neg = false;
+                self.data = Vec::new();
+                return Ok(0);
             }
+            vmap =serde_json::json!(ores.unwrap());
+        } else {
+            vmap = val.clone();
+        }
+        if  !vmap.is_string() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string",key}
         }
 
-        Ok((year,mon,mday,hour,min,sec))
-
-
+        if vmap.is_string() {
+            let mut c = vmap.as_str().unwrap();
+            let mut neg :bool = false;
+            if c.starts_with("-") {
+                neg = true;
+                c = &c[1..];
+            }
+            let ores = BigUint::parse_bytes(c.as_bytes(),16);
+            if ores.is_none() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} {} not valid biguint",key,c}
+            }
+            self.val = ores.unwrap();
+            self.neg = neg;
+        }
+        return Ok(1);
     }
 
-    fn extract_date_value(&self,s :&str) -> Result<(i64,i64,i64,i64,i64,i64),Box<dyn Error>> {
-        let c :String = "([0-9]{4})-([0-9]{2})-([0-9]{2}) ([0-9]{2}):([0-9]{2})(:([0-9]{2}))?".to_string();
-        let ro = Regex::new(&c);
-        if ro.is_err() {
-            let e = ro.err().unwrap();
-            asn1obj_new_error!{Asn1ObjBaseError,"regex [{}] error[{:?}]", c,e}
+    fn init_asn1() -> Self {
+        Asn1BigNum {
+            val : Zero::zero(),
+            neg : false,
+            data : Vec::new(),
         }
-        let reex = ro.unwrap();
-        let co = reex.captures(s);
-        if co.is_none() {
-            asn1obj_new_error!{Asn1ObjBaseError,"regex [{}] capture [{}] default [{}] none", c,s, ASN1_TIME_DEFAULT_STR}
+    }
+
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
-        let v = co.unwrap();
-        if v.len() < 8 {
-            asn1obj_new_error!{Asn1ObjBaseError,"regex [{}] capture [{}] default [{}] {:?} < 8", c,s, ASN1_TIME_DEFAULT_STR,v}
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if flag != ASN1_INTEGER_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
         }
 
-        let year :i64;
-        let mon :i64;
-        let mday :i64;
-        let hour :i64;
-        let min :i64;
-        let sec :i64;
-        let mut cc :String;
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
 
-        cc = format!("{}",v.get(1).map_or("", |m| m.as_str()));
-        match i64::from_str_radix(&cc,10) {
-            Ok(v) => {
-                year = v;
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+        if totallen < 1 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"need 1 length"}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_integer(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
             }
         }
 
-        cc = format!("{}",v.get(2).map_or("", |m| m.as_str()));
-        match i64::from_str_radix(&cc,10) {
-            Ok(v) => {
-                mon = v;
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
-            }
+        if (code[hdrlen] & 0x80) != 0 {
+            let bn = BigInt::from_signed_bytes_be(&code[hdrlen..(hdrlen+totallen)]);
+            self.val = bn.magnitude().clone();
+            self.neg = true;
+        } else {
+            self.val = BigUint::from_bytes_be(&code[hdrlen..(hdrlen+totallen)]);
+            self.neg = false;
         }
+        let cc = self.val.to_bytes_be();
+        asn1obj_debug_buffer_trace!(cc.as_ptr(), cc.len(),"Asn1BigNum neg {}",self.neg);
+        asn1obj_log_trace!("Asn1BigNum {:?}", self.val);
+        self.data = Vec::new();
+        for i in 0..(hdrlen + totallen) {
+            self.data.push(code[i]);
+        }
+        retv= hdrlen + totallen;
+        Ok(retv)
+    }
 
-        cc = format!("{}",v.get(3).map_or("", |m| m.as_str()));
-        match i64::from_str_radix(&cc,10) {
-            Ok(v) => {
-                mday = v;
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
-            }
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
         }
+        return Some(self.data.clone());
+    }
 
-        cc = format!("{}",v.get(4).map_or("", |m| m.as_str()));
-        match i64::from_str_radix(&cc,10) {
-            Ok(v) => {
-                hour = v;
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
-            }
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let mut retv :Vec<u8> ;
+        let v8 :Vec<u8>;
+        let mut clen :usize ;
+        if self.is_negative() {
+            v8 = self.to_bigint().to_signed_bytes_be();
+            retv = asn1obj_format_header(ASN1_INTEGER_FLAG as u64, v8.len() as u64)?;
+            retv.extend_from_slice(&v8);
+            return Ok(retv);
+        }
+        v8 = self.val.to_bytes_be();
+        clen = v8.len();
+        if v8.len() > 0 && (v8[0] & 0x80) != 0x0 {
+            clen = v8.len() + 1;
+        }
+        retv = asn1obj_format_header(ASN1_INTEGER_FLAG as u64, clen as u64)?;
+        if clen != v8.len() {
+            retv.push(0x0);
+        }
+        for v in v8.iter() {
+            retv.push(*v);
         }
+        Ok(retv)
+    }
 
-        cc = format!("{}",v.get(5).map_or("", |m| m.as_str()));
-        match i64::from_str_radix(&cc,10) {
-            Ok(v) => {
-                min = v;
-            },
-            Err(e) => {
-                asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = asn1obj_signed_octets_len(self.is_negative(),&self.val);
+        let hdrlen = asn1obj_header_len(ASN1_INTEGER_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_INTEGER_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> { 
+        let v8 = self.val.to_bytes_be();
+        let mut s :String;
+        let mut sign :&str = "";
+        if self.is_negative() {
+            sign = "-";
+        }
+        if v8.len() < 8 {
+            s = asn1_format_line(tab, &(format!("{}: ASN1_BIGNUM {}0x{:08x}", name, sign, self.val)));
+        } else {
+            let mut c :String = "".to_string();
+            let mut i :usize=0;
+            let mut lasti :usize = 0;
+            if self.is_negative() {
+                s = asn1_format_line(tab, &(format!("{}: ASN1_BIGNUM (Negative)", name)));
+            } else {
+                s = asn1_format_line(tab, &(format!("{}: ASN1_BIGNUM", name)));
+            }
+            while i < v8.len() {
+                if (i %16) == 0 {
+                    if i > 0 {
+                        c.push_str("    ");
+                        while lasti != i {
+                            if v8[lasti] >= 0x20 && v8[lasti] <= 0x7e {
+                                c.push( v8[lasti] as char);
+                            } else {
+                                c.push_str(".");
+                            }
+                            lasti += 1;
+                        }
+                        s.push_str(&asn1_format_line(tab + 1, &format!( "{}",c)));
+                        c = "".to_string();
+                    }
+                    lasti = i;
+                }
+                if lasti != i {
+                    c.push_str(":");
+                }               
+                c.push_str(&format!("{:02x}",v8[i]));
+                i += 1;
             }
-        }
-
-        cc = format!("{}", v.get(7).map_or("", |m| m.as_str()));
-        if cc.len() > 0 {
-            match i64::from_str_radix(&cc,10) {
-                Ok(v) => {
-                    sec = v;
-                },
-                Err(e) => {
-                    asn1obj_new_error!{Asn1ObjBaseError,"can not parse [{}] in [{}] error[{:?}]", s, cc,e}
+            if lasti != i {
+                while (i%16) != 0 {
+                    c.push_str("   ");
+                    i += 1;
+                }
+                c.push_str("    ");
+                while lasti < v8.len() {
+                    if v8[lasti] >= 0x20 && v8[lasti] <= 0x7e {
+                        c.push( v8[lasti] as char);
+                    } else {
+                        c.push_str(".");
+                    }
+                    lasti += 1;                    
                 }
             }
-        } else {
-            sec = 0;
-        }
 
-        Ok((year,mon,mday,hour,min,sec))
+            if c.len() > 0 {
+                s.push_str(&asn1_format_line(tab + 1, &format!("{}",c)));
+            }
+        }
+        iowriter.write(s.as_bytes())?;
+        Ok(())
     }
+}
 
-    fn check_data_valid(&self, year :i64, mon :i64,mday :i64,hour :i64, min :i64,sec :i64) -> Result<(),Box<dyn Error>> {
-        if year < 1900  ||  year > 2100 {
-            asn1obj_new_error!{Asn1ObjBaseError,"year [{}] < 1900" ,year}
-        }
-        if mon < 1 || mon > 12 {
-            asn1obj_new_error!{Asn1ObjBaseError,"mon {} not valid ", mon}
-        }
+/*
+    arbitrary size signed INTEGER, the content is the two's complement octets
+*/
+#[derive(Clone)]
+pub struct Asn1BigInteger {
+    pub val :BigInt,
+    data :Vec<u8>,
+}
 
-        if mday < 1 || mday > 31 {
-            asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid", mday}
-        }
+impl Asn1BigInteger {
+    pub fn set_value<T :Into<BigInt>>(&mut self, val :T) -> BigInt {
+        let retv = self.val.clone();
+        self.val = val.into();
+        return retv;
+    }
 
-        if hour < 0 || hour > 23 {
-            asn1obj_new_error!{Asn1ObjBaseError,"hour {} not valid", hour}  
+    /*
+        error when the value is out of the range of T
+    */
+    pub fn get_value<T :TryFrom<BigInt>>(&self) -> Result<T,Box<dyn Error>> {
+        let ores = T::try_from(self.val.clone());
+        if ores.is_err() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"value [{}] out of range of {}", self.val, std::any::type_name::<T>()}
         }
+        return Ok(ores.ok().unwrap());
+    }
 
-        if min < 0 || min > 59 {
-            asn1obj_new_error!{Asn1ObjBaseError,"min {} not valid", min}
+    fn parse_value(c :&str) -> Option<BigInt> {
+        let mut s :&str = c.trim();
+        let mut neg :bool = false;
+        if s.starts_with("-") {
+            neg = true;
+            s = &s[1..];
         }
-
-        if sec < 0 || sec > 59 {
-            asn1obj_new_error!{Asn1ObjBaseError,"sec {} not valid", sec}    
+        let ores :Option<BigInt>;
+        if s.starts_with("0x") || s.starts_with("0X") {
+            ores = BigInt::parse_bytes(s[2..].as_bytes(),16);
+        } else {
+            ores = BigInt::parse_bytes(s.as_bytes(),10);
         }
-
-        if (mon == 4 || mon == 6 || mon == 9 || mon == 11) && mday > 30 {
-            asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}    
+        if ores.is_none() {
+            return None;
         }
-
-        if mon == 2 {
-            if (year % 4) != 0 && mday > 28 {
-                asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}    
-            } else if (year % 4) == 0 && (year % 100) != 0 && mday > 29 {
-                asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}    
-            } else if (year % 4) == 0 && (year % 100) == 0 && (year % 400) != 0 && mday > 28 {
-                asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}    
-            } else if (year % 4) == 0 && (year % 400) == 0 && mday > 29  {
-                asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}
-            } else if mday > 28 {
-                asn1obj_new_error!{Asn1ObjBaseError,"mday {} not valid in mon {}", mday,mon}
-            }           
+        let v = ores.unwrap();
+        if neg {
+            return Some(-v);
         }
-        Ok(())
-    }
-
-    pub fn set_value_str(&mut self, s :&str) -> Result<(),Box<dyn Error>> {
-        let (year,mon,mday,hour,min,sec) = self.extract_date_value(s)?;
-        let _ = self.check_data_valid(year,mon,mday,hour,min,sec)?;
-        self.val = self.format_time_str(year,mon,mday,hour,min,sec);
-        self.origval = "".to_string();
-        Ok(())
-    }
-
-    pub fn get_value_str(&self) -> String {
-        return format!("{}",self.val);
+        return Some(v);
     }
+}
 
-    pub fn set_value_time_local(&mut self,dt :&DateTime<Local>) -> Result<(),Box<dyn Error>> {
-        let (year,mon,mday,hour,min,sec) = (dt.year(),dt.month(),dt.day(),dt.hour(),dt.minute(), dt.second());
-        let _ = self.check_data_valid(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64)?;
-        self.val = self.format_time_str(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64);
-        self.utag = ASN1_GENERALTIME_FLAG;
-        self.origval = "".to_string();
-        Ok(())
-    }
+macro_rules! asn1obj_big_integer_conv {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Asn1BigInteger {
+                fn from(val :$t) -> Self {
+                    let mut retv = Asn1BigInteger::init_asn1();
+                    retv.val = BigInt::from(val);
+                    retv
+                }
+            }
 
-    pub fn set_value_time(&mut self,dt :&DateTime<Utc>) -> Result<(),Box<dyn Error>> {
-        let (year,mon,mday,hour,min,sec) = (dt.year(),dt.month(),dt.day(),dt.hour(),dt.minute(), dt.second());
-        let _ = self.check_data_valid(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64)?;
-        self.val = self.format_time_str(year as i64,mon as i64,mday as i64,hour as i64,min as i64,sec as i64);
-        self.utag = ASN1_UTCTIME_FLAG;
-        self.origval = "".to_string();
-        Ok(())
-    }
+            impl TryFrom<&Asn1BigInteger> for $t {
+                type Error = Box<dyn Error>;
+                fn try_from(val :&Asn1BigInteger) -> Result<$t,Box<dyn Error>> {
+                    return val.get_value::<$t>();
+                }
+            }
+        )*
+    };
+}
 
-    #[allow(deprecated)]
-    pub fn get_value_time(&self) -> Result<DateTime<Utc>,Box<dyn Error>> {
-        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
-        let dt :DateTime<Utc> = Utc.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
-        Ok(dt)
-    }
+asn1obj_big_integer_conv!{i8,i16,i32,i64,i128,isize,u8,u16,u32,u64,u128,usize}
 
-    #[allow(deprecated)]
-    pub fn get_value_time_local(&self) -> Result<DateTime<Local>,Box<dyn Error>> {
-        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
-        let dt :DateTime<Local> = Local.ymd(year as i32,mon as u32,mday as u32).and_hms(hour as u32,min as u32,sec as u32);
-        Ok(dt)
+impl From<BigInt> for Asn1BigInteger {
+    fn from(val :BigInt) -> Self {
+        let mut retv = Asn1BigInteger::init_asn1();
+        retv.val = val;
+        retv
     }
+}
 
-
-    pub fn set_utag(&mut self,utag :u8) -> Result<u8,Box<dyn Error>> {
-        let rettag :u8 = self.utag;
-        if utag != ASN1_UTCTIME_FLAG && utag != ASN1_GENERALTIME_FLAG {
-            asn1obj_new_error!{Asn1ObjBaseError,"utag {} not valid ", utag}
+impl Asn1Op for Asn1BigInteger {
+    fn equal_asn1(&self, other :&Self) -> bool {
+        if self.val != other.val {
+            return false;
         }
-        self.utag = utag;
-        Ok(rettag)
-    }
-
-    pub fn get_utag(&self) -> u8 {
-        return self.utag;
+        return true;
     }
-}
-
 
-impl Asn1Op for Asn1Time {
+    /*
+        the value in i64 range is the json number, otherwise the decimal string
+    */
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let mut setjson :serde_json::value::Value = serde_json::from_str("{}").unwrap();
-        setjson[ASN1_JSON_TIME] = serde_json::from_str(&format!("\"{}\"",self.get_value_str())).unwrap();
-        setjson[ASN1_JSON_INNER_FLAG] = serde_json::from_str(&format!("{}",self.utag)).unwrap();
+        let setjson :serde_json::value::Value;
+        let ores = i64::try_from(self.val.clone());
+        if ores.is_ok() {
+            setjson = serde_json::json!(ores.unwrap());
+        } else {
+            setjson = serde_json::json!(self.val.to_str_radix(10));
+        }
         if key.len() > 0 {
-            val[key] = setjson;    
+            val[key] = setjson;
         } else {
             *val = setjson;
         }
-        
         Ok(1)
     }
 
@@ -2923,164 +5500,387 @@ impl Asn1Op for Asn1Time {
         if key.len() > 0 {
             let ores = val.get(key);
             if ores.is_none() {
-                self.val = ASN1_TIME_DEFAULT_STR.to_string();
-                self.origval = "".to_string();
+                self.val = Zero::zero();
                 self.data = Vec::new();
-                self.utag = ASN1_UTCTIME_FLAG;
                 return Ok(0);
             }
             vmap = serde_json::json!(ores.unwrap());
         } else {
             vmap = val.clone();
         }
-        if  !vmap.is_string() && !vmap.is_object() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string or object",key}
-        }
-        self.utag = ASN1_UTCTIME_FLAG;
-        self.origval = "".to_string();
-
-        if vmap.is_string() {
+        if vmap.is_i64() {
+            self.val = BigInt::from(vmap.as_i64().unwrap());
+        } else if vmap.is_u64() {
+            self.val = BigInt::from(vmap.as_u64().unwrap());
+        } else if vmap.is_string() {
             let c = vmap.as_str().unwrap();
-            let _ = self.set_value_str(c)?;
-        } else if vmap.is_object() {
-            let c = vmap.as_object().unwrap();
-            let k = c.get(ASN1_JSON_TIME);
-            if k.is_none() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{} not found {} in Asn1PrintableString object",key,ASN1_JSON_TIME}
-            } 
-            let k = k.unwrap();
-            if !k.is_string() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not string",key,ASN1_JSON_TIME}
-            }
-            let _ = self.set_value_str(k.as_str().unwrap());
-            let k = c.get(ASN1_JSON_INNER_FLAG);
-            if k.is_some()  {
-                let k = k.unwrap();
-                if k.is_i64() {
-                    let ival = k.as_i64().unwrap() as u8;
-                    if ival != ASN1_UTCTIME_FLAG  && ival != ASN1_GENERALTIME_FLAG {
-                        asn1obj_new_error!{Asn1ObjBaseError,"{}:{} not valid flag",key,ASN1_JSON_INNER_FLAG}
-                    }
-                    self.utag = ival;                    
-                }
+            let ores = Self::parse_value(c);
+            if ores.is_none() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} {} not valid integer",key,c}
             }
+            self.val = ores.unwrap();
+        } else {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string or integer",key}
         }
+        self.data = Vec::new();
         return Ok(1);
     }
 
     fn init_asn1() -> Self {
-        Asn1Time {
-            val : ASN1_TIME_DEFAULT_STR.to_string(),
-            origval : "".to_string(),
+        Asn1BigInteger {
+            val : Zero::zero(),
             data : Vec::new(),
-            utag : ASN1_UTCTIME_FLAG,
         }
     }
 
-    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
-        let retv :usize;
-        let (year,mon,mday,hour,min,sec):(i64,i64,i64,i64,i64,i64);
-        if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+    fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+        let retv :usize;
+        if code.len() < 2 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+
+        if flag != ASN1_INTEGER_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
+        }
+
+        if code.len() < (hdrlen + totallen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+        if totallen < 1 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"need 1 length"}
+        }
+        if asn1obj_is_der() {
+            let ores = asn1obj_der_check_integer(&code[hdrlen..(hdrlen+totallen)]);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
+        }
+
+        self.val = BigInt::from_signed_bytes_be(&code[hdrlen..(hdrlen+totallen)]);
+        asn1obj_log_trace!("Asn1BigInteger {}", self.val);
+        self.data = Vec::new();
+        for i in 0..(hdrlen + totallen) {
+            self.data.push(code[i]);
+        }
+        retv= hdrlen + totallen;
+        Ok(retv)
+    }
+
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
+        }
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let mut retv :Vec<u8>;
+        /*to_signed_bytes_be gives the minimal octets, so it is DER already*/
+        let v8 :Vec<u8> = self.val.to_signed_bytes_be();
+        retv = asn1obj_format_header(ASN1_INTEGER_FLAG as u64, v8.len() as u64)?;
+        retv.extend_from_slice(&v8);
+        Ok(retv)
+    }
+
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = asn1obj_signed_octets_len(self.val.sign() == Sign::Minus,self.val.magnitude());
+        let hdrlen = asn1obj_header_len(ASN1_INTEGER_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_INTEGER_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let mut s :String;
+        let v8 = self.val.magnitude().to_bytes_be();
+        if v8.len() <= 8 {
+            s = asn1_format_line(tab, &(format!("{}: ASN1_INTEGER {}", name, self.val)));
+        } else {
+            let mut c :String = "".to_string();
+            if self.val.sign() == Sign::Minus {
+                s = asn1_format_line(tab, &(format!("{}: ASN1_INTEGER (Negative)", name)));
+            } else {
+                s = asn1_format_line(tab, &(format!("{}: ASN1_INTEGER", name)));
+            }
+            for i in 0..v8.len() {
+                if i > 0 && (i % 16) == 0 {
+                    s.push_str(&asn1_format_line(tab + 1, &c));
+                    c = "".to_string();
+                }
+                c.push_str(&format!("{:02x}",v8[i]));
+                if i != (v8.len() - 1) {
+                    c.push_str(":");
+                }
+            }
+            s.push_str(&asn1_format_line(tab + 1, &c));
+        }
+        iowriter.write(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+/*
+    REAL in binary (base 2 8 16) and ISO 6093 decimal (NR1 NR2 NR3) encodings
+    decimal keeps the text of the decimal form, it is encoded again
+    while val is still the same value, otherwise val is encoded in DER binary form
+*/
+#[derive(Clone)]
+pub struct Asn1Real {
+    pub val :f64,
+    pub form :u8,
+    pub decimal :String,
+    data :Vec<u8>,
+}
+
+impl Asn1Real {
+    pub fn set_value(&mut self, val :f64) -> f64 {
+        let retv = self.val;
+        self.val = val;
+        self.form = ASN1_REAL_BINARY;
+        self.decimal = "".to_string();
+        return retv;
+    }
+
+    /*
+        form is ASN1_REAL_NR1 ASN1_REAL_NR2 or ASN1_REAL_NR3
+    */
+    pub fn set_decimal(&mut self, form :u8, s :&str) -> Result<f64,Box<dyn Error>> {
+        if form < ASN1_REAL_NR1 || form > ASN1_REAL_NR3 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"form [{}] not valid decimal form", form}
+        }
+        let ores = Self::parse_decimal(s);
+        if ores.is_none() {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"[{}] not valid ISO 6093 number", s}
+        }
+        let retv = self.val;
+        self.val = ores.unwrap();
+        self.form = form;
+        self.decimal = format!("{}",s);
+        return Ok(retv);
+    }
+
+    fn parse_decimal(s :&str) -> Option<f64> {
+        let c = s.trim_start_matches(' ').replace(",",".");
+        if c.len() == 0 || !c.is_ascii() {
+            return None;
+        }
+        for b in c.as_bytes().iter() {
+            if !((*b >= b'0' && *b <= b'9') || *b == b'.' || *b == b'+' || *b == b'-' || *b == b'e' || *b == b'E') {
+                return None;
+            }
+        }
+        match c.parse::<f64>() {
+            Ok(v) => {
+                return Some(v);
+            },
+            Err(_e) => {
+                return None;
+            }
+        }
+    }
+
+    fn decimal_preserved(&self) -> bool {
+        if self.form < ASN1_REAL_NR1 || self.form > ASN1_REAL_NR3 || self.decimal.len() == 0 {
+            return false;
         }
-        let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
-
-        if (flag as u8)  != ASN1_GENERALTIME_FLAG && (flag as u8) != ASN1_UTCTIME_FLAG {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}]  != ASN1_UTCTIME_FLAG [0x{:02x}] or ASN1_GENERALTIME_FLAG [0x{:02x}]", flag,ASN1_UTCTIME_FLAG,ASN1_GENERALTIME_FLAG}
+        let ores = Self::parse_decimal(&self.decimal);
+        if ores.is_none() {
+            return false;
         }
+        return ores.unwrap().to_bits() == self.val.to_bits();
+    }
 
-        self.utag = flag as u8;
-
-        if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+    fn special_name(&self) -> Option<&'static str> {
+        if self.val.is_nan() {
+            return Some(ASN1_REAL_NOT_A_NUMBER);
+        } else if self.val == f64::INFINITY {
+            return Some(ASN1_REAL_PLUS_INFINITY);
+        } else if self.val == f64::NEG_INFINITY {
+            return Some(ASN1_REAL_MINUS_INFINITY);
         }
+        return None;
+    }
 
-        let mut retm = BytesMut::with_capacity(totallen);
-        for i in 0..totallen {
-            retm.put_u8(code[hdrlen + i]);
+    /*
+        DER binary form: base 2, scaling factor 0 and odd mantissa
+    */
+    fn binary_parts(&self) -> (bool,i64,u64) {
+        let bits :u64 = self.val.to_bits();
+        let bexp :i64 = ((bits >> 52) & 0x7ff) as i64;
+        let mut mant :u64 = bits & 0xfffffffffffff;
+        let mut exp :i64;
+        if bexp == 0 {
+            exp = -1074;
+        } else {
+            mant |= 1 << 52;
+            exp = bexp - 1075;
         }
-        let a = retm.freeze();
-
-        let s = String::from_utf8_lossy(&a).to_string();
-        if s.len() < 12 {
-            asn1obj_new_error!{Asn1ObjBaseError,"not valid string [{}]",s}
+        while (mant & 0x1) == 0 {
+            mant >>= 1;
+            exp += 1;
         }
+        return ((bits >> 63) != 0,exp,mant);
+    }
 
-        (year,mon,mday,hour,min,sec) = self.extract_encode_value(&s)?;
-        let _ = self.check_data_valid(year,mon,mday,hour,min,sec)?;
-        self.origval = format!("{}",s);
-        self.val = self.format_time_str(year,mon,mday,hour,min,sec);
-        asn1obj_log_trace!("Asn1Time {}",self.val);
-        self.data = Vec::new();
-        retv = hdrlen + totallen;
-        for i in 0..retv {
-            self.data.push(code[i]);
+    fn encode_binary(&self) -> Vec<u8> {
+        let mut retv :Vec<u8> = Vec::new();
+        let (neg,exp,mant) = self.binary_parts();
+        let mut first :u8 = 0x80;
+        if neg {
+            first |= 0x40;
         }
-        Ok(retv)
+        let ev :Vec<u8> = BigInt::from(exp).to_signed_bytes_be();
+        if ev.len() <= 3 {
+            first |= (ev.len() - 1) as u8;
+            retv.push(first);
+        } else {
+            first |= 0x3;
+            retv.push(first);
+            retv.push(ev.len() as u8);
+        }
+        retv.extend_from_slice(&ev);
+        let mv :Vec<u8> = BigUint::from(mant).to_bytes_be();
+        retv.extend_from_slice(&mv);
+        return retv;
     }
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let llen :u64;
-        let mut retv :Vec<u8>;
-        let vcode :Vec<u8>;
-
-
-        let (year,mon,mday,hour,min,sec) = self.extract_date_value(&self.val)?;
-        let s;
-        if self.origval.len() == 0 {
-            if self.utag == ASN1_GENERALTIME_FLAG {
-                s = format!("{:04}{:02}{:02}{:02}{:02}{:02}Z",year,mon,mday,hour,min,sec);    
-            } else {
-                s = format!("{:02}{:02}{:02}{:02}{:02}{:02}Z",year % 100,mon,mday,hour,min,sec);
+    /*the content length of encode_asn1, no octets formatted*/
+    fn content_len(&self) -> usize {
+        if self.decimal_preserved() {
+            return 1 + self.decimal.len();
+        } else if self.val.is_nan() || self.val == f64::INFINITY || self.val == f64::NEG_INFINITY {
+            return 1;
+        } else if self.val == 0.0 {
+            if self.val.is_sign_negative() {
+                return 1;
             }
-            
+            return 0;
+        }
+        let (_,exp,mant) = self.binary_parts();
+        let mut elen :usize = 1;
+        while exp < -(1i64 << (elen * 8 - 1)) || exp >= (1i64 << (elen * 8 - 1)) {
+            elen += 1;
+        }
+        let mlen :usize = (((64 - mant.leading_zeros()) + 7) / 8) as usize;
+        if elen <= 3 {
+            return 1 + elen + mlen;
+        }
+        return 2 + elen + mlen;
+    }
+
+    fn decode_binary(content :&[u8]) -> Result<f64,Box<dyn Error>> {
+        let first :u8 = content[0];
+        let shift :i64;
+        let scale :i64 = ((first >> 2) & 0x3) as i64;
+        let mut pos :usize = 1;
+        let elen :usize;
+        let mut exp :i64;
+        let mut mant :f64 = 0.0;
+        match (first >> 4) & 0x3 {
+            0 => { shift = 1; },
+            1 => { shift = 3; },
+            2 => { shift = 4; },
+            _ => {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"reserved base in [0x{:02x}]", first}
+            }
+        }
+        if (first & 0x3) == 0x3 {
+            if content.len() < 2 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"no exponent length"}
+            }
+            elen = content[1] as usize;
+            pos = 2;
         } else {
-            s = format!("{}",self.origval);
-        }        
-        vcode = s.as_bytes().to_vec();
-        llen = vcode.len() as u64;
-
-        retv = asn1obj_format_header(self.utag as u64,llen);
-
-        for i in 0..vcode.len() {
-            retv.push(vcode[i]);
+            elen = ((first & 0x3) + 1) as usize;
+        }
+        if elen == 0 || elen > 8 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"exponent length [{}] not supported", elen}
+        }
+        if content.len() <= (pos + elen) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"content len [{}] <= exponent end [{}]", content.len(), pos + elen}
+        }
+        if asn1obj_is_der() {
+            if shift != 1 || scale != 0 || (content[content.len() - 1] & 0x1) == 0 || content[pos + elen] == 0 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,0,"DER REAL [0x{:02x}] not base 2 with odd mantissa", first}
+            }
+        }
+        exp = 0;
+        if (content[pos] & 0x80) != 0 {
+            exp = -1;
+        }
+        for i in 0..elen {
+            exp = (exp << 8) | (content[pos + i] as i64);
+        }
+        pos += elen;
+        for i in pos..content.len() {
+            mant = mant * 256.0 + (content[i] as f64);
+        }
+        /*the exponent out of i64 is far out of f64 too, saturate it before the clamp*/
+        let mut total :i64;
+        match exp.checked_mul(shift).and_then(|v| v.checked_add(scale)) {
+            Some(v) => {
+                total = v;
+            },
+            None => {
+                if exp < 0 {
+                    total = i64::MIN;
+                } else {
+                    total = i64::MAX;
+                }
+            }
+        }
+        if total > 4096 {
+            total = 4096;
+        } else if total < -4096 {
+            total = -4096;
+        }
+        let half :i32 = (total / 2) as i32;
+        let mut retv :f64 = mant * (2.0 as f64).powi(half) * (2.0 as f64).powi(total as i32 - half);
+        if (first & 0x40) != 0 {
+            retv = -retv;
         }
         Ok(retv)
     }
-
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {     
-        let s :String;
-        s = asn1_format_line(tab,&(format!("{}: ASN1_TIME {}", name, self.val)));
-        iowriter.write(s.as_bytes())?;
-        Ok(())
-    }
-}
-
-
-#[derive(Clone)]
-pub struct Asn1BigNum {
-    pub val :BigUint,
-    data :Vec<u8>,
 }
 
-impl Asn1BigNum {
-    pub fn set_value(&mut self, val :&[u8]) -> Vec<u8> {
-        let retv = self.val.to_bytes_be().to_vec();
-        self.val = BigUint::from_bytes_be(val);
-        return retv;
+impl Asn1Op for Asn1Real {
+    fn equal_asn1(&self, other :&Self) -> bool {
+        if self.val.to_bits() != other.val.to_bits() && !(self.val.is_nan() && other.val.is_nan()) {
+            return false;
+        }
+        return true;
     }
-}
 
-impl Asn1Op for Asn1BigNum {
+    /*
+        json number for the binary finite value, the special value name,
+        and the text for the decimal form
+    */
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
-        let s = format!("\"{}\"",self.val.to_str_radix(16));
-        let setjson :serde_json::value::Value = serde_json::from_str(&s).unwrap();
+        let setjson :serde_json::value::Value;
+        let ores = self.special_name();
+        if ores.is_some() {
+            setjson = serde_json::json!(ores.unwrap());
+        } else if self.decimal_preserved() {
+            setjson = serde_json::json!(self.decimal);
+        } else {
+            setjson = serde_json::json!(self.val);
+        }
         if key.len() > 0 {
-            val[key] = setjson;    
+            val[key] = setjson;
         } else {
             *val = setjson;
         }
-        
         Ok(1)
     }
 
@@ -3089,32 +5889,43 @@ impl Asn1Op for Asn1BigNum {
         if key.len() > 0 {
             let ores = val.get(key);
             if ores.is_none() {
-                self.val = BigUint::parse_bytes(b"0",16).unwrap();
+                let _ = self.set_value(0.0);
                 self.data = Vec::new();
                 return Ok(0);
             }
-            vmap =serde_json::json!(ores.unwrap());
+            vmap = serde_json::json!(ores.unwrap());
         } else {
             vmap = val.clone();
         }
-        if  !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string",key}
-        }
-
-        if vmap.is_string() {
+        self.data = Vec::new();
+        if vmap.is_number() {
+            let _ = self.set_value(vmap.as_f64().unwrap());
+        } else if vmap.is_string() {
             let c = vmap.as_str().unwrap();
-            let ores = BigUint::parse_bytes(c.as_bytes(),16);
-            if ores.is_none() {
-                asn1obj_new_error!{Asn1ObjBaseError,"{} {} not valid biguint",key,c}
+            if c == ASN1_REAL_PLUS_INFINITY {
+                let _ = self.set_value(f64::INFINITY);
+            } else if c == ASN1_REAL_MINUS_INFINITY {
+                let _ = self.set_value(f64::NEG_INFINITY);
+            } else if c == ASN1_REAL_NOT_A_NUMBER {
+                let _ = self.set_value(f64::NAN);
+            } else if c.contains("e") || c.contains("E") {
+                let _ = self.set_decimal(ASN1_REAL_NR3,c)?;
+            } else if c.contains(".") || c.contains(",") {
+                let _ = self.set_decimal(ASN1_REAL_NR2,c)?;
+            } else {
+                let _ = self.set_decimal(ASN1_REAL_NR1,c)?;
             }
-            self.val = ores.unwrap();
+        } else {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid number or string",key}
         }
         return Ok(1);
     }
 
     fn init_asn1() -> Self {
-        Asn1BigNum {
-            val : Zero::zero(),
+        Asn1Real {
+            val : 0.0,
+            form : ASN1_REAL_BINARY,
+            decimal : "".to_string(),
             data : Vec::new(),
         }
     }
@@ -3122,26 +5933,55 @@ impl Asn1Op for Asn1BigNum {
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
-        if flag != ASN1_INTEGER_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
+        if flag != ASN1_REAL_FLAG as u64 {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_REAL_FLAG [0x{:02x}]", flag,ASN1_REAL_FLAG}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
-        }
-
-        if totallen < 1 {
-            asn1obj_new_error!{Asn1ObjBaseError,"need 1 length"}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+        }
+
+        let content :&[u8] = &code[hdrlen..(hdrlen+totallen)];
+        self.form = ASN1_REAL_BINARY;
+        self.decimal = "".to_string();
+        if content.len() == 0 {
+            self.val = 0.0;
+        } else if (content[0] & 0x80) != 0 {
+            let ores = Self::decode_binary(content);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
+            self.val = ores.unwrap();
+        } else if (content[0] & 0x40) != 0 {
+            if content.len() != 1 {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,hdrlen,"special value len [{}] != 1", content.len()}
+            }
+            match content[0] {
+                0x40 => { self.val = f64::INFINITY; },
+                0x41 => { self.val = f64::NEG_INFINITY; },
+                0x42 => { self.val = f64::NAN; },
+                0x43 => { self.val = -0.0; },
+                _ => {
+                    asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen,"special value [0x{:02x}] not supported", content[0]}
+                }
+            }
+        } else {
+            let form :u8 = content[0] & 0x3f;
+            let ores = std::str::from_utf8(&content[1..]);
+            if ores.is_err() {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,hdrlen + 1,"decimal not valid text"}
+            }
+            let s = ores.unwrap();
+            let ores = self.set_decimal(form,s);
+            if ores.is_err() {
+                return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+            }
         }
-
-        self.val = BigUint::from_bytes_be(&code[hdrlen..(hdrlen+totallen)]);
-        let cc = self.val.to_bytes_be();
-        asn1obj_debug_buffer_trace!(cc.as_ptr(), cc.len(),"Asn1BigNum");
-        asn1obj_log_trace!("Asn1BigNum {:?}", self.val);
+        asn1obj_log_trace!("Asn1Real {} form {}", self.val, self.form);
         self.data = Vec::new();
         for i in 0..(hdrlen + totallen) {
             self.data.push(code[i]);
@@ -3150,77 +5990,62 @@ impl Asn1Op for Asn1BigNum {
         Ok(retv)
     }
 
-    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
-        let mut retv :Vec<u8> ;
-        let v8 :Vec<u8>;
-        let mut clen :usize ;
-        v8 = self.val.to_bytes_be();
-        clen = v8.len();
-        if v8.len() > 0 && (v8[0] & 0x80) != 0x0 {
-            clen = v8.len() + 1;
-        }
-        retv = asn1obj_format_header(ASN1_INTEGER_FLAG as u64, clen as u64);
-        if clen != v8.len() {
-            retv.push(0x0);
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.data.len() == 0 {
+            return None;
         }
-        for v in v8.iter() {
-            retv.push(*v);
+        return Some(self.data.clone());
+    }
+
+    fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
+        let mut content :Vec<u8> = Vec::new();
+        if self.decimal_preserved() {
+            content.push(self.form);
+            content.extend_from_slice(self.decimal.as_bytes());
+        } else if self.val.is_nan() {
+            content.push(0x42);
+        } else if self.val == f64::INFINITY {
+            content.push(0x40);
+        } else if self.val == f64::NEG_INFINITY {
+            content.push(0x41);
+        } else if self.val == 0.0 {
+            if self.val.is_sign_negative() {
+                content.push(0x43);
+            }
+        } else {
+            content = self.encode_binary();
         }
+        let mut retv :Vec<u8> = asn1obj_format_header(ASN1_REAL_FLAG as u64, content.len() as u64)?;
+        retv.extend_from_slice(&content);
         Ok(retv)
     }
 
-    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> { 
-        let v8 = self.val.to_bytes_be();
-        let mut s :String;
-        if v8.len() < 8 {
-            s = asn1_format_line(tab, &(format!("{}: ASN1_BIGNUM 0x{:08x}", name, self.val)));
-        } else {
-            let mut c :String = "".to_string();
-            let mut i :usize=0;
-            let mut lasti :usize = 0;
-            s = asn1_format_line(tab, &(format!("{}: ASN1_BIGNUM", name)));
-            while i < v8.len() {
-                if (i %16) == 0 {
-                    if i > 0 {
-                        c.push_str("    ");
-                        while lasti != i {
-                            if v8[lasti] >= 0x20 && v8[lasti] <= 0x7e {
-                                c.push( v8[lasti] as char);
-                            } else {
-                                c.push_str(".");
-                            }
-                            lasti += 1;
-                        }
-                        s.push_str(&asn1_format_line(tab + 1, &format!( "{}",c)));
-                        c = "".to_string();
-                    }
-                    lasti = i;
-                }
-                if lasti != i {
-                    c.push_str(":");
-                }               
-                c.push_str(&format!("{:02x}",v8[i]));
-                i += 1;
-            }
-            if lasti != i {
-                while (i%16) != 0 {
-                    c.push_str("   ");
-                    i += 1;
-                }
-                c.push_str("    ");
-                while lasti < v8.len() {
-                    if v8[lasti] >= 0x20 && v8[lasti] <= 0x7e {
-                        c.push( v8[lasti] as char);
-                    } else {
-                        c.push_str(".");
-                    }
-                    lasti += 1;                    
-                }
-            }
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.content_len();
+        let hdrlen = asn1obj_header_len(ASN1_REAL_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
 
-            if c.len() > 0 {
-                s.push_str(&asn1_format_line(tab + 1, &format!("{}",c)));
-            }
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_REAL_FLAG as u64));
+    }
+
+    fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+        let s :String;
+        let ores = self.special_name();
+        if ores.is_some() {
+            s = asn1_format_line(tab,&(format!("{}: ASN1_REAL {}", name, ores.unwrap())));
+        } else if self.decimal_preserved() {
+            s = asn1_format_line(tab,&(format!("{}: ASN1_REAL {} NR{} \"{}\"", name, self.val, self.form, self.decimal)));
+        } else {
+            s = asn1_format_line(tab,&(format!("{}: ASN1_REAL {}", name, self.val)));
         }
         iowriter.write(s.as_bytes())?;
         Ok(())
@@ -3230,9 +6055,73 @@ impl Asn1Op for Asn1BigNum {
 #[derive(Clone)]
 pub struct Asn1BMPString {
     pub val :String,
+    raw :Vec<u8>,
+    units :Vec<u16>,
+    tail :Option<u8>,
 }
 
+/*
+    the content is utf16 big endian, the surrogate pairs are decoded to the chars out of BMP,
+    the lone surrogates and the odd tail octet are U+FFFD in val.
+    strict_string decode rejects any surrogate and the odd length,
+    lossless_string decode accepts the odd length and keeps the code units and the tail octet,
+    they are encoded back byte-exact while val is not changed
+*/
+impl Asn1BMPString {
+    pub fn set_value(&mut self, val :&str) -> String {
+        let retv = format!("{}",self.val);
+        self.val = val.to_string();
+        self.units = Vec::new();
+        self.tail = None;
+        return retv;
+    }
+
+    pub fn set_units(&mut self, units :&[u16], tail :Option<u8>) {
+        self.units = units.to_vec();
+        self.tail = tail;
+        self.val = Self::units_string(units,tail);
+    }
+
+    /*the code units and the tail octet to encode*/
+    pub fn get_units(&self) -> (Vec<u16>,Option<u8>) {
+        if self.units_kept() {
+            return (self.units.clone(),self.tail);
+        }
+        return (self.val.encode_utf16().collect(),None);
+    }
+
+    /*the kept code units still give val, compared char by char as units_string*/
+    fn units_kept(&self) -> bool {
+        if self.units.len() == 0 && self.tail.is_none() {
+            return false;
+        }
+        let mut tailc :Option<char> = None;
+        if self.tail.is_some() {
+            tailc = Some(char::REPLACEMENT_CHARACTER);
+        }
+        let units = char::decode_utf16(self.units.iter().copied()).map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER));
+        return units.chain(tailc).eq(self.val.chars());
+    }
+
+    /*the content length of encode_asn1, no code units collected*/
+    fn units_len(&self) -> usize {
+        if self.units_kept() {
+            if self.tail.is_some() {
+                return self.units.len() * 2 + 1;
+            }
+            return self.units.len() * 2;
+        }
+        return self.val.encode_utf16().count() * 2;
+    }
 
+    fn units_string(units :&[u16], tail :Option<u8>) -> String {
+        let mut retv :String = String::from_utf16_lossy(units);
+        if tail.is_some() {
+            retv.push(char::REPLACEMENT_CHARACTER);
+        }
+        return retv;
+    }
+}
 
 impl Asn1Op for Asn1BMPString {
     fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
@@ -3253,6 +6142,8 @@ impl Asn1Op for Asn1BMPString {
             let ores = val.get(key);
             if ores.is_none() {
                 self.val = "".to_string();
+                self.units = Vec::new();
+                self.tail = None;
                 return Ok(0);
             }
             vmap =serde_json::json!(ores.unwrap());
@@ -3260,69 +6151,128 @@ impl Asn1Op for Asn1BMPString {
             vmap = val.clone();
         }
         if  !vmap.is_string() {
-            asn1obj_new_error!{Asn1ObjBaseError,"{} not valid string",key}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidValue,0,"{} not valid string",key}
         }
 
         if vmap.is_string() {
             let c = vmap.as_str().unwrap();
-            self.val = format!("{}",c);
+            let _ = self.set_value(c);
         }
         return Ok(1);
     }
 
     fn init_asn1() -> Self {
         Self {
+            raw : Vec::new(),
             val : "".to_string(),
+            units : Vec::new(),
+            tail : None,
         }
     }
 
     fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
         let retv :usize;
         if code.len() < 2 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len [{}] < 2", code.len()}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"len [{}] < 2", code.len()}
+        }
+        if code.len() > 0 && code[0] == (ASN1_BMPSTRING_FLAG | ASN1_CONSTRUCTED) {
+            let (flatv,clen) = asn1obj_flatten_string(code,ASN1_BMPSTRING_FLAG)?;
+            let ores = self.decode_asn1(&flatv);
+            if ores.is_err() {
+                return Err(asn1obj_error_at(ores.err().unwrap(),0));
+            }
+            self.raw = code[0..clen].to_vec();
+            return Ok(clen);
         }
         let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 
         if flag != ASN1_BMPSTRING_FLAG as u64 {
-            asn1obj_new_error!{Asn1ObjBaseError,"flag [0x{:02x}] != ASN1_BMPSTRING_FLAG [0x{:02x}]", flag,ASN1_BMPSTRING_FLAG}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_BMPSTRING_FLAG [0x{:02x}]", flag,ASN1_BMPSTRING_FLAG}
         }
 
-        if (totallen % 2) != 0 {
-            asn1obj_new_error!{Asn1ObjBaseError,"len {} % 2 != 0",totallen}
+        let strict :bool = asn1obj_is_strict_string();
+        let lossless :bool = asn1obj_is_lossless_string();
+        if (totallen % 2) != 0 && (strict || !lossless) {
+            asn1obj_new_kind_error!{Asn1ObjBaseError,InvalidLength,0,"len {} % 2 != 0",totallen}
         }
 
         if code.len() < (hdrlen + totallen) {
-            asn1obj_new_error!{Asn1ObjBaseError,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+            asn1obj_new_kind_error!{Asn1ObjBaseError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
         }
 
 
         let mut a :Vec<u16> = vec![];
         let mut i :usize = 0;
-        while i < totallen {
-            a.push((code[hdrlen+i] as u16) << 8 | code[hdrlen+i+1] as u16);
+        while (i + 1) < totallen {
+            let c :u16 = (code[hdrlen+i] as u16) << 8 | code[hdrlen+i+1] as u16;
+            if strict && c >= 0xd800 && c <= 0xdfff {
+                asn1obj_new_kind_error!{Asn1ObjBaseError,ConstraintViolation,hdrlen+i,"surrogate [0x{:04x}] not in BMP",c}
+            }
+            a.push(c);
             i += 2;
         }
-        self.val = String::from_utf16_lossy(&a).to_string();
+        let mut tail :Option<u8> = None;
+        if (totallen % 2) != 0 {
+            tail = Some(code[hdrlen+totallen-1]);
+        }
+        self.val = Self::units_string(&a,tail);
+        self.units = Vec::new();
+        self.tail = None;
+        if lossless {
+            self.units = a;
+            self.tail = tail;
+        }
         retv= hdrlen + totallen;
+        self.raw = code[0..retv].to_vec();
         Ok(retv)
     }
 
+    fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+        if self.raw.len() == 0 {
+            return None;
+        }
+        return Some(self.raw.clone());
+    }
+
     fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+        let ores = self.preserved_asn1()?;
+        if ores.is_some() {
+            return Ok(ores.unwrap());
+        }
         let mut retv :Vec<u8> ;
-        let mut uv16:Vec<u16> = vec![];
-        for c in self.val.encode_utf16() {
-            uv16.push(c);
+        let (uv16,tail) = self.get_units();
+        let mut clen = uv16.len() * 2;
+        if tail.is_some() {
+            clen += 1;
         }
-        let clen = uv16.len() * 2;
-        retv = asn1obj_format_header(ASN1_BMPSTRING_FLAG as u64, clen as u64);
+        retv = asn1obj_format_header(ASN1_BMPSTRING_FLAG as u64, clen as u64)?;
         for c in uv16.iter() {
             retv.push(((*c >> 8 ) & 0xff) as u8);
             retv.push((*c  & 0xff) as u8);
         }
+        if tail.is_some() {
+            retv.push(tail.unwrap());
+        }
 
+        if asn1obj_is_cer() {
+            return asn1obj_cer_string(&retv);
+        }
         Ok(retv)
     }
 
+    fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+        if asn1obj_write_by_encode() {
+            return asn1obj_encoded_len_of(self);
+        }
+        let clen :usize = self.units_len();
+        let hdrlen = asn1obj_header_len(ASN1_BMPSTRING_FLAG as u64, clen as u64)?;
+        Ok(hdrlen + clen)
+    }
+
+    fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+        return Ok(asn1obj_tag_octets_len(ASN1_BMPSTRING_FLAG as u64));
+    }
+
     fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> { 
         let s :String;
         s = asn1_format_line(tab, &(format!("{}: ASN1_BMPSTRING {}", name, self.val)));
@@ -4,17 +4,118 @@ use std::io::{Write};
 use std::error::Error;
 
 
-use crate::{asn1obj_error_class,asn1obj_new_error,asn1obj_debug_buffer_trace,asn1obj_format_buffer_log};
+use crate::{asn1obj_error_class,asn1obj_new_kind_error,asn1obj_debug_buffer_trace,asn1obj_format_buffer_log};
 use crate::{asn1obj_log_trace};
 use crate::logger::{asn1obj_debug_out,asn1obj_log_get_timestamp};
 
 use crate::strop::{asn1_format_line};
-use crate::base::{asn1obj_extract_header,asn1obj_format_header};
+use crate::base::{asn1obj_extract_header,asn1obj_extract_header_inf,asn1obj_flatten_string,asn1obj_format_header,asn1obj_make_tag,asn1obj_tag_number,asn1obj_tag_flag,asn1obj_tag_len,asn1obj_replace_tag,asn1obj_der_cmp,asn1obj_der_check_bits,asn1obj_cer_constructed,asn1obj_cer_string,asn1obj_format_tag};
+use crate::base::{asn1obj_header_len,asn1obj_tag_octets_len,asn1obj_write_header,asn1obj_encoded_len_of,asn1obj_write_encoded};
 
 use crate::consts::*;
+use crate::ctx::{asn1obj_is_der,asn1obj_is_cer,asn1obj_is_preserve,asn1obj_write_by_encode,asn1obj_check_elements,asn1obj_cached_len,Asn1EncodeLenGuard};
+use crate::errors::{asn1obj_error_shift,asn1obj_error_index,asn1obj_error_is_limit,asn1obj_error_remap};
+use crate::dump::{Asn1FieldRecordGuard,Asn1FieldMapGuard};
 
 asn1obj_error_class!{Asn1ComplexError}
 
+fn asn1obj_vals_len<T :Asn1Op>(vals :&[T]) -> Result<usize,Box<dyn Error>> {
+	let mut retv :usize = 0;
+	let mut idx :usize = 0;
+	while idx < vals.len() {
+		retv += vals[idx].encoded_len()?;
+		idx += 1;
+	}
+	Ok(retv)
+}
+
+fn asn1obj_write_vals<T :Asn1Op, W :Write>(flag :u64, vals :&[T], clen :usize, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+	let mut retv :usize;
+	let mut idx :usize = 0;
+	retv = asn1obj_write_header(iowriter,flag,clen as u64)?;
+	while idx < vals.len() {
+		retv += vals[idx].write_asn1(iowriter)?;
+		idx += 1;
+	}
+	Ok(retv)
+}
+
+/*
+    the length of the inner value with its identifier octets replaced by tlen octets,
+    no writing, so the nested tagged values are not written again for the length
+*/
+fn asn1obj_tagged_len<T :Asn1Op>(val :&T, tlen :usize) -> Result<usize,Box<dyn Error>> {
+	let clen :usize = val.encoded_len()?;
+	let ilen :usize = val.encoded_tag_len()?;
+	if ilen == 0 || clen < ilen {
+		asn1obj_new_kind_error!{Asn1ComplexError,InvalidValue,0,"no identifier len [{}] ilen [{}]", clen, ilen}
+	}
+	Ok(clen - ilen + tlen)
+}
+
+/*
+    pass the encoding of the inner value to iowriter with
+    the identifier octets replaced by the tag,
+    constructed bit is flag | (inner & consmask)
+*/
+struct Asn1TagWriter<'a, W :Write> {
+	iowriter :&'a mut W,
+	flag :u8,
+	consmask :u8,
+	tag :u64,
+	ident :Vec<u8>,
+	replaced :bool,
+	written :usize,
+}
+
+impl<'a, W :Write> Asn1TagWriter<'a,W> {
+	fn new(iowriter :&'a mut W, flag :u8, consmask :u8, tag :u64) -> Self {
+		Asn1TagWriter {
+			iowriter : iowriter,
+			flag : flag,
+			consmask : consmask,
+			tag : tag,
+			ident : Vec::new(),
+			replaced : false,
+			written : 0,
+		}
+	}
+
+	fn finish(&self) -> Result<usize,Box<dyn Error>> {
+		if !self.replaced {
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidValue,0,"no identifier written [{}]", self.ident.len()}
+		}
+		Ok(self.written)
+	}
+}
+
+impl<'a, W :Write> Write for Asn1TagWriter<'a,W> {
+	fn write(&mut self, buf :&[u8]) -> std::io::Result<usize> {
+		let mut idx :usize = 0;
+		if self.replaced {
+			let c = self.iowriter.write(buf)?;
+			self.written += c;
+			return Ok(c);
+		}
+		while idx < buf.len() {
+			self.ident.push(buf[idx]);
+			idx += 1;
+			if (self.ident[0] & ASN1_PRIMITIVE_TAG) != ASN1_PRIMITIVE_TAG || (self.ident.len() > 1 && (buf[idx-1] & 0x80) == 0) {
+				let code = asn1obj_format_tag(asn1obj_make_tag(self.flag | (self.ident[0] & self.consmask), self.tag));
+				self.iowriter.write_all(&code)?;
+				self.written += code.len();
+				self.replaced = true;
+				break;
+			}
+		}
+		Ok(idx)
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		return self.iowriter.flush();
+	}
+}
+
 #[derive(Clone)]
 pub struct Asn1Opt<T : Asn1Op + Clone> {
 	pub val : Option<T>,
@@ -56,9 +157,12 @@ impl<T: Asn1Op + Clone> Asn1Op for Asn1Opt<T> {
 			v = T::init_asn1();
 		}
 
-		let ores = v.decode_asn1(code);
+		let ores = v.decode_asn1_at(code,0);
 		if ores.is_err() {
 			let e = ores.err().unwrap();
+			if asn1obj_error_is_limit(&e) {
+				return Err(e);
+			}
 			if code.len() > 20 {
 				asn1obj_debug_buffer_trace!(code.as_ptr(),20,"Asn1Opt decode [{}:0x{:x}] error[{:?}]", code.len(),code.len(),e);
 			} else {
@@ -78,6 +182,13 @@ impl<T: Asn1Op + Clone> Asn1Op for Asn1Opt<T> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.val.is_none() {
+			return Some(Vec::new());
+		}
+		return self.val.as_ref().unwrap().get_raw_asn1();
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8> = Vec::new();
 		if self.val.is_some() {
@@ -86,6 +197,27 @@ impl<T: Asn1Op + Clone> Asn1Op for Asn1Opt<T> {
 		Ok(retv)
 	}
 
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if self.val.is_none() {
+			return Ok(0);
+		}
+		return self.val.as_ref().unwrap().encoded_len();
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		if self.val.is_none() {
+			return Ok(0);
+		}
+		return self.val.as_ref().unwrap().encoded_tag_len();
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if self.val.is_none() {
+			return Ok(0);
+		}
+		return self.val.as_ref().unwrap().write_asn1(iowriter);
+	}
+
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
 		if self.val.is_none() {
 			iowriter.write(asn1_format_line(tab,&format!("{}:<Absent>", name)).as_bytes())?;
@@ -105,15 +237,16 @@ impl<T: Asn1Op + Clone> Asn1Op for Asn1Opt<T> {
 }
 
 #[derive(Clone)]
-pub struct Asn1ImpSet<T : Asn1Op, const TAG:u8=0> {
+pub struct Asn1ImpSet<T : Asn1Op, const TAG:u64=0, const CLASS:u8=0x80> {
 	pub val : Vec<T>,
-	tag : u8,
+	tag : u64,
+	class : u8,
 	data : Vec<u8>,
 }
 
 
 
-impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
+impl<T: Asn1Op, const TAG:u64, const CLASS:u8> Asn1Op for Asn1ImpSet<T,TAG,CLASS> {
 	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
 		let mut mainv :Vec<serde_json::value::Value> = serde_json::from_str("[]").unwrap();
 		let mut idx :i32 = 0;
@@ -174,7 +307,7 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
 				idx += 1;
 			}
 		} else {
-			asn1obj_new_error!{Asn1ComplexError,"{} not valid type",key}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidValue,0,"{} not valid type",key}
 		}
 		return Ok(idx);
 	}
@@ -182,30 +315,47 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
 		self.val = Vec::new();
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
-		if ((flag as u8) & ASN1_IMP_SET_MASK ) != ASN1_IMP_SET_MASK {
+		self.data = Vec::new();
+		if asn1obj_tag_flag(flag) != (self.class | ASN1_CONSTRUCTED) {
 			/*we do have any type*/
 			return Ok(retv);
 		}
 
-		let ctag = (flag as u8) & ASN1_PRIMITIVE_TAG;
+		let ctag = asn1obj_tag_number(flag);
 		if ctag != self.tag {
-			asn1obj_new_error!(Asn1ComplexError,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag,self.tag)
+			asn1obj_new_kind_error!(Asn1ComplexError,UnexpectedTag,0,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag,self.tag)
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 
 		retv += hdrlen;
+		let der :bool = asn1obj_is_der();
+		let mut lastpos :usize = retv;
 		while retv < (totallen + hdrlen) {
 			let mut v :T = T::init_asn1();
-			let c = v.decode_asn1(&(code[retv..(hdrlen+totallen)]))?;
+			asn1obj_check_elements(self.val.len(),retv)?;
+			let _rec = Asn1FieldRecordGuard::enter_index(&code[retv..],self.val.len());
+			let ores = v.decode_asn1_at(&(code[0..(hdrlen+totallen)]),retv);
+			if ores.is_err() {
+				return Err(asn1obj_error_index(ores.err().unwrap(),self.val.len()));
+			}
+			let c = ores.unwrap();
+			if c == 0 {
+				asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,retv,"element [{}] at [{}] decoded nothing", self.val.len(), retv}
+			}
+			if der && lastpos < retv && asn1obj_der_cmp(&code[lastpos..retv],&code[retv..(retv+c)]) == std::cmp::Ordering::Greater {
+				asn1obj_new_kind_error!{Asn1ComplexError,ConstraintViolation,retv,"DER set element at [{}] not in order", retv}
+			}
+			lastpos = retv;
 			retv += c;
 			self.val.push(v);
 		}
+		retv += eoclen;
 
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -216,6 +366,14 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		/*no set decoded gives no octets*/
+		if self.data.len() == 0 && self.val.len() > 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8> = Vec::new();
 		let mut encv :Vec<u8> = Vec::new();
@@ -226,20 +384,62 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
 		if self.val.len() == 0{
 			return Ok(retv);
 		}
+		let mut encs :Vec<Vec<u8>> = Vec::new();
 		while idx < self.val.len() {
 			let code = self.val[idx].encode_asn1()?;
+			encs.push(code);
+			idx += 1;
+		}
+		if asn1obj_is_cer() {
+			/*CER set of must be sorted as DER*/
+			encs.sort_by(|a,b| asn1obj_der_cmp(a,b));
+		}
+		for code in encs.iter() {
 			for i in 0..code.len() {
 				encv.push(code[i]);
 			}
-			idx += 1;
 		}
 
-		flag = (ASN1_IMP_SET_MASK | self.tag ) as u64;
-		retv = asn1obj_format_header(flag,encv.len() as u64);
+		flag = asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag);
+		retv = asn1obj_format_header(flag,encv.len() as u64)?;
 		for i in 0..encv.len() {
 			retv.push(encv[i]);
 		}
-		Ok(retv)
+		if asn1obj_is_cer() {
+			return self.preserved_constructed(asn1obj_cer_constructed(&retv)?);
+		}
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if self.val.len() == 0 {
+			return Ok(0);
+		}
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		let hdrlen = asn1obj_header_len(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag),clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		if self.val.len() == 0 {
+			return Ok(0);
+		}
+		return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag)));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if self.val.len() == 0 {
+			return Ok(0);
+		}
+		if asn1obj_write_by_encode() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		return asn1obj_write_vals(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag),&self.val,clen,iowriter);
 	}
 
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
@@ -261,6 +461,7 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1ImpSet<T,TAG> {
 		Asn1ImpSet {
 			data : Vec::new(),
 			tag : TAG,
+			class : CLASS & ASN1_CLASS_MASK,
 			val : Vec::new(),
 		}
 	}
@@ -339,15 +540,15 @@ impl<T: Asn1Op> Asn1Op for Asn1Seq<T> {
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
 		self.val = Vec::new();
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
 		if (flag as u8) != ASN1_SEQ_MASK {
 			/*we do have any type*/
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] != ASN1_SEQ_MASK [0x{:02x}]", flag, ASN1_SEQ_MASK}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_SEQ_MASK [0x{:02x}]", flag, ASN1_SEQ_MASK}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 
@@ -355,11 +556,21 @@ impl<T: Asn1Op> Asn1Op for Asn1Seq<T> {
 		asn1obj_log_trace!("totallen {}",totallen);
 		while retv < (totallen + hdrlen) {
 			let mut v :T = T::init_asn1();
-			let c = v.decode_asn1(&(code[retv..(hdrlen+totallen)]))?;
+			asn1obj_check_elements(self.val.len(),retv)?;
+			let _rec = Asn1FieldRecordGuard::enter_index(&code[retv..],self.val.len());
+			let ores = v.decode_asn1_at(&(code[0..(hdrlen+totallen)]),retv);
+			if ores.is_err() {
+				return Err(asn1obj_error_index(ores.err().unwrap(),self.val.len()));
+			}
+			let c = ores.unwrap();
+			if c == 0 {
+				asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,retv,"element [{}] at [{}] decoded nothing", self.val.len(), retv}
+			}
 			asn1obj_log_trace!("c [{}]",c);
 			retv += c;
 			self.val.push(v);
 		}
+		retv += eoclen;
 
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -370,6 +581,13 @@ impl<T: Asn1Op> Asn1Op for Asn1Seq<T> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 		let mut encv :Vec<u8> = Vec::new();
@@ -384,11 +602,36 @@ impl<T: Asn1Op> Asn1Op for Asn1Seq<T> {
 			idx += 1;
 		}
 
-		retv = asn1obj_format_header(ASN1_SEQ_MASK as u64,encv.len() as u64);
+		retv = asn1obj_format_header(ASN1_SEQ_MASK as u64,encv.len() as u64)?;
 		for i in 0..encv.len() {
 			retv.push(encv[i]);
 		}
-		Ok(retv)
+		if asn1obj_is_cer() {
+			return self.preserved_constructed(asn1obj_cer_constructed(&retv)?);
+		}
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		let hdrlen = asn1obj_header_len(ASN1_SEQ_MASK as u64,clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(ASN1_SEQ_MASK as u64));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		return asn1obj_write_vals(ASN1_SEQ_MASK as u64,&self.val,clen,iowriter);
 	}
 
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
@@ -417,7 +660,7 @@ impl<T: Asn1Op> Asn1Op for Asn1Seq<T> {
 impl<T: Asn1Op> Asn1Seq<T> {
 	pub fn make_safe_one(&mut self,note :&str) -> Result<(),Box<dyn Error>> {
 		if self.val.len() != 0 && self.val.len() != 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} len {} != 1 or 0",note,self.val.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,ConstraintViolation,0,"{} len {} != 1 or 0",note,self.val.len()}
 		}
 		if self.val.len() == 0 {
 			self.val.push(T::init_asn1());
@@ -427,14 +670,14 @@ impl<T: Asn1Op> Asn1Seq<T> {
 
 	pub fn check_safe_one(&self,note :&str) -> Result<(),Box<dyn Error>> {
 		if self.val.len() != 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} len {} != 1",note,self.val.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,ConstraintViolation,0,"{} len {} != 1",note,self.val.len()}
 		}
 		Ok(())
 	}
 
 	pub fn sure_safe_one(&self, note :&str) -> Result<(),Box<dyn Error>> {
 		if self.val.len() != 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} len {} != 1",note,self.val.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,ConstraintViolation,0,"{} len {} != 1",note,self.val.len()}
 		}
 		Ok(())
 	}
@@ -510,7 +753,7 @@ impl<T: Asn1Op> Asn1Op for Asn1Set<T> {
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
 		self.val = Vec::new();
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
 		if (flag as u8) != ASN1_SET_MASK {
 			/*we do have any type*/
@@ -520,22 +763,38 @@ impl<T: Asn1Op> Asn1Op for Asn1Set<T> {
 				asn1obj_debug_buffer_trace!(code.as_ptr(),code.len(),"not match len");	
 			}
 			
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] != ASN1_SET_MASK [0x{:02x}]", flag, ASN1_SET_MASK}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_SET_MASK [0x{:02x}]", flag, ASN1_SET_MASK}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 		retv += hdrlen;
 		asn1obj_log_trace!("totallen [{}]", totallen);
+		let der :bool = asn1obj_is_der();
+		let mut lastpos :usize = retv;
 		while retv < (totallen + hdrlen) {
 			let mut v :T = T::init_asn1();
-			let c = v.decode_asn1(&(code[retv..(hdrlen+totallen)]))?;
+			asn1obj_check_elements(self.val.len(),retv)?;
+			let _rec = Asn1FieldRecordGuard::enter_index(&code[retv..],self.val.len());
+			let ores = v.decode_asn1_at(&(code[0..(hdrlen+totallen)]),retv);
+			if ores.is_err() {
+				return Err(asn1obj_error_index(ores.err().unwrap(),self.val.len()));
+			}
+			let c = ores.unwrap();
+			if c == 0 {
+				asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,retv,"element [{}] at [{}] decoded nothing", self.val.len(), retv}
+			}
 			asn1obj_log_trace!("passed [{}]", c);
+			if der && lastpos < retv && asn1obj_der_cmp(&code[lastpos..retv],&code[retv..(retv+c)]) == std::cmp::Ordering::Greater {
+				asn1obj_new_kind_error!{Asn1ComplexError,ConstraintViolation,retv,"DER set element at [{}] not in order", retv}
+			}
+			lastpos = retv;
 			retv += c;
 			self.val.push(v);
 		}
+		retv += eoclen;
 
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -546,25 +805,65 @@ impl<T: Asn1Op> Asn1Op for Asn1Set<T> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 		let mut encv :Vec<u8> = Vec::new();
 		let mut idx :usize = 0;
 
 
+		let mut encs :Vec<Vec<u8>> = Vec::new();
 		while idx < self.val.len() {
 			let code = self.val[idx].encode_asn1()?;
+			encs.push(code);
+			idx += 1;
+		}
+		if asn1obj_is_cer() {
+			/*CER set of must be sorted as DER*/
+			encs.sort_by(|a,b| asn1obj_der_cmp(a,b));
+		}
+		for code in encs.iter() {
 			for i in 0..code.len() {
 				encv.push(code[i]);
 			}
-			idx += 1;
 		}
 
-		retv = asn1obj_format_header(ASN1_SET_MASK as u64,encv.len() as u64);
+		retv = asn1obj_format_header(ASN1_SET_MASK as u64,encv.len() as u64)?;
 		for i in 0..encv.len() {
 			retv.push(encv[i]);
 		}
-		Ok(retv)
+		if asn1obj_is_cer() {
+			return self.preserved_constructed(asn1obj_cer_constructed(&retv)?);
+		}
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		let hdrlen = asn1obj_header_len(ASN1_SET_MASK as u64,clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(ASN1_SET_MASK as u64));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let clen = asn1obj_cached_len(self,|| asn1obj_vals_len(&self.val))?;
+		return asn1obj_write_vals(ASN1_SET_MASK as u64,&self.val,clen,iowriter);
 	}
 
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
@@ -592,14 +891,15 @@ impl<T: Asn1Op> Asn1Op for Asn1Set<T> {
 
 
 #[derive(Clone)]
-pub struct Asn1Imp<T : Asn1Op,const TAG:u8=0> {
+pub struct Asn1Imp<T : Asn1Op,const TAG:u64=0, const CLASS:u8=0x80> {
 	pub val : T,
-	tag : u8,
+	tag : u64,
+	class : u8,
 	data : Vec<u8>,
 }
 
 
-impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Imp<T,TAG> {
+impl<T: Asn1Op, const TAG:u64, const CLASS:u8> Asn1Op for Asn1Imp<T,TAG,CLASS> {
 	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
 		return self.val.encode_json(key,val);
 	}
@@ -610,24 +910,24 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Imp<T,TAG> {
 
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		let  mut parsevec : Vec<u8>;
 		let encv :Vec<u8>;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
-		if ((flag as u8) & ASN1_IMP_FILTER_MASK) != ASN1_IMP_FLAG_MASK {
+		if (asn1obj_tag_flag(flag) & ASN1_CLASS_MASK) != self.class {
 			/*we do have any type*/
-			asn1obj_log_trace!("flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != ASN1_IMP_FLAG_MASK [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,ASN1_IMP_FLAG_MASK);
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != ASN1_IMP_FLAG_MASK [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,ASN1_IMP_FLAG_MASK}
+			asn1obj_log_trace!("flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != class [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,self.class);
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != class [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,self.class}
 		}
 
-		let ctag = code[0] & ASN1_PRIMITIVE_TAG;
+		let ctag = asn1obj_tag_number(flag);
 		if ctag != self.tag {
 			asn1obj_log_trace!("tag [0x{:02x}] != self.tag [0x{:02x}]", ctag, self.tag);
-			asn1obj_new_error!{Asn1ComplexError,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag, self.tag}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag, self.tag}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 
@@ -636,19 +936,31 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Imp<T,TAG> {
 		encv = self.val.encode_asn1()?;
 		if encv.len() < 1 {
 			asn1obj_log_trace!("{} < 1",encv.len());
-			asn1obj_new_error!{Asn1ComplexError,"{} < 1",encv.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"{} < 1",encv.len()}
 		}
 		asn1obj_debug_buffer_trace!(encv.as_ptr(),encv.len(),"encv value");
 		parsevec = Vec::new();
 		/*to make first tag*/
-		parsevec.push(encv[0]);
-		for i in 1..(totallen+hdrlen) {
+		let ilen = asn1obj_tag_len(&encv)?;
+		for i in 0..ilen {
+			parsevec.push(encv[i]);
+		}
+		/*constructed form for the segmented string*/
+		parsevec[0] |= asn1obj_tag_flag(flag) & ASN1_CONSTRUCTED;
+		for i in asn1obj_tag_len(code)?..(totallen+hdrlen+eoclen) {
 			parsevec.push(code[i]);
 		}
 
 		asn1obj_debug_buffer_trace!(parsevec.as_ptr(), parsevec.len(),"Asn1Imp decode buffer");
-		let _ = self.val.decode_asn1(&parsevec)?;
-		retv += totallen;
+		let ores;
+		{
+			let _map = Asn1FieldMapGuard::enter(&parsevec,ilen,code,asn1obj_tag_len(code)?);
+			ores = self.val.decode_asn1_at(&parsevec,0);
+		}
+		if ores.is_err() {
+			return Err(asn1obj_error_remap(ores.err().unwrap(),ilen,asn1obj_tag_len(code)?));
+		}
+		retv += totallen + eoclen;
 		self.data = Vec::new();
 		for i in 0..retv {
 			self.data.push(code[i]);
@@ -658,16 +970,47 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Imp<T,TAG> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 
 		retv = self.val.encode_asn1()?;
 		if retv.len() < 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} < 1",retv.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidValue,0,"{} < 1",retv.len()}
 		}
 
-		retv[0] = self.tag | ASN1_IMP_FLAG_MASK;
-		Ok(retv)
+		/*the implicit tag keeps the constructed form of the inner value*/
+		retv = asn1obj_replace_tag(&retv,asn1obj_make_tag(self.class | (retv[0] & ASN1_CONSTRUCTED),self.tag))?;
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_is_preserve() {
+			return asn1obj_encoded_len_of(self);
+		}
+		return asn1obj_cached_len(self,|| {
+			return asn1obj_tagged_len(&self.val,self.encoded_tag_len()?);
+		});
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(self.class,self.tag)));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_is_preserve() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let mut tagw = Asn1TagWriter::new(iowriter,self.class,ASN1_CONSTRUCTED,self.tag);
+		let _ = self.val.write_asn1(&mut tagw)?;
+		return tagw.finish();
 	}
 
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
@@ -681,20 +1024,22 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Imp<T,TAG> {
 		Asn1Imp {
 			data : Vec::new(),
 			tag : TAG,
+			class : CLASS & ASN1_CLASS_MASK,
 			val : T::init_asn1(),
 		}
 	}
 }
 
 #[derive(Clone)]
-pub struct Asn1Exp<T : Asn1Op,const TAG:u8=0> {
+pub struct Asn1Exp<T : Asn1Op,const TAG:u64=0, const CLASS:u8=0x80> {
 	pub val : T,
-	tag : u8,
+	tag : u64,
+	class : u8,
 	data : Vec<u8>,
 }
 
 
-impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Exp<T,TAG> {
+impl<T: Asn1Op, const TAG:u64, const CLASS:u8> Asn1Op for Asn1Exp<T,TAG,CLASS> {
 	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
 		return self.val.encode_json(key,val);
 	}
@@ -705,39 +1050,49 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Exp<T,TAG> {
 
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		let  mut parsevec : Vec<u8>;
 		let encv :Vec<u8>;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
-		if ((flag as u8) & ASN1_IMP_FILTER_MASK) != ASN1_IMP_SET_MASK {
+		if asn1obj_tag_flag(flag) != (self.class | ASN1_CONSTRUCTED) {
 			/*we do have any type*/
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != ASN1_IMP_SET_MASK [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,ASN1_IMP_SET_MASK}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != class [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,self.class | ASN1_CONSTRUCTED}
 		}
 
-		let ctag = code[0] & ASN1_PRIMITIVE_TAG;
+		let ctag = asn1obj_tag_number(flag);
 		if ctag != self.tag {
-			asn1obj_new_error!{Asn1ComplexError,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag, self.tag}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"tag [0x{:02x}] != self.tag [0x{:02x}]", ctag, self.tag}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 
 		retv += hdrlen;
 		encv = self.val.encode_asn1()?;
 		if encv.len() < 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} < 1",encv.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"{} < 1",encv.len()}
 		}
 		parsevec = Vec::new();
 		/*to make first tag*/
-		parsevec.push(encv[0]);
-		for i in 1..(totallen+hdrlen) {
+		let ilen = asn1obj_tag_len(&encv)?;
+		for i in 0..ilen {
+			parsevec.push(encv[i]);
+		}
+		for i in asn1obj_tag_len(code)?..(totallen+hdrlen+eoclen) {
 			parsevec.push(code[i]);
 		}
 
-		let _ = self.val.decode_asn1(&parsevec)?;
-		retv += totallen;
+		let ores;
+		{
+			let _map = Asn1FieldMapGuard::enter(&parsevec,ilen,code,asn1obj_tag_len(code)?);
+			ores = self.val.decode_asn1_at(&parsevec,0);
+		}
+		if ores.is_err() {
+			return Err(asn1obj_error_remap(ores.err().unwrap(),ilen,asn1obj_tag_len(code)?));
+		}
+		retv += totallen + eoclen;
 		self.data = Vec::new();
 		for i in 0..retv {
 			self.data.push(code[i]);
@@ -747,16 +1102,46 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Exp<T,TAG> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 
 		retv = self.val.encode_asn1()?;
 		if retv.len() < 1 {
-			asn1obj_new_error!{Asn1ComplexError,"{} < 1",retv.len()}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidValue,0,"{} < 1",retv.len()}
 		}
 
-		retv[0] = self.tag | ASN1_IMP_SET_MASK;
-		Ok(retv)
+		retv = asn1obj_replace_tag(&retv,asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag))?;
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_is_preserve() {
+			return asn1obj_encoded_len_of(self);
+		}
+		return asn1obj_cached_len(self,|| {
+			return asn1obj_tagged_len(&self.val,self.encoded_tag_len()?);
+		});
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag)));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_is_preserve() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let mut tagw = Asn1TagWriter::new(iowriter,self.class | ASN1_CONSTRUCTED,0,self.tag);
+		let _ = self.val.write_asn1(&mut tagw)?;
+		return tagw.finish();
 	}
 
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
@@ -770,6 +1155,7 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Exp<T,TAG> {
 		Self {
 			data : Vec::new(),
 			tag : TAG,
+			class : CLASS & ASN1_CLASS_MASK,
 			val : T::init_asn1(),
 		}
 	}
@@ -777,13 +1163,25 @@ impl<T: Asn1Op, const TAG:u8> Asn1Op for Asn1Exp<T,TAG> {
 
 
 #[derive(Clone)]
-pub struct Asn1Ndef<T : Asn1Op + Clone, const TAG:u8=0> {
+pub struct Asn1Ndef<T : Asn1Op + Clone, const TAG:u64=0, const CLASS:u8=0x80> {
 	pub val :Option<T>,
-	tag : u8,
+	tag : u64,
+	class : u8,
 	data : Vec<u8>,
 }
 
-impl<T: Asn1Op + Clone, const TAG:u8> Asn1Op for Asn1Ndef<T,TAG> {
+impl<T: Asn1Op + Clone, const TAG:u64, const CLASS:u8> Asn1Ndef<T,TAG,CLASS> {
+	/*the absent value is written as the init one*/
+	fn content_len(&self) -> Result<usize,Box<dyn Error>> {
+		if self.val.is_some() {
+			return self.val.as_ref().unwrap().encoded_len();
+		}
+		let v = T::init_asn1();
+		return v.encoded_len();
+	}
+}
+
+impl<T: Asn1Op + Clone, const TAG:u64, const CLASS:u8> Asn1Op for Asn1Ndef<T,TAG,CLASS> {
 	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
 		if self.val.is_none() {
 			return Ok(0);
@@ -811,32 +1209,33 @@ impl<T: Asn1Op + Clone, const TAG:u8> Asn1Op for Asn1Ndef<T,TAG> {
 
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize;
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
-		if ((flag as u8) & ASN1_IMP_SET_MASK) != ASN1_IMP_SET_MASK {
+		if asn1obj_tag_flag(flag) != (self.class | ASN1_CONSTRUCTED) {
 			/*we do have any type*/
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] & ASN1_IMP_SET_MASK[0x{:02x}] != ASN1_IMP_SET_MASK [0x{:02x}]", flag, ASN1_IMP_SET_MASK,ASN1_IMP_SET_MASK}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] & ASN1_IMP_FILTER_MASK[0x{:02x}] != class [0x{:02x}]", flag, ASN1_IMP_FILTER_MASK,self.class | ASN1_CONSTRUCTED}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
-		let ctag = code[0] & ASN1_PRIMITIVE_TAG;
+		let ctag = asn1obj_tag_number(flag);
 		if ctag != self.tag {
-			asn1obj_new_error!{Asn1ComplexError,"tag [0x{:02x}] != self.tag [0x{:02x}]",ctag,self.tag}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"tag [0x{:02x}] != self.tag [0x{:02x}]",ctag,self.tag}
 		}
 		self.val = None;
 		retv = hdrlen;
 		if totallen > 0 {
 			let mut v :T = T::init_asn1();
-			let c = v.decode_asn1(&(code[retv..(hdrlen+totallen)]))?;
+			let c = v.decode_asn1_at(&(code[0..(hdrlen+totallen)]),retv)?;
 			if c != totallen {
-				asn1obj_new_error!{Asn1ComplexError,"c [{}] != totallen [{}]", c, totallen}
+				asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"c [{}] != totallen [{}]", c, totallen}
 			}
 			retv += totallen;
 			self.val = Some(v.clone());			
 		}
+		retv += eoclen;
 
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -847,6 +1246,13 @@ impl<T: Asn1Op + Clone, const TAG:u8> Asn1Op for Asn1Ndef<T,TAG> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 		let encv :Vec<u8>;
@@ -861,12 +1267,47 @@ impl<T: Asn1Op + Clone, const TAG:u8> Asn1Op for Asn1Ndef<T,TAG> {
 		}
 
 
-		flag = (ASN1_IMP_SET_MASK | self.tag) as u64;
+		flag = asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag);
 
-		retv = asn1obj_format_header(flag,encv.len() as u64);
+		retv = asn1obj_format_header(flag,encv.len() as u64)?;
 		for i in 0..encv.len() {
 			retv.push(encv[i]);
 		}
+		if asn1obj_is_cer() {
+			return self.preserved_constructed(asn1obj_cer_constructed(&retv)?);
+		}
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen = asn1obj_cached_len(self,|| self.content_len())?;
+		let hdrlen = asn1obj_header_len(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag),clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag)));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		let mut retv :usize;
+		let flag :u64;
+		if asn1obj_write_by_encode() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		flag = asn1obj_make_tag(self.class | ASN1_CONSTRUCTED,self.tag);
+		let clen = asn1obj_cached_len(self,|| self.content_len())?;
+		retv = asn1obj_write_header(iowriter,flag,clen as u64)?;
+		if self.val.is_some() {
+			retv += self.val.as_ref().unwrap().write_asn1(iowriter)?;
+		} else {
+			let v = T::init_asn1();
+			retv += v.write_asn1(iowriter)?;
+		}
 		Ok(retv)
 	}
 
@@ -885,11 +1326,21 @@ impl<T: Asn1Op + Clone, const TAG:u8> Asn1Op for Asn1Ndef<T,TAG> {
 		Asn1Ndef {
 			data : Vec::new(),
 			tag : TAG,
+			class : CLASS & ASN1_CLASS_MASK,
 			val : None,
 		}
 	}
 }
 
+pub type Asn1AppImp<T,const TAG:u64> = Asn1Imp<T,TAG,ASN1_CLASS_APPLICATION>;
+pub type Asn1PrivImp<T,const TAG:u64> = Asn1Imp<T,TAG,ASN1_CLASS_PRIVATE>;
+pub type Asn1AppExp<T,const TAG:u64> = Asn1Exp<T,TAG,ASN1_CLASS_APPLICATION>;
+pub type Asn1PrivExp<T,const TAG:u64> = Asn1Exp<T,TAG,ASN1_CLASS_PRIVATE>;
+pub type Asn1AppNdef<T,const TAG:u64> = Asn1Ndef<T,TAG,ASN1_CLASS_APPLICATION>;
+pub type Asn1PrivNdef<T,const TAG:u64> = Asn1Ndef<T,TAG,ASN1_CLASS_PRIVATE>;
+pub type Asn1AppImpSet<T,const TAG:u64> = Asn1ImpSet<T,TAG,ASN1_CLASS_APPLICATION>;
+pub type Asn1PrivImpSet<T,const TAG:u64> = Asn1ImpSet<T,TAG,ASN1_CLASS_PRIVATE>;
+
 #[derive(Clone)]
 pub struct Asn1SeqSelector<T : Asn1Op +  Asn1Selector + Clone> {
 	pub val : T,
@@ -907,29 +1358,30 @@ impl<T: Asn1Op + Asn1Selector + Clone> Asn1Op for Asn1SeqSelector<T> {
 
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
-		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
 		if (flag as u8) != ASN1_SEQ_MASK {
 			/*we do have any type*/
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}] != ASN1_SEQ_MASK [0x{:02x}]", flag, ASN1_SEQ_MASK}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_SEQ_MASK [0x{:02x}]", flag, ASN1_SEQ_MASK}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 		retv += hdrlen;
 		asn1obj_log_trace!("totallen {}",totallen);
 		while retv < (totallen + hdrlen) {
 			let mut v :T = T::init_asn1();
-			let c = v.decode_asn1(&(code[retv..(hdrlen+totallen)]))?;
+			let c = v.decode_asn1_at(&(code[0..(hdrlen+totallen)]),retv)?;
 			asn1obj_log_trace!("c [{}]",c);
 			if c != totallen {
-				asn1obj_new_error!{Asn1ComplexError, "c [{}] != totallen [{}]", c, totallen}
+				asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"c [{}] != totallen [{}]", c, totallen}
 			}
 			retv += c;
 			self.val = v.clone();
 		}
+		retv += eoclen;
 
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -939,15 +1391,50 @@ impl<T: Asn1Op + Asn1Selector + Clone> Asn1Op for Asn1SeqSelector<T> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
 		let mut retv :Vec<u8>;
 		let encv :Vec<u8>;
 
 		encv = self.val.encode_asn1()?;
-		retv = asn1obj_format_header(ASN1_SEQ_MASK as u64,encv.len() as u64);
+		retv = asn1obj_format_header(ASN1_SEQ_MASK as u64,encv.len() as u64)?;
 		for i in 0..encv.len() {
 			retv.push(encv[i]);
 		}
+		if asn1obj_is_cer() {
+			return self.preserved_constructed(asn1obj_cer_constructed(&retv)?);
+		}
+		self.preserved_constructed(retv)
+	}
+
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen = asn1obj_cached_len(self,|| self.val.encoded_len())?;
+		let hdrlen = asn1obj_header_len(ASN1_SEQ_MASK as u64,clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(ASN1_SEQ_MASK as u64));
+	}
+
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		let mut retv :usize;
+		if asn1obj_write_by_encode() {
+			return asn1obj_write_encoded(self,iowriter);
+		}
+		let _lens = Asn1EncodeLenGuard::enter();
+		let clen = asn1obj_cached_len(self,|| self.val.encoded_len())?;
+		retv = asn1obj_write_header(iowriter,ASN1_SEQ_MASK as u64,clen as u64)?;
+		retv += self.val.write_asn1(iowriter)?;
 		Ok(retv)
 	}
 
@@ -993,28 +1480,44 @@ impl<T: Asn1Op> Asn1Op for Asn1BitSeq<T> {
 
 	fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {
 		let mut retv :usize = 0;
+		if code.len() > 0 && code[0] == (ASN1_BIT_STRING_FLAG | ASN1_CONSTRUCTED) {
+			let (flatv,clen) = asn1obj_flatten_string(code,ASN1_BIT_STRING_FLAG)?;
+			let _ = self.decode_asn1(&flatv)?;
+			self.data = Vec::new();
+			for i in 0..clen {
+				self.data.push(code[i]);
+			}
+			return Ok(clen);
+		}
 		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
 		asn1obj_log_trace!("flag [0x{:x}]", flag);
 		if flag as u8 != ASN1_BIT_STRING_FLAG {
 			/*we do have any type*/
-			asn1obj_new_error!{Asn1ComplexError,"flag [0x{:02x}]  != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag, ASN1_BIT_STRING_FLAG}
+			asn1obj_new_kind_error!{Asn1ComplexError,UnexpectedTag,0,"flag [0x{:02x}]  != ASN1_BIT_STRING_FLAG [0x{:02x}]", flag, ASN1_BIT_STRING_FLAG}
 		}
 
 		if totallen < 1 {
-			asn1obj_new_error!{Asn1ComplexError,"totallen [{}] < 1", totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"totallen [{}] < 1", totallen}
 		}
 
 		if code.len() < (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
 		}
 
 
+		if asn1obj_is_der() {
+			let ores = asn1obj_der_check_bits(&code[hdrlen..(hdrlen+totallen)]);
+			if ores.is_err() {
+				return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+			}
+		}
+
 		retv += hdrlen + 1;
 
-		let c = self.val.decode_asn1(&code[retv..(hdrlen + totallen)])?;
+		let c = self.val.decode_asn1_at(&code[0..(hdrlen + totallen)],retv)?;
 		retv += c;
 		if retv != (hdrlen + totallen) {
-			asn1obj_new_error!{Asn1ComplexError,"decode [{}] != [{}] - 1", c, totallen}
+			asn1obj_new_kind_error!{Asn1ComplexError,InvalidLength,0,"decode [{}] != [{}] - 1", c, totallen}
 		}
 		self.data = Vec::new();
 		for i in 0..retv {
@@ -1025,7 +1528,18 @@ impl<T: Asn1Op> Asn1Op for Asn1BitSeq<T> {
 		Ok(retv)
 	}
 
+	fn get_raw_asn1(&self) -> Option<Vec<u8>> {
+		if self.data.len() == 0 {
+			return None;
+		}
+		return Some(self.data.clone());
+	}
+
 	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let ores = self.preserved_asn1()?;
+		if ores.is_some() {
+			return Ok(ores.unwrap());
+		}
 		let mut retv :Vec<u8>;
 		let vcode :Vec<u8>;
 		let mut idx :usize;
@@ -1063,15 +1577,31 @@ impl<T: Asn1Op> Asn1Op for Asn1BitSeq<T> {
 		}
 
 		let llen = (vcode.len() + 1) as u64;
-		retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64, llen);
+		retv = asn1obj_format_header(ASN1_BIT_STRING_FLAG as u64, llen)?;
 		retv.push(bits);
 		for i in 0..vcode.len() {
 			retv.push(vcode[i]);
 		}
 
+		if asn1obj_is_cer() {
+			return asn1obj_cer_string(&retv);
+		}
 		Ok(retv)
 	}
 
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		if asn1obj_write_by_encode() {
+			return asn1obj_encoded_len_of(self);
+		}
+		let clen :usize = asn1obj_cached_len(self,|| self.val.encoded_len())? + 1;
+		let hdrlen = asn1obj_header_len(ASN1_BIT_STRING_FLAG as u64,clen as u64)?;
+		Ok(hdrlen + clen)
+	}
+
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return Ok(asn1obj_tag_octets_len(ASN1_BIT_STRING_FLAG as u64));
+	}
+
 	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
 		let cname = format!("{} Asn1BitSeq",name);
 		let _ = self.val.print_asn1(&cname,tab,iowriter)?;
@@ -8,22 +8,53 @@ pub const ASN1_BIT_STRING_FLAG : u8 = 0x3;
 pub const ASN1_OCT_STRING_FLAG : u8 = 0x4;
 pub const ASN1_NULL_FLAG :u8 = 0x5;
 pub const ASN1_OBJECT_FLAG :u8 = 0x6;
+pub const ASN1_REAL_FLAG :u8 = 0x9;
+pub const ASN1_RELATIVE_OID_FLAG :u8 = 0xd;
 pub const ASN1_ENUMERATED_FLAG :u8 = 0xa;
 pub const ASN1_UTF8STRING_FLAG :u8 = 0xc;
+pub const ASN1_NUMERICSTRING_FLAG :u8 = 0x12;
 pub const ASN1_PRINTABLE_FLAG :u8 = 0x13;
 pub const ASN1_T61STRING_FLAG :u8 = 0x14;
+pub const ASN1_VIDEOTEXSTRING_FLAG :u8 = 0x15;
 pub const ASN1_PRINTABLE2_FLAG :u8 = 0x16;
 pub const ASN1_UTCTIME_FLAG : u8 = 0x17;
+pub const ASN1_GRAPHICSTRING_FLAG :u8 = 0x19;
+pub const ASN1_VISIBLESTRING_FLAG :u8 = 0x1a;
+pub const ASN1_GENERALSTRING_FLAG :u8 = 0x1b;
+pub const ASN1_UNIVERSALSTRING_FLAG :u8 = 0x1c;
 pub const ASN1_BMPSTRING_FLAG :u8 = 0x1e;
 pub const ASN1_GENERALTIME_FLAG : u8 = 0x18;
+/*the tag numbers above 30, made into tag by asn1obj_make_tag*/
+pub const ASN1_OID_IRI_TAG :u64 = 35;
+pub const ASN1_RELATIVE_OID_IRI_TAG :u64 = 36;
 pub const ASN1_IMP_FLAG_MASK :u8 = 0x80;
 pub const ASN1_SEQ_MASK :u8 = 0x30;
 pub const ASN1_SET_MASK :u8 = 0x31;
 pub const ASN1_IMP_SET_MASK :u8 = 0xa0;
 pub const ASN1_IMP_FILTER_MASK :u8 = 0xe0;
+pub const ASN1_CLASS_MASK :u8 = 0xc0;
+pub const ASN1_CLASS_UNIVERSAL :u8 = 0x00;
+pub const ASN1_CLASS_APPLICATION :u8 = 0x40;
+pub const ASN1_CLASS_CONTEXT :u8 = 0x80;
+pub const ASN1_CLASS_PRIVATE :u8 = 0xc0;
+pub const ASN1_CER_SEGMENT_SIZE :usize = 1000;
+pub const ASN1_HIGH_TAG_SHIFT :u64 = 8;
+pub const ASN1_HIGH_TAG_MAX :u64 = 0x00ffffffffffffff;
 
 pub const ASN1_TIME_DEFAULT_STR :&str = "1970-01-01 00:00";
 pub const ASN1_OBJECT_DEFAULT_STR :&str = "1.1.1";
+pub const ASN1_RELATIVE_OID_DEFAULT_STR :&str = "1";
+pub const ASN1_OID_IRI_DEFAULT_STR :&str = "/ISO";
+pub const ASN1_RELATIVE_OID_IRI_DEFAULT_STR :&str = "ISO";
+
+/*ISO 6093 number representations of the REAL decimal encoding, 0 for binary*/
+pub const ASN1_REAL_BINARY :u8 = 0;
+pub const ASN1_REAL_NR1 :u8 = 1;
+pub const ASN1_REAL_NR2 :u8 = 2;
+pub const ASN1_REAL_NR3 :u8 = 3;
+pub const ASN1_REAL_PLUS_INFINITY :&str = "PLUS-INFINITY";
+pub const ASN1_REAL_MINUS_INFINITY :&str = "MINUS-INFINITY";
+pub const ASN1_REAL_NOT_A_NUMBER :&str = "NOT-A-NUMBER";
 
 
 pub const ASN1_JSON_TAG :&str = "tag";
@@ -0,0 +1,311 @@
+use std::cell::{RefCell,Cell};
+use std::collections::HashMap;
+use std::error::Error;
+use crate::{asn1obj_new_kind_error};
+
+pub const ASN1_DEFAULT_MAX_DEPTH :usize = 128;
+
+/*
+    max_depth is the nested decode of the elements,
+    max_elements is the element count in one Asn1Seq Asn1Set Asn1ImpSet,
+    max_content is the content length of one element or the collected segmented string,
+    strict_string rejects the invalid utf8 in UTF8String and the octets out of
+    the alphabet of PrintableString IA5String and TeletexString,
+    and the surrogates in BMPString,
+    lossless_string keeps the utf16 code units of BMPString to encode them back
+*/
+#[derive(Clone,Debug)]
+pub struct Asn1DecodeCtx {
+	pub der :bool,
+	pub max_depth :usize,
+	pub max_elements :usize,
+	pub max_content :usize,
+	pub strict_string :bool,
+	pub lossless_string :bool,
+}
+
+impl Asn1DecodeCtx {
+	pub fn new() -> Self {
+		Asn1DecodeCtx {
+			der : false,
+			max_depth : ASN1_DEFAULT_MAX_DEPTH,
+			max_elements : usize::MAX,
+			max_content : usize::MAX,
+			strict_string : false,
+			lossless_string : false,
+		}
+	}
+
+	pub fn der() -> Self {
+		let mut retv = Self::new();
+		retv.der = true;
+		retv
+	}
+
+	pub fn strict_string() -> Self {
+		let mut retv = Self::new();
+		retv.strict_string = true;
+		retv
+	}
+
+	pub fn lossless_string() -> Self {
+		let mut retv = Self::new();
+		retv.lossless_string = true;
+		retv
+	}
+}
+
+thread_local! {
+	static ASN1_DECODE_CTX : RefCell<Asn1DecodeCtx> = RefCell::new(Asn1DecodeCtx::new());
+}
+
+/*return the old context*/
+pub fn asn1obj_set_decode_ctx(ctx :&Asn1DecodeCtx) -> Asn1DecodeCtx {
+	ASN1_DECODE_CTX.with(|c| {
+		c.replace(ctx.clone())
+	})
+}
+
+pub fn asn1obj_get_decode_ctx() -> Asn1DecodeCtx {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().clone()
+	})
+}
+
+pub fn asn1obj_is_der() -> bool {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().der
+	})
+}
+
+pub fn asn1obj_is_strict_string() -> bool {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().strict_string
+	})
+}
+
+pub fn asn1obj_is_lossless_string() -> bool {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().lossless_string
+	})
+}
+
+pub fn asn1obj_max_elements() -> usize {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().max_elements
+	})
+}
+
+pub fn asn1obj_max_content() -> usize {
+	ASN1_DECODE_CTX.with(|c| {
+		c.borrow().max_content
+	})
+}
+
+pub(crate) fn asn1obj_check_content(clen :usize) -> Result<(),Box<dyn Error>> {
+	let maxcontent = asn1obj_max_content();
+	if clen > maxcontent {
+		asn1obj_new_kind_error!{Asn1DecodeCtx,ContentTooLarge,0,"content len [0x{:x}] > max_content [0x{:x}]", clen, maxcontent}
+	}
+	Ok(())
+}
+
+pub(crate) fn asn1obj_check_elements(cnt :usize, offset :usize) -> Result<(),Box<dyn Error>> {
+	let maxelem = asn1obj_max_elements();
+	if cnt >= maxelem {
+		asn1obj_new_kind_error!{Asn1DecodeCtx,TooManyElements,offset,"elements [{}] >= max_elements [{}]", cnt, maxelem}
+	}
+	Ok(())
+}
+
+thread_local! {
+	static ASN1_DECODE_DEPTH : Cell<usize> = Cell::new(0);
+}
+
+/*count the nested decode, leave when drop*/
+pub(crate) struct Asn1DecodeDepthGuard {
+}
+
+impl Asn1DecodeDepthGuard {
+	pub(crate) fn enter() -> Result<Self,Box<dyn Error>> {
+		let depth :usize = ASN1_DECODE_DEPTH.with(|c| c.get());
+		let maxdepth :usize = ASN1_DECODE_CTX.with(|c| c.borrow().max_depth);
+		if depth >= maxdepth {
+			asn1obj_new_kind_error!{Asn1DecodeCtx,DepthExceeded,0,"decode depth [{}] >= max_depth [{}]", depth, maxdepth}
+		}
+		ASN1_DECODE_DEPTH.with(|c| c.set(depth + 1));
+		Ok(Asn1DecodeDepthGuard {})
+	}
+}
+
+impl Drop for Asn1DecodeDepthGuard {
+	fn drop(&mut self) {
+		ASN1_DECODE_DEPTH.with(|c| c.set(c.get() - 1));
+	}
+}
+
+/*to restore the old context when drop, even on panic*/
+pub(crate) struct Asn1DecodeCtxGuard {
+	oldctx :Asn1DecodeCtx,
+}
+
+impl Asn1DecodeCtxGuard {
+	pub(crate) fn new(ctx :&Asn1DecodeCtx) -> Self {
+		Asn1DecodeCtxGuard {
+			oldctx : asn1obj_set_decode_ctx(ctx),
+		}
+	}
+}
+
+impl Drop for Asn1DecodeCtxGuard {
+	fn drop(&mut self) {
+		let _ = asn1obj_set_decode_ctx(&self.oldctx);
+	}
+}
+
+/*
+    preserve is to give the original decoded octets back
+    for the value not modified since decode, see Asn1Op::preserved_asn1,
+    oid_names is to show Asn1Object with the registered name in print_asn1 and encode_json
+*/
+#[derive(Clone,Debug)]
+pub struct Asn1EncodeCtx {
+	pub cer :bool,
+	pub preserve :bool,
+	pub oid_names :bool,
+}
+
+impl Asn1EncodeCtx {
+	pub fn new() -> Self {
+		Asn1EncodeCtx {
+			cer : false,
+			preserve : false,
+			oid_names : false,
+		}
+	}
+
+	pub fn cer() -> Self {
+		let mut retv = Self::new();
+		retv.cer = true;
+		retv
+	}
+
+	pub fn preserve() -> Self {
+		let mut retv = Self::new();
+		retv.preserve = true;
+		retv
+	}
+
+	pub fn oid_names() -> Self {
+		let mut retv = Self::new();
+		retv.oid_names = true;
+		retv
+	}
+}
+
+thread_local! {
+	static ASN1_ENCODE_CTX : RefCell<Asn1EncodeCtx> = RefCell::new(Asn1EncodeCtx::new());
+}
+
+/*return the old context*/
+pub fn asn1obj_set_encode_ctx(ctx :&Asn1EncodeCtx) -> Asn1EncodeCtx {
+	ASN1_ENCODE_CTX.with(|c| {
+		c.replace(ctx.clone())
+	})
+}
+
+pub fn asn1obj_get_encode_ctx() -> Asn1EncodeCtx {
+	ASN1_ENCODE_CTX.with(|c| {
+		c.borrow().clone()
+	})
+}
+
+pub fn asn1obj_is_cer() -> bool {
+	ASN1_ENCODE_CTX.with(|c| {
+		c.borrow().cer
+	})
+}
+
+pub fn asn1obj_is_preserve() -> bool {
+	ASN1_ENCODE_CTX.with(|c| {
+		c.borrow().preserve
+	})
+}
+
+pub fn asn1obj_is_oid_names() -> bool {
+	ASN1_ENCODE_CTX.with(|c| {
+		c.borrow().oid_names
+	})
+}
+
+/*
+    the length and the writer must go through encode_asn1 when
+    the output is not from the value directly
+*/
+pub(crate) fn asn1obj_write_by_encode() -> bool {
+	ASN1_ENCODE_CTX.with(|c| {
+		let ctx = c.borrow();
+		ctx.cer || ctx.preserve
+	})
+}
+
+pub(crate) struct Asn1EncodeCtxGuard {
+	oldctx :Asn1EncodeCtx,
+}
+
+impl Asn1EncodeCtxGuard {
+	pub(crate) fn new(ctx :&Asn1EncodeCtx) -> Self {
+		Asn1EncodeCtxGuard {
+			oldctx : asn1obj_set_encode_ctx(ctx),
+		}
+	}
+}
+
+impl Drop for Asn1EncodeCtxGuard {
+	fn drop(&mut self) {
+		let _ = asn1obj_set_encode_ctx(&self.oldctx);
+	}
+}
+
+thread_local! {
+	static ASN1_ENCODE_LENS_DEPTH : Cell<usize> = Cell::new(0);
+	static ASN1_ENCODE_LENS : RefCell<HashMap<(usize,&'static str),usize>> = RefCell::new(HashMap::new());
+}
+
+/*
+    keep the lengths of the constructed values while one outer write_asn1 or encoded_len runs,
+    so the header of every ancestor does not compute the length of the subtree again,
+    the values are borrowed all the time, the address and the type name key them,
+    the lengths are dropped when the outer one leaves
+*/
+pub(crate) struct Asn1EncodeLenGuard {
+}
+
+impl Asn1EncodeLenGuard {
+	pub(crate) fn enter() -> Self {
+		ASN1_ENCODE_LENS_DEPTH.with(|c| c.set(c.get() + 1));
+		Asn1EncodeLenGuard {}
+	}
+}
+
+impl Drop for Asn1EncodeLenGuard {
+	fn drop(&mut self) {
+		let depth :usize = ASN1_ENCODE_LENS_DEPTH.with(|c| c.get()) - 1;
+		ASN1_ENCODE_LENS_DEPTH.with(|c| c.set(depth));
+		if depth == 0 {
+			ASN1_ENCODE_LENS.with(|c| c.borrow_mut().clear());
+		}
+	}
+}
+
+pub(crate) fn asn1obj_cached_len<T :?Sized, F :FnOnce() -> Result<usize,Box<dyn Error>>>(v :&T, lenfn :F) -> Result<usize,Box<dyn Error>> {
+	let key :(usize,&'static str) = (v as *const T as *const u8 as usize, std::any::type_name::<T>());
+	let ores = ASN1_ENCODE_LENS.with(|c| c.borrow().get(&key).copied());
+	if ores.is_some() {
+		return Ok(ores.unwrap());
+	}
+	let _guard = Asn1EncodeLenGuard::enter();
+	let retv :usize = lenfn()?;
+	ASN1_ENCODE_LENS.with(|c| c.borrow_mut().insert(key,retv));
+	Ok(retv)
+}
@@ -0,0 +1,541 @@
+
+use std::io::{Write};
+use std::error::Error;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::asn1impl::{Asn1Op};
+use crate::base::{Asn1Object,Asn1RelativeOid,Asn1BMPString,Asn1UniversalString,asn1obj_tag_number};
+use crate::node::{Asn1Node};
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
+
+use crate::consts::*;
+
+asn1obj_error_class!{Asn1DumpError}
+
+/*
+    hex dump as xx:xx:xx 16 bytes in one line with the printable chars,
+    every line is prefixed with tab * 4 spaces
+*/
+pub fn asn1obj_format_vecs(buf :&[u8], tab :i32) -> String {
+	let mut outs :String = "".to_string();
+	let mut lasti : usize = 0;
+	let mut ki :usize;
+	for i in 0..buf.len() {
+		if (i%16) == 0 {
+			if i > 0 {
+				outs.push_str("    ");
+				while lasti != i {
+					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
+						outs.push(buf[lasti] as char);
+					} else {
+						outs.push_str(".");
+					}
+					lasti += 1;
+				}
+				outs.push_str("\n");
+			}
+
+			for _j in 0..tab {
+				outs.push_str("    ");
+			}
+		}
+		if (i % 16) == 0 {
+			outs.push_str(&format!("{:02x}", buf[i]));
+		} else {
+			outs.push_str(&format!(":{:02x}", buf[i]));
+		}
+	}
+
+	if lasti != buf.len() {
+		ki = buf.len();
+		while (ki % 16) != 0 {
+			outs.push_str("   ");
+			ki += 1;
+		}
+		outs.push_str("    ");
+		while lasti != buf.len() {
+			if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
+				outs.push(buf[lasti] as char);
+			} else {
+				outs.push_str(".");
+			}
+			lasti += 1;
+		}
+	}
+	outs.push_str("\n");
+	return outs;
+}
+
+/*
+    the tag name as openssl asn1parse
+*/
+pub fn asn1obj_tag_name(tag :u64) -> String {
+	let number :u64 = asn1obj_tag_number(tag);
+	let class :u8 = ((tag & 0xff) as u8) & ASN1_CLASS_MASK;
+	if class == ASN1_CLASS_CONTEXT {
+		return format!("cont [ {} ]",number);
+	} else if class == ASN1_CLASS_APPLICATION {
+		return format!("appl [ {} ]",number);
+	} else if class == ASN1_CLASS_PRIVATE {
+		return format!("priv [ {} ]",number);
+	}
+	let s :&str = match number {
+		0 => "EOC",
+		1 => "BOOLEAN",
+		2 => "INTEGER",
+		3 => "BIT STRING",
+		4 => "OCTET STRING",
+		5 => "NULL",
+		6 => "OBJECT",
+		7 => "OBJECT DESCRIPTOR",
+		8 => "EXTERNAL",
+		9 => "REAL",
+		10 => "ENUMERATED",
+		11 => "EMBEDDED PDV",
+		12 => "UTF8STRING",
+		13 => "RELATIVE OID",
+		16 => "SEQUENCE",
+		17 => "SET",
+		18 => "NUMERICSTRING",
+		19 => "PRINTABLESTRING",
+		20 => "T61STRING",
+		21 => "VIDEOTEXSTRING",
+		22 => "IA5STRING",
+		23 => "UTCTIME",
+		24 => "GENERALIZEDTIME",
+		25 => "GRAPHICSTRING",
+		26 => "VISIBLESTRING",
+		27 => "GENERALSTRING",
+		28 => "UNIVERSALSTRING",
+		30 => "BMPSTRING",
+		35 => "OID-IRI",
+		36 => "RELATIVE-OID-IRI",
+		_ => "",
+	};
+	if s.len() == 0 {
+		return format!("<ASN1 {}>",number);
+	}
+	return format!("{}",s);
+}
+
+fn asn1obj_hex_upper(data :&[u8]) -> String {
+	let mut rets :String = "".to_string();
+	for b in data.iter() {
+		rets.push_str(&format!("{:02X}",b));
+	}
+	return rets;
+}
+
+fn asn1obj_dump_value(node :&Asn1Node) -> String {
+	if node.constructed || node.class != ASN1_CLASS_UNIVERSAL {
+		return "".to_string();
+	}
+	let data :&[u8] = &node.data;
+	match node.number {
+		1 => {
+			if data.len() == 1 {
+				return format!(":{}",data[0]);
+			}
+			return format!(":BAD BOOLEAN");
+		},
+		2 | 10 => {
+			if data.len() > 0 && (data[0] & 0x80) != 0 {
+				/*two's complement to the magnitude*/
+				let mut mag :Vec<u8> = Vec::new();
+				let mut carry :u16 = 1;
+				let mut idx :usize = data.len();
+				while idx > 0 {
+					idx -= 1;
+					let c :u16 = ((!data[idx]) as u16) + carry;
+					mag.insert(0,(c & 0xff) as u8);
+					carry = c >> 8;
+				}
+				while mag.len() > 1 && mag[0] == 0 {
+					mag.remove(0);
+				}
+				return format!(":-{}",asn1obj_hex_upper(&mag));
+			}
+			return format!(":{}",asn1obj_hex_upper(data));
+		},
+		4 => {
+			if node.encapsulated {
+				return "".to_string();
+			}
+			return format!("[HEX DUMP]:{}",asn1obj_hex_upper(data));
+		},
+		3 | 5 => {
+			return "".to_string();
+		},
+		6 => {
+			let mut o :Asn1Object = Asn1Object::init_asn1();
+			if o.decode_asn1(&node.encode()).is_ok() {
+				return format!(":{}",o.get_value());
+			}
+			return format!(":BAD OBJECT");
+		},
+		13 => {
+			let mut o :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+			if o.decode_asn1(&node.encode()).is_ok() {
+				return format!(":{}",o.get_value());
+			}
+			return format!(":BAD RELATIVE OID");
+		},
+		12 | 18 | 19 | 20 | 22 | 23 | 24 | 26 | 35 | 36 => {
+			return format!(":{}",String::from_utf8_lossy(data));
+		},
+		30 => {
+			let mut b :Asn1BMPString = Asn1BMPString::init_asn1();
+			if b.decode_asn1(&node.encode()).is_ok() {
+				return format!(":{}",b.val);
+			}
+			return format!(":BAD BMPSTRING");
+		},
+		28 => {
+			let mut u :Asn1UniversalString = Asn1UniversalString::init_asn1();
+			if u.decode_asn1(&node.encode()).is_ok() {
+				return format!(":{}",u.val);
+			}
+			return format!(":BAD UNIVERSALSTRING");
+		},
+		_ => {
+			return format!("[HEX DUMP]:{}",asn1obj_hex_upper(data));
+		}
+	}
+}
+
+fn asn1obj_dump_line<W :Write>(iowriter :&mut W, offset :usize, depth :usize, hdrlen :usize, length :Option<usize>, cons :bool, name :&str, value :&str, fields :&HashMap<usize,String>, lookup :bool) -> Result<(),Box<dyn Error>> {
+	let mut s :String = format!("{:5}:d={:<2} hl={} ",offset,depth,hdrlen);
+	if length.is_some() {
+		s.push_str(&format!("l={:4} ",length.unwrap()));
+	} else {
+		s.push_str("l=inf  ");
+	}
+	if cons {
+		s.push_str("cons: ");
+	} else {
+		s.push_str("prim: ");
+	}
+	for _ in 0..depth {
+		s.push_str(" ");
+	}
+	s.push_str(&format!("{:<18}{}",name,value));
+	if lookup {
+		let ores = fields.get(&offset);
+		if ores.is_some() {
+			s.push_str(&format!("  -- {}",ores.unwrap()));
+		}
+	}
+	s.push_str("\n");
+	iowriter.write_all(s.as_bytes())?;
+	Ok(())
+}
+
+fn asn1obj_dump_node<W :Write>(node :&Asn1Node, depth :usize, iowriter :&mut W, fields :&HashMap<usize,String>) -> Result<(),Box<dyn Error>> {
+	let mut length :Option<usize> = Some(node.content.len());
+	if node.eoclen > 0 {
+		length = None;
+	}
+	asn1obj_dump_line(iowriter,node.offset,depth,node.hdrlen,length,node.constructed,&asn1obj_tag_name(node.tag),&asn1obj_dump_value(node),fields,true)?;
+	for c in node.children.iter() {
+		asn1obj_dump_node(c,depth + 1,iowriter,fields)?;
+	}
+	if node.eoclen > 0 {
+		asn1obj_dump_line(iowriter,node.content.end,depth + 1,2,Some(0),false,"EOC","",fields,false)?;
+	}
+	Ok(())
+}
+
+impl Asn1Node {
+	/*
+	    dump as openssl asn1parse -i
+	*/
+	pub fn dump<W :Write>(&self, iowriter :&mut W) -> Result<(),Box<dyn Error>> {
+		return asn1obj_dump_node(self,0,iowriter,&HashMap::new());
+	}
+}
+
+/*
+    schema-less dump of all the elements in code
+*/
+pub fn asn1obj_dump<W :Write>(code :&[u8], iowriter :&mut W) -> Result<(),Box<dyn Error>> {
+	let nodes = Asn1Node::parse_all(code)?;
+	for n in nodes.iter() {
+		asn1obj_dump_node(n,0,iowriter,&HashMap::new())?;
+	}
+	Ok(())
+}
+
+/*
+    the decode buffer copied from the code, the identifier octets
+    are replaced and the rest is the same
+*/
+struct Asn1FieldMap {
+	start :usize,
+	end :usize,
+	off :usize,
+	copytaglen :usize,
+	codetaglen :usize,
+}
+
+struct Asn1FieldRecord {
+	start :usize,
+	end :usize,
+	names :Vec<String>,
+	fields :HashMap<usize,(usize,String)>,
+	maps :Vec<Asn1FieldMap>,
+}
+
+impl Asn1FieldRecord {
+	/*the offset in the dumped code for pos, the inner copy first*/
+	fn offset(&self, pos :usize) -> Option<usize> {
+		for m in self.maps.iter().rev() {
+			if pos >= m.start && pos < m.end {
+				let rel :usize = pos - m.start;
+				if rel < m.copytaglen {
+					return Some(m.off);
+				}
+				return Some(m.off + m.codetaglen + rel - m.copytaglen);
+			}
+		}
+		if pos >= self.start && pos < self.end {
+			return Some(pos - self.start);
+		}
+		return None;
+	}
+}
+
+thread_local! {
+	static ASN1_FIELD_RECORD : RefCell<Option<Asn1FieldRecord>> = RefCell::new(None);
+}
+
+/*
+    record the field name at the position of code when dump with type,
+    pop the name when drop
+*/
+pub(crate) struct Asn1FieldRecordGuard {
+	pushed :bool,
+}
+
+impl Asn1FieldRecordGuard {
+	pub(crate) fn enter(code :&[u8], fname :&str) -> Self {
+		let pos :usize = code.as_ptr() as usize;
+		let pushed :bool = ASN1_FIELD_RECORD.with(|c| {
+			let mut b = c.borrow_mut();
+			if b.is_none() {
+				return false;
+			}
+			let rec = b.as_mut().unwrap();
+			rec.names.push(format!("{}",fname));
+			let ooff = rec.offset(pos);
+			if ooff.is_some() {
+				let mut path :String = "".to_string();
+				for n in rec.names.iter() {
+					if !n.starts_with("[") && path.len() > 0 {
+						path.push_str(".");
+					}
+					path.push_str(n);
+				}
+				let off :usize = ooff.unwrap();
+				let olddepth :usize = match rec.fields.get(&off) {
+					Some(v) => v.0,
+					None => 0,
+				};
+				/*the inner field is kept for the same offset*/
+				if rec.names.len() >= olddepth {
+					rec.fields.insert(off,(rec.names.len(),path));
+				}
+			}
+			return true;
+		});
+		Asn1FieldRecordGuard {
+			pushed : pushed,
+		}
+	}
+
+	/*
+	    for the element idx in Asn1Seq Asn1Set Asn1ImpSet
+	*/
+	pub(crate) fn enter_index(code :&[u8], idx :usize) -> Self {
+		let recording :bool = ASN1_FIELD_RECORD.with(|c| {
+			c.borrow().is_some()
+		});
+		if !recording {
+			return Asn1FieldRecordGuard {
+				pushed : false,
+			};
+		}
+		return Self::enter(code,&format!("[{}]",idx));
+	}
+}
+
+/*
+    map the decode buffer copied from code when Asn1Imp Asn1Exp decode,
+    so the fields inside the tagged value are recorded at the offset of code
+*/
+pub(crate) struct Asn1FieldMapGuard {
+	pushed :bool,
+}
+
+impl Asn1FieldMapGuard {
+	pub(crate) fn enter(copy :&[u8], copytaglen :usize, code :&[u8], codetaglen :usize) -> Self {
+		let pushed :bool = ASN1_FIELD_RECORD.with(|c| {
+			let mut b = c.borrow_mut();
+			if b.is_none() {
+				return false;
+			}
+			let rec = b.as_mut().unwrap();
+			let ooff = rec.offset(code.as_ptr() as usize);
+			if ooff.is_none() {
+				return false;
+			}
+			let start :usize = copy.as_ptr() as usize;
+			rec.maps.push(Asn1FieldMap {
+				start : start,
+				end : start + copy.len(),
+				off : ooff.unwrap(),
+				copytaglen : copytaglen,
+				codetaglen : codetaglen,
+			});
+			return true;
+		});
+		Asn1FieldMapGuard {
+			pushed : pushed,
+		}
+	}
+}
+
+impl Drop for Asn1FieldMapGuard {
+	fn drop(&mut self) {
+		if self.pushed {
+			ASN1_FIELD_RECORD.with(|c| {
+				let mut b = c.borrow_mut();
+				if b.is_some() {
+					b.as_mut().unwrap().maps.pop();
+				}
+			});
+		}
+	}
+}
+
+impl Drop for Asn1FieldRecordGuard {
+	fn drop(&mut self) {
+		if self.pushed {
+			ASN1_FIELD_RECORD.with(|c| {
+				let mut b = c.borrow_mut();
+				if b.is_some() {
+					b.as_mut().unwrap().names.pop();
+				}
+			});
+		}
+	}
+}
+
+/*
+    decode code into v and dump the elements as asn1obj_dump,
+    the line is annotated with the field name of v decoded at the offset
+*/
+pub fn asn1obj_dump_typed<T :Asn1Op, W :Write>(v :&mut T, code :&[u8], iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+	let (retv,fields) = asn1obj_decode_fields(v,code)?;
+	let nodes = Asn1Node::parse_all(&code[0..retv])?;
+	for n in nodes.iter() {
+		asn1obj_dump_node(n,0,iowriter,&fields)?;
+	}
+	Ok(retv)
+}
+
+/*
+    decode code into v and return (decoded length, field path at the offset)
+*/
+fn asn1obj_decode_fields<T :Asn1Op>(v :&mut T, code :&[u8]) -> Result<(usize,HashMap<usize,String>),Box<dyn Error>> {
+	let start :usize = code.as_ptr() as usize;
+	let oldrec = ASN1_FIELD_RECORD.with(|c| {
+		c.replace(Some(Asn1FieldRecord {
+			start : start,
+			end : start + code.len(),
+			names : Vec::new(),
+			fields : HashMap::new(),
+			maps : Vec::new(),
+		}))
+	});
+	let ores = v.decode_asn1(code);
+	let rec = ASN1_FIELD_RECORD.with(|c| {
+		c.replace(oldrec)
+	}).unwrap();
+	let retv = ores?;
+	let mut fields :HashMap<usize,String> = HashMap::new();
+	for (k,v) in rec.fields.iter() {
+		fields.insert(*k,format!("{}",v.1));
+	}
+	Ok((retv,fields))
+}
+
+fn asn1obj_hexdump_range<W :Write>(iowriter :&mut W, code :&[u8], start :usize, end :usize, path :&str, what :&str) -> Result<(),Box<dyn Error>> {
+	let mut pos :usize = start;
+	let mut label :String = format!("({})",what);
+	if path.len() > 0 {
+		label = format!("{} ({})",path,what);
+	}
+	while pos < end {
+		let mut lend :usize = pos + 16;
+		if lend > end {
+			lend = end;
+		}
+		let mut hexs :String = "".to_string();
+		let mut ascs :String = "".to_string();
+		for i in pos..lend {
+			hexs.push_str(&format!(" {:02x}",code[i]));
+			if code[i] >= 0x20 && code[i] <= 0x7e {
+				ascs.push(code[i] as char);
+			} else {
+				ascs.push_str(".");
+			}
+		}
+		let s = format!("0x{:08x}:{:<48}    {:<16}    {}\n",pos,hexs,ascs,label);
+		iowriter.write_all(s.as_bytes())?;
+		pos = lend;
+	}
+	Ok(())
+}
+
+fn asn1obj_hexdump_node<W :Write>(node :&Asn1Node, code :&[u8], iowriter :&mut W, fields :&HashMap<usize,String>, parent :&str) -> Result<(),Box<dyn Error>> {
+	let mut path :String = format!("{}",parent);
+	let ores = fields.get(&node.offset);
+	if ores.is_some() {
+		path = format!("{}",ores.unwrap());
+	}
+	asn1obj_hexdump_range(iowriter,code,node.offset,node.offset + node.hdrlen,&path,&format!("header {}",asn1obj_tag_name(node.tag)))?;
+	if node.children.len() == 0 {
+		asn1obj_hexdump_range(iowriter,code,node.content.start,node.content.end,&path,"content")?;
+	} else {
+		if node.encapsulated && node.children[0].offset > node.content.start {
+			asn1obj_hexdump_range(iowriter,code,node.content.start,node.children[0].offset,&path,"unused bits")?;
+		}
+		for c in node.children.iter() {
+			asn1obj_hexdump_node(c,code,iowriter,fields,&path)?;
+		}
+	}
+	if node.eoclen > 0 {
+		asn1obj_hexdump_range(iowriter,code,node.content.end,node.content.end + node.eoclen,&path,"eoc")?;
+	}
+	Ok(())
+}
+
+/*
+    hex dump of code which v is decoded from, every header and content
+    range is labeled with the field path of v
+    like
+    0x00000000: 30 10                                               0.                  elem (header SEQUENCE)
+*/
+pub fn asn1obj_hexdump_typed<T :Asn1Op, W :Write>(v :&T, code :&[u8], iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+	let mut tmpv :T = T::init_asn1();
+	let (retv,fields) = asn1obj_decode_fields(&mut tmpv,code)?;
+	if !tmpv.equal_asn1(v) {
+		asn1obj_new_kind_error!{Asn1DumpError,InvalidValue,0,"value not decoded from the code"}
+	}
+	let nodes = Asn1Node::parse_all(&code[0..retv])?;
+	for n in nodes.iter() {
+		asn1obj_hexdump_node(n,code,iowriter,&fields,"")?;
+	}
+	Ok(retv)
+}
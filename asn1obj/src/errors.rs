@@ -32,4 +32,173 @@ macro_rules! asn1obj_new_error {
 		return Err(Box::new(<$type>::create(c.as_str())));
 	  }
 	};
-}
\ No newline at end of file
+}
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Asn1ErrorKind {
+	UnexpectedTag,
+	Truncated,
+	InvalidLength,
+	InvalidValue,
+	ConstraintViolation,
+	DepthExceeded,
+	TooManyElements,
+	ContentTooLarge,
+}
+
+/*
+    offset is the byte offset from the start of the input given to decode,
+    it is 0 for the encode and json errors
+    tname and fields are filled by the generated decode_asn1 from inner to outer,
+    so the outermost type name is kept
+*/
+#[derive(Debug,Clone)]
+pub struct Asn1Error {
+	kind :Asn1ErrorKind,
+	offset :usize,
+	msg :String,
+	tname :String,
+	fields :Vec<String>,
+}
+
+impl Asn1Error {
+	pub fn new(kind :Asn1ErrorKind, offset :usize, msg :&str) -> Asn1Error {
+		Asn1Error {
+			kind : kind,
+			offset : offset,
+			msg : format!("{}",msg),
+			tname : "".to_string(),
+			fields : Vec::new(),
+		}
+	}
+
+	pub fn kind(&self) -> Asn1ErrorKind {
+		return self.kind;
+	}
+
+	pub fn offset(&self) -> usize {
+		return self.offset;
+	}
+
+	pub fn msg(&self) -> &str {
+		return &self.msg;
+	}
+
+	/*
+	    like Asn1Pkcs12SafeBagElem.selectelem.bag.elem[3].digest
+	    empty when no generated decode_asn1 is on the way
+	*/
+	pub fn path(&self) -> String {
+		let mut rets :String = format!("{}",self.tname);
+		for f in self.fields.iter() {
+			if !f.starts_with("[") && rets.len() > 0 {
+				rets.push_str(".");
+			}
+			rets.push_str(f);
+		}
+		return rets;
+	}
+}
+
+impl std::fmt::Display for Asn1Error {
+	fn fmt(&self,f :&mut std::fmt::Formatter) -> std::fmt::Result {
+		let path = self.path();
+		if path.len() > 0 {
+			return write!(f,"{}[{:?} at 0x{:x} in {}]",self.msg,self.kind,self.offset,path);
+		}
+		write!(f,"{}[{:?} at 0x{:x}]",self.msg,self.kind,self.offset)
+	}
+}
+
+impl std::error::Error for Asn1Error {}
+
+/*
+    add off to the offset of error from the child decode at off,
+    other errors are changed to InvalidValue at off
+*/
+pub fn asn1obj_error_shift(e :Box<dyn std::error::Error>, off :usize) -> Box<dyn std::error::Error> {
+	let ores = e.downcast::<Asn1Error>();
+	if ores.is_ok() {
+		let mut ae = ores.unwrap();
+		ae.offset += off;
+		return ae;
+	}
+	let e = ores.err().unwrap();
+	return Box::new(Asn1Error::new(Asn1ErrorKind::InvalidValue,off,&format!("{}",e)));
+}
+
+/*
+    the error from the decode of a copy (such as the flattened string)
+    has no offset in code, so report it at off
+*/
+pub fn asn1obj_error_at(e :Box<dyn std::error::Error>, off :usize) -> Box<dyn std::error::Error> {
+	let mut ae = asn1obj_error_asn1(e);
+	ae.offset = off;
+	return ae;
+}
+
+/*
+    the error from the decode of a copy whose first copylen octets stand for
+    the first codelen octets of code (the replaced identifier), put the offset back in code
+*/
+pub fn asn1obj_error_remap(e :Box<dyn std::error::Error>, copylen :usize, codelen :usize) -> Box<dyn std::error::Error> {
+	let mut ae = asn1obj_error_asn1(e);
+	if ae.offset < copylen {
+		ae.offset = 0;
+	} else {
+		ae.offset = ae.offset - copylen + codelen;
+	}
+	return ae;
+}
+
+/*
+    the error from the decode limits of the context, they are not
+    the absent value for Asn1Opt and must be given to the caller
+*/
+pub fn asn1obj_error_is_limit(e :&Box<dyn std::error::Error>) -> bool {
+	let ae = e.downcast_ref::<Asn1Error>();
+	if ae.is_none() {
+		return false;
+	}
+	let kind = ae.unwrap().kind();
+	return kind == Asn1ErrorKind::DepthExceeded || kind == Asn1ErrorKind::TooManyElements || kind == Asn1ErrorKind::ContentTooLarge;
+}
+
+fn asn1obj_error_asn1(e :Box<dyn std::error::Error>) -> Box<Asn1Error> {
+	let ores = e.downcast::<Asn1Error>();
+	if ores.is_ok() {
+		return ores.unwrap();
+	}
+	let e = ores.err().unwrap();
+	return Box::new(Asn1Error::new(Asn1ErrorKind::InvalidValue,0,&format!("{}",e)));
+}
+
+/*
+    put the field fname of type tname before the path of the error
+*/
+pub fn asn1obj_error_field(e :Box<dyn std::error::Error>, tname :&str, fname :&str) -> Box<dyn std::error::Error> {
+	let mut ae = asn1obj_error_asn1(e);
+	ae.fields.insert(0,format!("{}",fname));
+	ae.tname = format!("{}",tname);
+	return ae;
+}
+
+/*
+    put the element index idx before the path of the error
+*/
+pub fn asn1obj_error_index(e :Box<dyn std::error::Error>, idx :usize) -> Box<dyn std::error::Error> {
+	let mut ae = asn1obj_error_asn1(e);
+	ae.fields.insert(0,format!("[{}]",idx));
+	return ae;
+}
+
+#[macro_export]
+macro_rules! asn1obj_new_kind_error {
+	($type:ty,$kind:ident,$off:expr,$($a:expr),*) => {
+		{
+		let mut c :String= format!("[{}:{}][{}]",file!(),line!(),stringify!($type));
+		c.push_str(&(format!($($a),*)[..]));
+		return Err(Box::new($crate::errors::Asn1Error::new($crate::errors::Asn1ErrorKind::$kind,$off,c.as_str())));
+	  }
+	};
+}
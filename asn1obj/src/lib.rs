@@ -1,8 +1,11 @@
 //!  package to use for ASN.1 pack and unpack rust library
 //!  we define basic struct Asn1Any Asn1Integer Asn1Boolean
 //!  Asn1BitString Asn1BitData Asn1OctString Asn1OctData Asn1Null
-//!  Asn1Object Asn1Enumerated Asn1String Asn1PrintableString
-//!  Asn1IA5String Asn1Time Asn1BigNum 
+//!  Asn1Object Asn1StaticOid Asn1RelativeOid Asn1OidIri Asn1RelativeOidIri
+//!  Asn1Enumerated Asn1String Asn1PrintableString
+//!  Asn1IA5String Asn1NumericString Asn1VisibleString Asn1TeletexString
+//!  Asn1VideotexString Asn1GraphicString Asn1GeneralString Asn1UniversalString
+//!  Asn1Time Asn1BigNum Asn1BigInteger Asn1Real
 //!  and 
 //!  complex struct Asn1Opt Asn1ImpSet Asn1Seq Asn1Set Asn1Imp Asn1Ndef 
 //!  Asn1SeqSelector Asn1BitSeq 
@@ -15,6 +18,7 @@
 //!  use asn1obj::base::*;
 //!  use asn1obj::complex::*;
 //!  use asn1obj::asn1impl::Asn1Op;
+//!  use asn1obj::dump::asn1obj_format_vecs;
 //!   use asn1obj::strop::asn1_format_line;
 //!  
 //!  use num_bigint::{BigUint};
@@ -36,57 +40,6 @@
 //!  	pub elem :Asn1Seq<Asn1RsaPubkeyElem>,
 //!  }
 //!  
-//!  fn format_vecs(buf :&[u8], tab :i32) -> String {
-//!  	let mut outs :String = "".to_string();
-//!  	let mut lasti : usize = 0;
-//!  	let mut ki :usize;
-//!  	for i in 0..buf.len() {
-//!  		if (i%16) == 0 {
-//!  			if i > 0 {
-//!  				outs.push_str("    ");
-//!  				while lasti != i {
-//!  					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-//!  						outs.push(buf[lasti] as char);
-//!  					} else {
-//!  						outs.push_str(".");
-//!  					}
-//!  					lasti += 1;
-//!  				}
-//!  				outs.push_str("\n");
-//!  			}
-//!  
-//!  			for _j in 0..tab {
-//!  				outs.push_str("    ");
-//!  			}
-//!  		}
-//!  		if (i % 16) == 0 {
-//!  			outs.push_str(&format!("{:02x}", buf[i]));	
-//!  		} else {
-//!  			outs.push_str(&format!(":{:02x}", buf[i]));	
-//!  		}
-//!  		
-//!  	}
-//!  
-//!  	if lasti != buf.len() {
-//!  		ki = buf.len();
-//!  		while (ki % 16) != 0 {
-//!  			outs.push_str("   ");
-//!  			ki += 1;
-//!  		}
-//!  		outs.push_str("    ");
-//!  		while lasti != buf.len() {
-//!  			if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-//!  				outs.push(buf[lasti] as char);
-//!  			} else {
-//!  				outs.push_str(".");
-//!  			}
-//!  			lasti += 1;
-//!  		}
-//!  	}
-//!  	outs.push_str("\n");
-//!  	return outs;
-//!  }
-//!  
 //!  
 //!  fn main() -> Result<(),Box<dyn Error>> {
 //!  	let mut pubkey :Asn1RsaPubkey = Asn1RsaPubkey::init_asn1();
@@ -97,7 +50,7 @@
 //!  	pubkeyelem.e.val = BigUint::from_bytes_be(&ne);
 //!  	pubkey.elem.val.push(pubkeyelem);
 //!  	let outd = pubkey.encode_asn1()?;
-//!  	let s = format_vecs(&outd,1);
+//!  	let s = asn1obj_format_vecs(&outd,1);
 //!  	let mut outs :String = "".to_string();
 //!  	let mut outf = std::io::stdout();
 //!  	outs.push_str("outs\n");
@@ -148,13 +101,22 @@
 //!  */
 //!  ```
 
+/*the generated code names ::asn1obj, also for the tests in this crate*/
+extern crate self as asn1obj;
+
 mod logger;
-mod errors;
+pub mod errors;
 pub mod consts;
+pub mod ctx;
 pub mod asn1impl;
 pub mod strop;
 pub mod base;
 pub mod complex;
+pub mod refs;
+pub mod stream;
+pub mod node;
+pub mod dump;
+pub mod oids;
 
 #[cfg(test)]
 mod tests;
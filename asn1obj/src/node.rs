@@ -0,0 +1,184 @@
+
+use std::error::Error;
+use std::ops::Range;
+
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
+use crate::{asn1obj_log_trace};
+use crate::logger::{asn1obj_debug_out,asn1obj_log_get_timestamp};
+
+use crate::base::{asn1obj_extract_header_inf,asn1obj_tag_number,asn1obj_tag_flag};
+use crate::ctx::{Asn1DecodeCtxGuard,Asn1DecodeDepthGuard,asn1obj_get_decode_ctx,asn1obj_check_elements};
+use crate::errors::{asn1obj_error_shift,asn1obj_error_index};
+
+use crate::consts::*;
+
+asn1obj_error_class!{Asn1NodeError}
+
+/*
+    schema-less tlv tree of BER/DER
+    offset and content are the absolute position in the parsed input
+    header and data keep the original octets, so encode gives the same input back
+    encapsulated is set when the children are parsed from the OCTET STRING or BIT STRING content
+*/
+#[derive(Clone,Debug)]
+pub struct Asn1Node {
+	pub tag :u64,
+	pub class :u8,
+	pub constructed :bool,
+	pub number :u64,
+	pub offset :usize,
+	pub hdrlen :usize,
+	pub content :Range<usize>,
+	pub eoclen :usize,
+	pub encapsulated :bool,
+	pub header :Vec<u8>,
+	pub data :Vec<u8>,
+	pub children :Vec<Asn1Node>,
+}
+
+impl Asn1Node {
+	/*
+	    parse one element from the start of code
+	    return (node,consumed length)
+	*/
+	pub fn parse(code :&[u8]) -> Result<(Asn1Node,usize),Box<dyn Error>> {
+		let retv = Self::parse_at(code,0,code.len())?;
+		let clen = retv.total_len();
+		Ok((retv,clen))
+	}
+
+	/*
+	    parse all the elements in code one by one
+	*/
+	pub fn parse_all(code :&[u8]) -> Result<Vec<Asn1Node>,Box<dyn Error>> {
+		let mut retv :Vec<Asn1Node> = Vec::new();
+		let mut pos :usize = 0;
+		while pos < code.len() {
+			asn1obj_check_elements(retv.len(),pos)?;
+			let ores = Self::parse_at(code,pos,code.len());
+			if ores.is_err() {
+				return Err(asn1obj_error_index(ores.err().unwrap(),retv.len()));
+			}
+			let n = ores.unwrap();
+			pos += n.total_len();
+			retv.push(n);
+		}
+		Ok(retv)
+	}
+
+	/*
+	    the length of the whole element with header and end-of-contents octets
+	*/
+	pub fn total_len(&self) -> usize {
+		return self.hdrlen + self.content.len() + self.eoclen;
+	}
+
+	pub fn encode(&self) -> Vec<u8> {
+		let mut retv :Vec<u8> = Vec::new();
+		self.encode_into(&mut retv);
+		return retv;
+	}
+
+	fn encode_into(&self, retv :&mut Vec<u8>) {
+		retv.extend_from_slice(&self.header);
+		if !self.constructed {
+			retv.extend_from_slice(&self.data);
+			return;
+		}
+		for c in self.children.iter() {
+			c.encode_into(retv);
+		}
+		if self.eoclen > 0 {
+			retv.push(0x0);
+			retv.push(0x0);
+		}
+	}
+
+	/*
+	    parse the element at code[offset..end], the error offset is from the start of code
+	*/
+	fn parse_at(code :&[u8], offset :usize, end :usize) -> Result<Asn1Node,Box<dyn Error>> {
+		let code :&[u8] = &code[0..end];
+		let gres = Asn1DecodeDepthGuard::enter();
+		if gres.is_err() {
+			return Err(asn1obj_error_shift(gres.err().unwrap(),offset));
+		}
+		let _guard = gres.unwrap();
+		let ores = asn1obj_extract_header_inf(&code[offset..]);
+		if ores.is_err() {
+			return Err(asn1obj_error_shift(ores.err().unwrap(),offset));
+		}
+		let (flag,hdrlen,totallen,eoclen) = ores.unwrap();
+		if (code.len() - offset) < (hdrlen + totallen + eoclen) {
+			asn1obj_new_kind_error!{Asn1NodeError,Truncated,offset,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}] + eoclen [{}])", code.len() - offset,hdrlen,totallen,eoclen}
+		}
+		let cstart :usize = offset + hdrlen;
+		let cend :usize = cstart + totallen;
+		let mut retv :Asn1Node = Asn1Node {
+			tag : flag,
+			class : asn1obj_tag_flag(flag) & ASN1_CLASS_MASK,
+			constructed : ((flag as u8) & ASN1_CONSTRUCTED) != 0,
+			number : asn1obj_tag_number(flag),
+			offset : offset,
+			hdrlen : hdrlen,
+			content : cstart..cend,
+			eoclen : eoclen,
+			encapsulated : false,
+			header : code[offset..cstart].to_vec(),
+			data : Vec::new(),
+			children : Vec::new(),
+		};
+		asn1obj_log_trace!("node offset [0x{:x}] flag [0x{:x}] hdrlen [{}] totallen [0x{:x}]", offset,flag,hdrlen,totallen);
+
+		if retv.constructed {
+			let mut pos :usize = cstart;
+			while pos < cend {
+				asn1obj_check_elements(retv.children.len(),pos)?;
+				let ores = Self::parse_at(code,pos,cend);
+				if ores.is_err() {
+					return Err(asn1obj_error_index(ores.err().unwrap(),retv.children.len()));
+				}
+				let c = ores.unwrap();
+				pos += c.total_len();
+				retv.children.push(c);
+			}
+			return Ok(retv);
+		}
+
+		retv.data = code[cstart..cend].to_vec();
+		if flag == ASN1_OCT_STRING_FLAG as u64 {
+			retv.children = Self::parse_encapsulated(code,cstart,cend);
+		} else if flag == ASN1_BIT_STRING_FLAG as u64 && totallen > 1 && code[cstart] == 0 {
+			retv.children = Self::parse_encapsulated(code,cstart + 1,cend);
+		}
+		if retv.children.len() > 0 {
+			retv.encapsulated = true;
+		}
+		Ok(retv)
+	}
+
+	/*
+	    heuristic: the content is taken as encapsulated only when it is parsed
+	    in DER to the end exactly, otherwise no children
+	*/
+	fn parse_encapsulated(code :&[u8], start :usize, end :usize) -> Vec<Asn1Node> {
+		let mut retv :Vec<Asn1Node> = Vec::new();
+		let mut ctx = asn1obj_get_decode_ctx();
+		let mut pos :usize = start;
+		ctx.der = true;
+		let _guard = Asn1DecodeCtxGuard::new(&ctx);
+		while pos < end {
+			if asn1obj_check_elements(retv.len(),pos).is_err() {
+				return Vec::new();
+			}
+			let ores = Self::parse_at(code,pos,end);
+			if ores.is_err() {
+				return Vec::new();
+			}
+			let c = ores.unwrap();
+			pos += c.total_len();
+			retv.push(c);
+		}
+		return retv;
+	}
+}
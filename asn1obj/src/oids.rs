@@ -0,0 +1,250 @@
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use lazy_static::lazy_static;
+
+use crate::asn1impl::{Asn1Op};
+use crate::base::{Asn1Object};
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
+
+asn1obj_error_class!{Asn1OidError}
+
+/*
+    well-known object identifiers as (name, dotted value),
+    the names follow the openssl ones when there is
+*/
+const ASN1_OID_BUILTIN :&[(&str,&str)] = &[
+	/*PKCS#1*/
+	("rsaEncryption","1.2.840.113549.1.1.1"),
+	("md2WithRSAEncryption","1.2.840.113549.1.1.2"),
+	("md5WithRSAEncryption","1.2.840.113549.1.1.4"),
+	("sha1WithRSAEncryption","1.2.840.113549.1.1.5"),
+	("rsaesOaep","1.2.840.113549.1.1.7"),
+	("mgf1","1.2.840.113549.1.1.8"),
+	("pSpecified","1.2.840.113549.1.1.9"),
+	("rsassaPss","1.2.840.113549.1.1.10"),
+	("sha256WithRSAEncryption","1.2.840.113549.1.1.11"),
+	("sha384WithRSAEncryption","1.2.840.113549.1.1.12"),
+	("sha512WithRSAEncryption","1.2.840.113549.1.1.13"),
+	("sha224WithRSAEncryption","1.2.840.113549.1.1.14"),
+	("sha512-224WithRSAEncryption","1.2.840.113549.1.1.15"),
+	("sha512-256WithRSAEncryption","1.2.840.113549.1.1.16"),
+	/*PKCS#5*/
+	("pbeWithMD5AndDES-CBC","1.2.840.113549.1.5.3"),
+	("pbeWithSHA1AndDES-CBC","1.2.840.113549.1.5.10"),
+	("pbkdf2","1.2.840.113549.1.5.12"),
+	("pbes2","1.2.840.113549.1.5.13"),
+	("pbmac1","1.2.840.113549.1.5.14"),
+	/*PKCS#7*/
+	("pkcs7-data","1.2.840.113549.1.7.1"),
+	("pkcs7-signedData","1.2.840.113549.1.7.2"),
+	("pkcs7-envelopedData","1.2.840.113549.1.7.3"),
+	("pkcs7-signedAndEnvelopedData","1.2.840.113549.1.7.4"),
+	("pkcs7-digestData","1.2.840.113549.1.7.5"),
+	("pkcs7-encryptedData","1.2.840.113549.1.7.6"),
+	/*PKCS#9*/
+	("emailAddress","1.2.840.113549.1.9.1"),
+	("unstructuredName","1.2.840.113549.1.9.2"),
+	("contentType","1.2.840.113549.1.9.3"),
+	("messageDigest","1.2.840.113549.1.9.4"),
+	("signingTime","1.2.840.113549.1.9.5"),
+	("countersignature","1.2.840.113549.1.9.6"),
+	("challengePassword","1.2.840.113549.1.9.7"),
+	("unstructuredAddress","1.2.840.113549.1.9.8"),
+	("extensionRequest","1.2.840.113549.1.9.14"),
+	("SMIMECapabilities","1.2.840.113549.1.9.15"),
+	("id-smime-ct-TSTInfo","1.2.840.113549.1.9.16.1.4"),
+	("id-smime-aa-signingCertificateV2","1.2.840.113549.1.9.16.2.47"),
+	("friendlyName","1.2.840.113549.1.9.20"),
+	("localKeyID","1.2.840.113549.1.9.21"),
+	("x509Certificate","1.2.840.113549.1.9.22.1"),
+	("sdsiCertificate","1.2.840.113549.1.9.22.2"),
+	("x509Crl","1.2.840.113549.1.9.23.1"),
+	/*PKCS#12*/
+	("pbeWithSHA1And128BitRC4","1.2.840.113549.1.12.1.1"),
+	("pbeWithSHA1And40BitRC4","1.2.840.113549.1.12.1.2"),
+	("pbeWithSHA1And3-KeyTripleDES-CBC","1.2.840.113549.1.12.1.3"),
+	("pbeWithSHA1And2-KeyTripleDES-CBC","1.2.840.113549.1.12.1.4"),
+	("pbeWithSHA1And128BitRC2-CBC","1.2.840.113549.1.12.1.5"),
+	("pbeWithSHA1And40BitRC2-CBC","1.2.840.113549.1.12.1.6"),
+	("keyBag","1.2.840.113549.1.12.10.1.1"),
+	("pkcs8ShroudedKeyBag","1.2.840.113549.1.12.10.1.2"),
+	("certBag","1.2.840.113549.1.12.10.1.3"),
+	("crlBag","1.2.840.113549.1.12.10.1.4"),
+	("secretBag","1.2.840.113549.1.12.10.1.5"),
+	("safeContentsBag","1.2.840.113549.1.12.10.1.6"),
+	/*RSADSI digest and cipher*/
+	("md2","1.2.840.113549.2.2"),
+	("md5","1.2.840.113549.2.5"),
+	("hmacWithSHA1","1.2.840.113549.2.7"),
+	("hmacWithSHA224","1.2.840.113549.2.8"),
+	("hmacWithSHA256","1.2.840.113549.2.9"),
+	("hmacWithSHA384","1.2.840.113549.2.10"),
+	("hmacWithSHA512","1.2.840.113549.2.11"),
+	("rc2-cbc","1.2.840.113549.3.2"),
+	("des-ede3-cbc","1.2.840.113549.3.7"),
+	/*X9.57 and X9.62*/
+	("dsaEncryption","1.2.840.10040.4.1"),
+	("dsaWithSHA1","1.2.840.10040.4.3"),
+	("id-ecPublicKey","1.2.840.10045.2.1"),
+	("prime192v1","1.2.840.10045.3.1.1"),
+	("prime256v1","1.2.840.10045.3.1.7"),
+	("ecdsa-with-SHA1","1.2.840.10045.4.1"),
+	("ecdsa-with-SHA224","1.2.840.10045.4.3.1"),
+	("ecdsa-with-SHA256","1.2.840.10045.4.3.2"),
+	("ecdsa-with-SHA384","1.2.840.10045.4.3.3"),
+	("ecdsa-with-SHA512","1.2.840.10045.4.3.4"),
+	("secp384r1","1.3.132.0.34"),
+	("secp521r1","1.3.132.0.35"),
+	/*NIST hash and aes*/
+	("sha1","1.3.14.3.2.26"),
+	("aes-128-cbc","2.16.840.1.101.3.4.1.2"),
+	("aes-128-gcm","2.16.840.1.101.3.4.1.6"),
+	("aes-192-cbc","2.16.840.1.101.3.4.1.22"),
+	("aes-256-cbc","2.16.840.1.101.3.4.1.42"),
+	("aes-256-gcm","2.16.840.1.101.3.4.1.46"),
+	("sha256","2.16.840.1.101.3.4.2.1"),
+	("sha384","2.16.840.1.101.3.4.2.2"),
+	("sha512","2.16.840.1.101.3.4.2.3"),
+	("sha224","2.16.840.1.101.3.4.2.4"),
+	("sha512-224","2.16.840.1.101.3.4.2.5"),
+	("sha512-256","2.16.840.1.101.3.4.2.6"),
+	("sha3-224","2.16.840.1.101.3.4.2.7"),
+	("sha3-256","2.16.840.1.101.3.4.2.8"),
+	("sha3-384","2.16.840.1.101.3.4.2.9"),
+	("sha3-512","2.16.840.1.101.3.4.2.10"),
+	("shake128","2.16.840.1.101.3.4.2.11"),
+	("shake256","2.16.840.1.101.3.4.2.12"),
+	("X25519","1.3.101.110"),
+	("ED25519","1.3.101.112"),
+	("ED448","1.3.101.113"),
+	/*X.520 attribute types*/
+	("commonName","2.5.4.3"),
+	("surname","2.5.4.4"),
+	("serialNumber","2.5.4.5"),
+	("countryName","2.5.4.6"),
+	("localityName","2.5.4.7"),
+	("stateOrProvinceName","2.5.4.8"),
+	("streetAddress","2.5.4.9"),
+	("organizationName","2.5.4.10"),
+	("organizationalUnitName","2.5.4.11"),
+	("title","2.5.4.12"),
+	("givenName","2.5.4.42"),
+	/*PKIX certificate extensions*/
+	("subjectKeyIdentifier","2.5.29.14"),
+	("keyUsage","2.5.29.15"),
+	("privateKeyUsagePeriod","2.5.29.16"),
+	("subjectAltName","2.5.29.17"),
+	("issuerAltName","2.5.29.18"),
+	("basicConstraints","2.5.29.19"),
+	("cRLNumber","2.5.29.20"),
+	("cRLReason","2.5.29.21"),
+	("nameConstraints","2.5.29.30"),
+	("cRLDistributionPoints","2.5.29.31"),
+	("certificatePolicies","2.5.29.32"),
+	("anyPolicy","2.5.29.32.0"),
+	("policyMappings","2.5.29.33"),
+	("authorityKeyIdentifier","2.5.29.35"),
+	("policyConstraints","2.5.29.36"),
+	("extendedKeyUsage","2.5.29.37"),
+	("anyExtendedKeyUsage","2.5.29.37.0"),
+	/*PKIX id-pe id-qt id-kp id-ad*/
+	("authorityInfoAccess","1.3.6.1.5.5.7.1.1"),
+	("subjectInfoAccess","1.3.6.1.5.5.7.1.11"),
+	("id-qt-cps","1.3.6.1.5.5.7.2.1"),
+	("id-qt-unotice","1.3.6.1.5.5.7.2.2"),
+	("serverAuth","1.3.6.1.5.5.7.3.1"),
+	("clientAuth","1.3.6.1.5.5.7.3.2"),
+	("codeSigning","1.3.6.1.5.5.7.3.3"),
+	("emailProtection","1.3.6.1.5.5.7.3.4"),
+	("timeStamping","1.3.6.1.5.5.7.3.8"),
+	("OCSPSigning","1.3.6.1.5.5.7.3.9"),
+	("OCSP","1.3.6.1.5.5.7.48.1"),
+	("basicOCSPResponse","1.3.6.1.5.5.7.48.1.1"),
+	("Nonce","1.3.6.1.5.5.7.48.1.2"),
+	("caIssuers","1.3.6.1.5.5.7.48.2"),
+	/*Microsoft Authenticode*/
+	("SPC_INDIRECT_DATA","1.3.6.1.4.1.311.2.1.4"),
+	("SPC_STATEMENT_TYPE","1.3.6.1.4.1.311.2.1.11"),
+	("SPC_SP_OPUS_INFO","1.3.6.1.4.1.311.2.1.12"),
+	("SPC_PE_IMAGE_DATA","1.3.6.1.4.1.311.2.1.15"),
+	("msCodeInd","1.3.6.1.4.1.311.2.1.21"),
+	("msCodeCom","1.3.6.1.4.1.311.2.1.22"),
+	("SPC_SIPINFO","1.3.6.1.4.1.311.2.1.30"),
+	("SPC_PE_IMAGE_PAGE_HASHES_V1","1.3.6.1.4.1.311.2.3.1"),
+	("SPC_PE_IMAGE_PAGE_HASHES_V2","1.3.6.1.4.1.311.2.3.2"),
+	("SPC_NESTED_SIGNATURE","1.3.6.1.4.1.311.2.4.1"),
+	("SPC_RFC3161_OBJID","1.3.6.1.4.1.311.3.3.1"),
+];
+
+struct Asn1OidRegistry {
+	names :HashMap<String,String>,
+	oids :HashMap<String,String>,
+}
+
+impl Asn1OidRegistry {
+	fn new() -> Self {
+		let mut retv = Asn1OidRegistry {
+			names : HashMap::new(),
+			oids : HashMap::new(),
+		};
+		for (n,o) in ASN1_OID_BUILTIN.iter() {
+			retv.insert(n,o);
+		}
+		retv
+	}
+
+	/*the old mappings of name and oid are both removed*/
+	fn insert(&mut self, name :&str, oid :&str) {
+		let ores = self.names.remove(name);
+		if ores.is_some() {
+			self.oids.remove(&ores.unwrap());
+		}
+		let ores = self.oids.remove(oid);
+		if ores.is_some() {
+			self.names.remove(&ores.unwrap());
+		}
+		self.names.insert(name.to_string(),oid.to_string());
+		self.oids.insert(oid.to_string(),name.to_string());
+	}
+}
+
+lazy_static! {
+	static ref ASN1_OID_REGISTRY : RwLock<Asn1OidRegistry> = {
+		RwLock::new(Asn1OidRegistry::new())
+	};
+}
+
+/*
+    the registered name of the dotted oid
+*/
+pub fn asn1obj_oid_to_name(oid :&str) -> Option<String> {
+	let reg = ASN1_OID_REGISTRY.read().unwrap();
+	return reg.oids.get(oid).cloned();
+}
+
+/*
+    the dotted oid of the registered name, the name is case sensitive
+*/
+pub fn asn1obj_name_to_oid(name :&str) -> Option<String> {
+	let reg = ASN1_OID_REGISTRY.read().unwrap();
+	return reg.names.get(name).cloned();
+}
+
+/*
+    add or replace the mapping of name and oid for all threads,
+    one name maps to one oid, so the old mapping of either is removed
+*/
+pub fn asn1obj_register_oid(name :&str, oid :&str) -> Result<(),Box<dyn Error>> {
+	if name.len() == 0 || name.contains(|c :char| c.is_whitespace() || c == '(' || c == ')') {
+		asn1obj_new_kind_error!{Asn1OidError,InvalidValue,0,"[{}] not valid oid name",name}
+	}
+	if name.chars().all(|c| c.is_ascii_digit() || c == '.') {
+		asn1obj_new_kind_error!{Asn1OidError,InvalidValue,0,"[{}] name can not be dotted number",name}
+	}
+	let mut obj :Asn1Object = Asn1Object::init_asn1();
+	let _ = obj.set_value(oid)?;
+	let mut reg = ASN1_OID_REGISTRY.write().unwrap();
+	reg.insert(name,oid);
+	Ok(())
+}
@@ -0,0 +1,269 @@
+
+use crate::asn1impl::{Asn1Op,Asn1RefOp};
+use std::io::{Write};
+use std::error::Error;
+
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
+use crate::{asn1obj_log_trace};
+use crate::logger::{asn1obj_debug_out,asn1obj_log_get_timestamp};
+
+use crate::strop::{asn1_format_line};
+use crate::base::{asn1obj_extract_header,asn1obj_extract_header_inf,asn1obj_format_header,Asn1Any,Asn1OctData};
+use crate::ctx::{asn1obj_is_der,asn1obj_is_cer,asn1obj_check_elements};
+use crate::errors::{asn1obj_error_shift,asn1obj_error_index};
+use crate::dump::{Asn1FieldRecordGuard};
+use crate::base::{asn1obj_der_check_integer,asn1obj_cer_constructed,asn1obj_cer_string};
+
+use crate::consts::*;
+
+asn1obj_error_class!{Asn1RefError}
+
+/*
+    the borrowed types keep the slice of the input code without copy,
+    the constructed (segmented) string form can not be borrowed
+*/
+
+#[derive(Clone)]
+pub struct Asn1OctDataRef<'a> {
+	pub data :&'a [u8],
+}
+
+impl<'a> Asn1RefOp<'a> for Asn1OctDataRef<'a> {
+	fn decode_asn1(&mut self, code :&'a [u8]) -> Result<usize,Box<dyn Error>> {
+		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		if flag != ASN1_OCT_STRING_FLAG as u64 {
+			asn1obj_new_kind_error!{Asn1RefError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_OCT_STRING_FLAG [0x{:02x}]", flag,ASN1_OCT_STRING_FLAG}
+		}
+		if code.len() < (hdrlen + totallen) {
+			asn1obj_new_kind_error!{Asn1RefError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+		}
+		self.data = &code[hdrlen..(hdrlen+totallen)];
+		Ok(hdrlen + totallen)
+	}
+
+	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut retv :Vec<u8>;
+		retv = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64, self.data.len() as u64)?;
+		retv.extend_from_slice(self.data);
+		if asn1obj_is_cer() {
+			return asn1obj_cer_string(&retv);
+		}
+		Ok(retv)
+	}
+
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+		let mut v = Asn1OctData::init_asn1();
+		v.data = self.data.to_vec();
+		return v.print_asn1(name,tab,iowriter);
+	}
+
+	fn init_asn1() -> Self {
+		Asn1OctDataRef {
+			data : &[],
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct Asn1AnyRef<'a> {
+	pub tag :u64,
+	pub content :&'a [u8],
+}
+
+impl<'a> Asn1RefOp<'a> for Asn1AnyRef<'a> {
+	fn decode_asn1(&mut self, code :&'a [u8]) -> Result<usize,Box<dyn Error>> {
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
+		if code.len() < (hdrlen + totallen) {
+			asn1obj_new_kind_error!{Asn1RefError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+		}
+		self.tag = flag;
+		self.content = &code[hdrlen..(hdrlen+totallen)];
+		Ok(hdrlen + totallen + eoclen)
+	}
+
+	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut retv :Vec<u8>;
+		retv = asn1obj_format_header(self.tag, self.content.len() as u64)?;
+		retv.extend_from_slice(self.content);
+		if asn1obj_is_cer() {
+			return asn1obj_cer_constructed(&retv);
+		}
+		Ok(retv)
+	}
+
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+		let mut v = Asn1Any::init_asn1();
+		v.tag = self.tag;
+		v.content = self.content.to_vec();
+		return v.print_asn1(name,tab,iowriter);
+	}
+
+	fn init_asn1() -> Self {
+		Asn1AnyRef {
+			tag : 0,
+			content : &[],
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct Asn1IntegerRef<'a> {
+	pub data :&'a [u8],
+}
+
+impl<'a> Asn1IntegerRef<'a> {
+	pub fn is_negative(&self) -> bool {
+		if self.data.len() > 0 && (self.data[0] & 0x80) != 0 {
+			return true;
+		}
+		return false;
+	}
+
+	pub fn get_i64(&self) -> Result<i64,Box<dyn Error>> {
+		let mut retv :i64;
+		if self.data.len() > 8 {
+			asn1obj_new_kind_error!{Asn1RefError,InvalidValue,0,"len [{}] > 8", self.data.len()}
+		}
+		retv = 0;
+		if self.is_negative() {
+			retv = -1;
+		}
+		for i in 0..self.data.len() {
+			retv = (retv << 8) | (self.data[i] as i64);
+		}
+		Ok(retv)
+	}
+}
+
+impl<'a> Asn1RefOp<'a> for Asn1IntegerRef<'a> {
+	fn decode_asn1(&mut self, code :&'a [u8]) -> Result<usize,Box<dyn Error>> {
+		let (flag,hdrlen,totallen) = asn1obj_extract_header(code)?;
+		if flag != ASN1_INTEGER_FLAG as u64 {
+			asn1obj_new_kind_error!{Asn1RefError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_INTEGER_FLAG [0x{:02x}]", flag,ASN1_INTEGER_FLAG}
+		}
+		if code.len() < (hdrlen + totallen) {
+			asn1obj_new_kind_error!{Asn1RefError,Truncated,0,"code len[0x{:x}] < (hdrlen [0x{:x}] + totallen [0x{:x}])", code.len(),hdrlen,totallen}
+		}
+		if totallen < 1 {
+			asn1obj_new_kind_error!{Asn1RefError,InvalidLength,0,"need 1 length"}
+		}
+		if asn1obj_is_der() {
+			let ores = asn1obj_der_check_integer(&code[hdrlen..(hdrlen+totallen)]);
+			if ores.is_err() {
+				return Err(asn1obj_error_shift(ores.err().unwrap(),hdrlen));
+			}
+		}
+		self.data = &code[hdrlen..(hdrlen+totallen)];
+		Ok(hdrlen + totallen)
+	}
+
+	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut retv :Vec<u8>;
+		if self.data.len() == 0 {
+			asn1obj_new_kind_error!{Asn1RefError,InvalidValue,0,"data [0] not valid"}
+		}
+		retv = asn1obj_format_header(ASN1_INTEGER_FLAG as u64, self.data.len() as u64)?;
+		retv.extend_from_slice(self.data);
+		Ok(retv)
+	}
+
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+		let s :String;
+		let ores = self.get_i64();
+		if ores.is_ok() {
+			s = asn1_format_line(tab,&(format!("{}: ASN1_INTEGER {}", name, ores.unwrap())));
+		} else {
+			let mut c :String = "".to_string();
+			for i in 0..self.data.len() {
+				c.push_str(&format!("{:02x}",self.data[i]));
+			}
+			s = asn1_format_line(tab,&(format!("{}: ASN1_INTEGER 0x{}", name, c)));
+		}
+		iowriter.write(s.as_bytes())?;
+		Ok(())
+	}
+
+	fn init_asn1() -> Self {
+		Asn1IntegerRef {
+			data : &[],
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct Asn1SeqRef<'a, T : Asn1RefOp<'a>> {
+	pub val :Vec<T>,
+	_marker :std::marker::PhantomData<&'a [u8]>,
+}
+
+impl<'a, T : Asn1RefOp<'a>> Asn1RefOp<'a> for Asn1SeqRef<'a,T> {
+	fn decode_asn1(&mut self, code :&'a [u8]) -> Result<usize,Box<dyn Error>> {
+		let mut retv :usize = 0;
+		self.val = Vec::new();
+		let (flag,hdrlen,totallen,eoclen) = asn1obj_extract_header_inf(code)?;
+		asn1obj_log_trace!("flag [0x{:x}]", flag);
+		if flag != ASN1_SEQ_MASK as u64 {
+			asn1obj_new_kind_error!{Asn1RefError,UnexpectedTag,0,"flag [0x{:02x}] != ASN1_SEQ_MASK [0x{:02x}]", flag, ASN1_SEQ_MASK}
+		}
+
+		if code.len() < (hdrlen + totallen) {
+			asn1obj_new_kind_error!{Asn1RefError,Truncated,0,"code len [{}] < ( {} + {})", code.len(),hdrlen,totallen}
+		}
+
+		retv += hdrlen;
+		while retv < (totallen + hdrlen) {
+			let mut v :T = T::init_asn1();
+			asn1obj_check_elements(self.val.len(),retv)?;
+			let _rec = Asn1FieldRecordGuard::enter_index(&code[retv..],self.val.len());
+			let ores = v.decode_asn1_at(&code[0..(hdrlen+totallen)],retv);
+			if ores.is_err() {
+				return Err(asn1obj_error_index(ores.err().unwrap(),self.val.len()));
+			}
+			let c = ores.unwrap();
+			if c == 0 {
+				asn1obj_new_kind_error!{Asn1RefError,InvalidLength,retv,"element [{}] at [{}] decoded nothing", self.val.len(), retv}
+			}
+			retv += c;
+			self.val.push(v);
+		}
+		retv += eoclen;
+		Ok(retv)
+	}
+
+	fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut retv :Vec<u8>;
+		let mut encv :Vec<u8> = Vec::new();
+		for v in self.val.iter() {
+			let code = v.encode_asn1()?;
+			encv.extend_from_slice(&code);
+		}
+		retv = asn1obj_format_header(ASN1_SEQ_MASK as u64,encv.len() as u64)?;
+		retv.extend_from_slice(&encv);
+		if asn1obj_is_cer() {
+			return asn1obj_cer_constructed(&retv);
+		}
+		Ok(retv)
+	}
+
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+		if self.val.len() == 0 {
+			let s = asn1_format_line(tab,&(format!("{} SEQ 0",name)));
+			iowriter.write(s.as_bytes())?;
+		} else {
+			let mut idx :usize = 0;
+			while idx < self.val.len() {
+				let s = format!("{}[{}]",name,idx);
+				let _ = self.val[idx].print_asn1(&s,tab,iowriter)?;
+				idx += 1;
+			}
+		}
+		Ok(())
+	}
+
+	fn init_asn1() -> Self {
+		Asn1SeqRef {
+			val : Vec::new(),
+			_marker : std::marker::PhantomData,
+		}
+	}
+}
@@ -0,0 +1,418 @@
+
+use crate::asn1impl::{Asn1Op};
+use std::io::{Read,ErrorKind};
+use std::error::Error;
+
+use crate::{asn1obj_error_class,asn1obj_new_kind_error};
+use crate::{asn1obj_log_trace};
+use crate::logger::{asn1obj_debug_out,asn1obj_log_get_timestamp};
+
+use crate::base::{asn1obj_extract_header};
+use crate::errors::{asn1obj_error_shift};
+use crate::ctx::{asn1obj_get_decode_ctx,asn1obj_check_content};
+use crate::consts::*;
+
+asn1obj_error_class!{Asn1StreamError}
+
+const ASN1_STREAM_DEFAULT_CHUNK_SIZE :usize = 4096;
+/*header is at most tag 10 bytes + length 127 bytes*/
+const ASN1_STREAM_MAX_TAG_BYTES :usize = 10;
+
+#[derive(Clone,Debug)]
+pub struct Asn1StreamHeader {
+	pub tag :u64,
+	pub constructed :bool,
+	/*None for indefinite length*/
+	pub length :Option<u64>,
+	pub hdrlen :usize,
+	pub offset :u64,
+	pub depth :usize,
+}
+
+/*
+    every Header is matched by one End with the same tag,
+    primitive content is given in Content chunks between them
+*/
+#[derive(Clone,Debug)]
+pub enum Asn1StreamEvent {
+	Header(Asn1StreamHeader),
+	Content(Vec<u8>),
+	End(u64),
+}
+
+struct Asn1StreamFrame {
+	tag :u64,
+	constructed :bool,
+	end :Option<u64>,
+}
+
+/*
+    pull decoder over std::io::Read, headers are read byte by byte,
+    so wrap the reader in std::io::BufReader for the file or socket
+*/
+pub struct Asn1StreamDecoder<R :Read> {
+	reader :R,
+	pos :u64,
+	peeked :Vec<u8>,
+	frames :Vec<Asn1StreamFrame>,
+	chunksize :usize,
+}
+
+impl<R :Read> Asn1StreamDecoder<R> {
+	pub fn new(reader :R) -> Self {
+		Asn1StreamDecoder {
+			reader : reader,
+			pos : 0,
+			peeked : Vec::new(),
+			frames : Vec::new(),
+			chunksize : ASN1_STREAM_DEFAULT_CHUNK_SIZE,
+		}
+	}
+
+	pub fn set_chunk_size(&mut self, chunksize :usize) -> Result<(),Box<dyn Error>> {
+		if chunksize == 0 {
+			asn1obj_new_kind_error!{Asn1StreamError,InvalidValue,self.pos as usize,"chunk size can not be 0"}
+		}
+		self.chunksize = chunksize;
+		Ok(())
+	}
+
+	/*offset of bytes consumed from the reader*/
+	pub fn offset(&self) -> u64 {
+		return self.pos;
+	}
+
+	/*number of open elements*/
+	pub fn depth(&self) -> usize {
+		return self.frames.len();
+	}
+
+	/*bytes left for the content of the current primitive element*/
+	pub fn content_left(&self) -> u64 {
+		if self.frames.len() > 0 {
+			let fr = &self.frames[self.frames.len() - 1];
+			if !fr.constructed {
+				return fr.end.unwrap() - self.pos;
+			}
+		}
+		return 0;
+	}
+
+	pub fn into_inner(self) -> R {
+		return self.reader;
+	}
+
+	fn read_byte(&mut self) -> Result<Option<u8>,Box<dyn Error>> {
+		let mut b :[u8;1] = [0];
+		if self.peeked.len() > 0 {
+			b[0] = self.peeked.remove(0);
+			self.pos += 1;
+			return Ok(Some(b[0]));
+		}
+		loop {
+			match self.reader.read(&mut b) {
+				Ok(0) => {
+					return Ok(None);
+				},
+				Ok(_) => {
+					self.pos += 1;
+					return Ok(Some(b[0]));
+				},
+				Err(e) => {
+					if e.kind() != ErrorKind::Interrupted {
+						return Err(Box::new(e));
+					}
+				},
+			}
+		}
+	}
+
+	fn must_read_byte(&mut self) -> Result<u8,Box<dyn Error>> {
+		let ores = self.read_byte()?;
+		if ores.is_none() {
+			asn1obj_new_kind_error!{Asn1StreamError,Truncated,self.pos as usize,"unexpected end of stream at offset [0x{:x}]", self.pos}
+		}
+		Ok(ores.unwrap())
+	}
+
+	fn peek_bytes(&mut self, cnt :usize) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut b :[u8;1] = [0];
+		while self.peeked.len() < cnt {
+			match self.reader.read(&mut b) {
+				Ok(0) => {
+					break;
+				},
+				Ok(_) => {
+					self.peeked.push(b[0]);
+				},
+				Err(e) => {
+					if e.kind() != ErrorKind::Interrupted {
+						return Err(Box::new(e));
+					}
+				},
+			}
+		}
+		let mut retv :Vec<u8> = Vec::new();
+		let mut idx :usize = 0;
+		while idx < cnt && idx < self.peeked.len() {
+			retv.push(self.peeked[idx]);
+			idx += 1;
+		}
+		Ok(retv)
+	}
+
+	fn read_exact_buf(&mut self, buf :&mut [u8]) -> Result<(),Box<dyn Error>> {
+		let mut idx :usize = 0;
+		while idx < buf.len() && self.peeked.len() > 0 {
+			buf[idx] = self.peeked.remove(0);
+			idx += 1;
+		}
+		let ores = self.reader.read_exact(&mut buf[idx..]);
+		if ores.is_err() {
+			let e = ores.err().unwrap();
+			if e.kind() == ErrorKind::UnexpectedEof {
+				asn1obj_new_kind_error!{Asn1StreamError,Truncated,self.pos as usize,"unexpected end of stream in [0x{:x}] bytes at offset [0x{:x}]", buf.len(), self.pos}
+			}
+			return Err(Box::new(e));
+		}
+		self.pos += buf.len() as u64;
+		Ok(())
+	}
+
+	/*return empty when stream ends before the header*/
+	fn read_header_raw(&mut self) -> Result<Vec<u8>,Box<dyn Error>> {
+		let mut retv :Vec<u8> = Vec::new();
+		let ores = self.read_byte()?;
+		if ores.is_none() {
+			return Ok(retv);
+		}
+		let b = ores.unwrap();
+		retv.push(b);
+		if (b & ASN1_PRIMITIVE_TAG) == ASN1_PRIMITIVE_TAG {
+			loop {
+				let c = self.must_read_byte()?;
+				retv.push(c);
+				if (c & 0x80) == 0 {
+					break;
+				}
+				if retv.len() > ASN1_STREAM_MAX_TAG_BYTES {
+					asn1obj_new_kind_error!{Asn1StreamError,UnexpectedTag,(self.pos as usize) - retv.len(),"tag at offset [0x{:x}] too long", self.pos - (retv.len() as u64)}
+				}
+			}
+		}
+		let c = self.must_read_byte()?;
+		retv.push(c);
+		if (c & 0x80) != 0 && c != 0x80 {
+			let mut cnt :usize = (c & 0x7f) as usize;
+			while cnt > 0 {
+				let d = self.must_read_byte()?;
+				retv.push(d);
+				cnt -= 1;
+			}
+		}
+		Ok(retv)
+	}
+
+	fn check_parent_end(&self, end :u64) -> Result<(),Box<dyn Error>> {
+		if self.frames.len() > 0 {
+			let fr = &self.frames[self.frames.len() - 1];
+			if fr.end.is_some() && end > fr.end.unwrap() {
+				asn1obj_new_kind_error!{Asn1StreamError,InvalidLength,self.pos as usize,"element end [0x{:x}] exceeds parent end [0x{:x}]", end, fr.end.unwrap()}
+			}
+		}
+		Ok(())
+	}
+
+	/*
+	    return None when the stream is finished at the top level
+	*/
+	pub fn next_event(&mut self) -> Result<Option<Asn1StreamEvent>,Box<dyn Error>> {
+		if self.frames.len() > 0 {
+			let idx = self.frames.len() - 1;
+			let tag = self.frames[idx].tag;
+			if !self.frames[idx].constructed {
+				let left = self.frames[idx].end.unwrap() - self.pos;
+				if left > 0 {
+					let mut rdlen :usize = self.chunksize;
+					if left < (rdlen as u64) {
+						rdlen = left as usize;
+					}
+					let mut buf :Vec<u8> = vec![0; rdlen];
+					self.read_exact_buf(&mut buf)?;
+					return Ok(Some(Asn1StreamEvent::Content(buf)));
+				}
+				self.frames.pop();
+				return Ok(Some(Asn1StreamEvent::End(tag)));
+			}
+
+			if self.frames[idx].end.is_some() {
+				let end = self.frames[idx].end.unwrap();
+				if self.pos == end {
+					self.frames.pop();
+					return Ok(Some(Asn1StreamEvent::End(tag)));
+				}
+				if self.pos > end {
+					asn1obj_new_kind_error!{Asn1StreamError,InvalidLength,self.pos as usize,"offset [0x{:x}] > end [0x{:x}]", self.pos, end}
+				}
+			}
+		}
+
+		let offset = self.pos;
+		let hdr = self.read_header_raw()?;
+		if hdr.len() == 0 {
+			if self.frames.len() > 0 {
+				asn1obj_new_kind_error!{Asn1StreamError,Truncated,self.pos as usize,"unexpected end of stream in depth [{}]", self.frames.len()}
+			}
+			return Ok(None);
+		}
+		let (flag,hdrlen,totallen) = asn1obj_extract_header(&hdr)?;
+		asn1obj_log_trace!("offset [0x{:x}] flag [0x{:x}] hdrlen [{}] totallen [0x{:x}]", offset,flag,hdrlen,totallen);
+		if hdr[0] == 0 && totallen == 0 && hdrlen == 2 {
+			if self.frames.len() > 0 {
+				let idx = self.frames.len() - 1;
+				if self.frames[idx].end.is_none() {
+					let tag = self.frames[idx].tag;
+					self.frames.pop();
+					return Ok(Some(Asn1StreamEvent::End(tag)));
+				}
+			}
+			asn1obj_new_kind_error!{Asn1StreamError,UnexpectedTag,offset as usize,"unexpected end-of-contents at offset [0x{:x}]", offset}
+		}
+
+		let constructed :bool = (hdr[0] & ASN1_CONSTRUCTED) != 0;
+		let mut length :Option<u64> = Some(totallen as u64);
+		if totallen == 0 && hdr[hdrlen - 1] == 0x80 {
+			if !constructed {
+				asn1obj_new_kind_error!{Asn1StreamError,InvalidLength,offset as usize,"primitive [0x{:x}] with indefinite length at offset [0x{:x}]", flag, offset}
+			}
+			length = None;
+		}
+
+		let mut end :Option<u64> = None;
+		if length.is_some() {
+			end = Some(self.pos + length.unwrap());
+			self.check_parent_end(end.unwrap())?;
+		}
+
+		let maxdepth :usize = asn1obj_get_decode_ctx().max_depth;
+		if self.frames.len() >= maxdepth {
+			asn1obj_new_kind_error!{Asn1StreamError,DepthExceeded,offset as usize,"depth [{}] >= max_depth [{}]", self.frames.len(), maxdepth}
+		}
+
+		let hdrv = Asn1StreamHeader {
+			tag : flag,
+			constructed : constructed,
+			length : length,
+			hdrlen : hdrlen,
+			offset : offset,
+			depth : self.frames.len(),
+		};
+		self.frames.push(Asn1StreamFrame {
+			tag : flag,
+			constructed : constructed,
+			end : end,
+		});
+		Ok(Some(Asn1StreamEvent::Header(hdrv)))
+	}
+
+	/*
+	    read the content of current primitive element into buf,
+	    return 0 when all content is read, the End event follows
+	*/
+	pub fn read_content(&mut self, buf :&mut [u8]) -> Result<usize,Box<dyn Error>> {
+		let left = self.content_left();
+		let mut rdlen :usize = buf.len();
+		if left < (rdlen as u64) {
+			rdlen = left as usize;
+		}
+		if rdlen == 0 {
+			return Ok(0);
+		}
+		self.read_exact_buf(&mut buf[0..rdlen])?;
+		Ok(rdlen)
+	}
+
+	/*
+	    decode the next whole element into T, the element is kept in memory,
+	    return None when the current constructed element has no more element
+	*/
+	pub fn decode_next<T :Asn1Op>(&mut self) -> Result<Option<T>,Box<dyn Error>> {
+		let mut level :usize = 0;
+		let maxdepth :usize = asn1obj_get_decode_ctx().max_depth;
+		let mut raw :Vec<u8> = Vec::new();
+		if self.frames.len() > 0 {
+			let fr = &self.frames[self.frames.len() - 1];
+			if !fr.constructed {
+				asn1obj_new_kind_error!{Asn1StreamError,InvalidValue,self.pos as usize,"in primitive [0x{:x}] content", fr.tag}
+			}
+			if fr.end.is_some() && fr.end.unwrap() == self.pos {
+				return Ok(None);
+			}
+			if fr.end.is_none() {
+				let p = self.peek_bytes(2)?;
+				if p.len() == 2 && p[0] == 0 && p[1] == 0 {
+					return Ok(None);
+				}
+			}
+		} else {
+			let p = self.peek_bytes(1)?;
+			if p.len() == 0 {
+				return Ok(None);
+			}
+		}
+
+		loop {
+			if level > 0 {
+				let p = self.peek_bytes(2)?;
+				if p.len() == 2 && p[0] == 0 && p[1] == 0 {
+					let mut eoc :[u8;2] = [0,0];
+					self.read_exact_buf(&mut eoc)?;
+					raw.extend_from_slice(&eoc);
+					level -= 1;
+					if level == 0 {
+						break;
+					}
+					continue;
+				}
+			}
+			let hdr = self.read_header_raw()?;
+			if hdr.len() == 0 {
+				asn1obj_new_kind_error!{Asn1StreamError,Truncated,self.pos as usize,"unexpected end of stream at offset [0x{:x}]", self.pos}
+			}
+			let (_,hdrlen,totallen) = asn1obj_extract_header(&hdr)?;
+			raw.extend_from_slice(&hdr);
+			if totallen == 0 && hdr[hdrlen - 1] == 0x80 {
+				if level >= maxdepth {
+					asn1obj_new_kind_error!{Asn1StreamError,DepthExceeded,self.pos as usize,"depth [{}] >= max_depth [{}]", level, maxdepth}
+				}
+				level += 1;
+				continue;
+			}
+			if level == 0 {
+				self.check_parent_end(self.pos + (totallen as u64))?;
+			}
+			let ores = asn1obj_check_content(raw.len() + totallen);
+			if ores.is_err() {
+				return Err(asn1obj_error_shift(ores.err().unwrap(),self.pos as usize));
+			}
+			let start = raw.len();
+			raw.resize(start + totallen, 0);
+			self.read_exact_buf(&mut raw[start..])?;
+			if level == 0 {
+				break;
+			}
+		}
+		self.check_parent_end(self.pos)?;
+
+		let mut retv :T = T::init_asn1();
+		let ores = retv.decode_asn1(&raw);
+		if ores.is_err() {
+			return Err(asn1obj_error_shift(ores.err().unwrap(),(self.pos as usize) - raw.len()));
+		}
+		let c = ores.unwrap();
+		if c != raw.len() {
+			asn1obj_new_kind_error!{Asn1StreamError,InvalidLength,(self.pos as usize) - raw.len(),"decode [0x{:x}] != element len [0x{:x}]", c, raw.len()}
+		}
+		Ok(Some(retv))
+	}
+}
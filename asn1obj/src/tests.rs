@@ -6,13 +6,20 @@ use crate::complex::*;
 #[allow(unused_imports)]
 use crate::{asn1obj_log_trace,asn1obj_log_error,asn1obj_error_class,asn1obj_new_error,asn1obj_debug_buffer_trace,asn1obj_format_buffer_log};
 use crate::logger::{asn1obj_debug_out,asn1obj_log_get_timestamp};
-use crate::asn1impl::{Asn1Op,Asn1Selector};
+use crate::asn1impl::{Asn1Op,Asn1Selector,Asn1RefOp};
+use crate::refs::*;
+use crate::stream::*;
+use crate::node::*;
+use crate::dump::*;
+use crate::oids::*;
 use crate::consts::*;
+use crate::ctx::*;
+use crate::errors::*;
 use crate::strop::*;
 use chrono::{Utc,Local,DateTime,Datelike,Timelike};
 use chrono::prelude::*;
 
-use num_bigint::{BigUint};
+use num_bigint::{BigUint,BigInt};
 use num_traits::Num;
 use std::io::{Write};
 use std::error::Error;
@@ -2862,4 +2869,1874 @@ fn test_a055() {
 	let v1 = vec![0x1e,0x6,0x00,0x63,0x00,0x63,0x00,0x76];
 	a1.decode_asn1(&v1).unwrap();
 	assert!(a1.val == "ccv");
-}
\ No newline at end of file
+}
+#[test]
+fn test_a056() {
+	let mut a1 :Asn1Imp<Asn1Integer,1234> = Asn1Imp::init_asn1();
+	a1.val.val = 5;
+	let code = a1.encode_asn1().unwrap();
+	let v1 = vec![0x9f,0x89,0x52,0x01,0x05];
+	assert!(check_equal_u8(&code,&v1));
+	let mut a2 :Asn1Imp<Asn1Integer,1234> = Asn1Imp::init_asn1();
+	let c = a2.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(a2.val.val == 5);
+	let mut a3 :Asn1Imp<Asn1Integer,1235> = Asn1Imp::init_asn1();
+	assert!(a3.decode_asn1(&v1).is_err());
+
+	let mut b1 :Asn1Ndef<Asn1Integer,1234> = Asn1Ndef::init_asn1();
+	let mut iv :Asn1Integer = Asn1Integer::init_asn1();
+	iv.val = 5;
+	b1.val = Some(iv);
+	let code = b1.encode_asn1().unwrap();
+	let v1 = vec![0xbf,0x89,0x52,0x03,0x02,0x01,0x05];
+	assert!(check_equal_u8(&code,&v1));
+	let mut b2 :Asn1Ndef<Asn1Integer,1234> = Asn1Ndef::init_asn1();
+	let c = b2.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(b2.val.as_ref().unwrap().val == 5);
+
+	let mut d1 :Asn1ImpSet<Asn1Integer,31> = Asn1ImpSet::init_asn1();
+	let mut iv :Asn1Integer = Asn1Integer::init_asn1();
+	iv.val = 7;
+	d1.val.push(iv);
+	let code = d1.encode_asn1().unwrap();
+	let v1 = vec![0xbf,0x1f,0x03,0x02,0x01,0x07];
+	assert!(check_equal_u8(&code,&v1));
+	let mut d2 :Asn1ImpSet<Asn1Integer,31> = Asn1ImpSet::init_asn1();
+	let _ = d2.decode_asn1(&v1).unwrap();
+	assert!(d2.val.len() == 1 && d2.val[0].val == 7);
+
+	let v1 = vec![0x7f,0x81,0x00,0x01,0x00];
+	let (flag,hdrlen,totallen) = asn1obj_extract_header(&v1).unwrap();
+	assert!(asn1obj_tag_number(flag) == 128);
+	assert!(asn1obj_tag_flag(flag) == 0x60);
+	assert!(hdrlen == 4 && totallen == 1);
+	let mut e1 :Asn1Any = Asn1Any::init_asn1();
+	let _ = e1.decode_asn1(&v1).unwrap();
+	let code = e1.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+	assert!(asn1obj_make_tag(0x40,30) == 0x5e);
+}
+
+#[test]
+fn test_a057() {
+	let mut a1 :Asn1Imp<Asn1Seq<Asn1Integer>,1> = Asn1Imp::init_asn1();
+	let mut iv :Asn1Integer = Asn1Integer::init_asn1();
+	iv.val = 5;
+	a1.val.val.push(iv);
+	let v1 :Vec<u8> = vec![0xa1,0x03,0x02,0x01,0x05];
+	assert!(a1.encode_asn1().unwrap() == v1);
+	let mut w :Vec<u8> = Vec::new();
+	let _ = a1.write_asn1(&mut w).unwrap();
+	assert!(w == v1);
+	assert!(a1.encoded_len().unwrap() == v1.len());
+	let (n,_) = Asn1Node::parse(&v1).unwrap();
+	assert!(n.constructed);
+	assert!(n.children.len() == 1);
+	let mut a2 :Asn1Imp<Asn1Seq<Asn1Integer>,1> = Asn1Imp::init_asn1();
+	let _ = a2.decode_asn1(&v1).unwrap();
+	assert!(a2.val.val[0].val == 5);
+
+	/*the primitive inner value stays primitive*/
+	let mut a3 :Asn1Imp<Asn1Integer,2> = Asn1Imp::init_asn1();
+	a3.val.val = 5;
+	assert!(a3.encode_asn1().unwrap() == vec![0x82,0x01,0x05]);
+	let mut w :Vec<u8> = Vec::new();
+	let _ = a3.write_asn1(&mut w).unwrap();
+	assert!(w == vec![0x82,0x01,0x05]);
+}
+
+#[test]
+fn test_a058() {
+	let mut a1 :Asn1AppImp<Asn1Integer,3> = Asn1AppImp::init_asn1();
+	a1.val.val = 5;
+	let code = a1.encode_asn1().unwrap();
+	let v1 = vec![0x43,0x01,0x05];
+	assert!(check_equal_u8(&code,&v1));
+	let mut a2 :Asn1Imp<Asn1Integer,3,ASN1_CLASS_APPLICATION> = Asn1Imp::init_asn1();
+	let _ = a2.decode_asn1(&v1).unwrap();
+	assert!(a2.val.val == 5);
+	let mut a3 :Asn1Imp<Asn1Integer,3> = Asn1Imp::init_asn1();
+	assert!(a3.decode_asn1(&v1).is_err());
+	let mut a4 :Asn1PrivImp<Asn1Integer,3> = Asn1PrivImp::init_asn1();
+	assert!(a4.decode_asn1(&v1).is_err());
+
+	let mut b1 :Asn1PrivExp<Asn1Seq<Asn1Integer>,2> = Asn1PrivExp::init_asn1();
+	b1.val.val.push(a1.val.clone());
+	let code = b1.encode_asn1().unwrap();
+	let v1 = vec![0xe2,0x03,0x02,0x01,0x05];
+	assert!(check_equal_u8(&code,&v1));
+	let mut b2 :Asn1PrivExp<Asn1Seq<Asn1Integer>,2> = Asn1PrivExp::init_asn1();
+	let _ = b2.decode_asn1(&v1).unwrap();
+	assert!(b2.val.val.len() == 1 && b2.val.val[0].val == 5);
+	let mut b3 :Asn1Exp<Asn1Seq<Asn1Integer>,2> = Asn1Exp::init_asn1();
+	assert!(b3.decode_asn1(&v1).is_err());
+
+	let mut c1 :Asn1AppNdef<Asn1Integer,1234> = Asn1AppNdef::init_asn1();
+	c1.val = Some(a1.val.clone());
+	let code = c1.encode_asn1().unwrap();
+	let v1 = vec![0x7f,0x89,0x52,0x03,0x02,0x01,0x05];
+	assert!(check_equal_u8(&code,&v1));
+	let mut c2 :Asn1AppNdef<Asn1Integer,1234> = Asn1AppNdef::init_asn1();
+	let _ = c2.decode_asn1(&v1).unwrap();
+	assert!(c2.val.as_ref().unwrap().val == 5);
+	let mut c3 :Asn1Ndef<Asn1Integer,1234> = Asn1Ndef::init_asn1();
+	assert!(c3.decode_asn1(&v1).is_err());
+}
+
+#[test]
+fn test_a059() {
+	let code = asn1obj_format_header(ASN1_OCT_STRING_FLAG as u64,0x100000000).unwrap();
+	let v1 = vec![0x04,0x85,0x01,0x00,0x00,0x00,0x00];
+	assert!(check_equal_u8(&code,&v1));
+	let (flag,hdrlen,totallen) = asn1obj_extract_header(&v1).unwrap();
+	assert!(flag == ASN1_OCT_STRING_FLAG as u64);
+	assert!(hdrlen == 7);
+	assert!(totallen as u64 == 0x100000000);
+	let code = asn1obj_format_header(ASN1_SEQ_MASK as u64,0x0102030405060708).unwrap();
+	let v1 = vec![0x30,0x88,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08];
+	assert!(check_equal_u8(&code,&v1));
+	let (_,hdrlen,totallen) = asn1obj_extract_header(&v1).unwrap();
+	assert!(hdrlen == 10);
+	assert!(totallen as u64 == 0x0102030405060708);
+	let code = asn1obj_format_header(ASN1_SEQ_MASK as u64,0x80).unwrap();
+	assert!(check_equal_u8(&code,&[0x30,0x81,0x80]));
+	let v1 = vec![0x30,0x88,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0xff];
+	assert!(asn1obj_extract_header(&v1).is_err());
+	let v1 = vec![0x30,0x89,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00];
+	assert!(asn1obj_extract_header(&v1).is_err());
+	assert!(asn1obj_format_header(0x100,1).is_err());
+}
+
+#[test]
+fn test_a060() {
+	let v1 = vec![0x30,0x80,0x02,0x01,0x05,0x02,0x01,0x06,0x00,0x00];
+	let mut a1 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let c = a1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(a1.val.len() == 2 && a1.val[0].val == 5 && a1.val[1].val == 6);
+
+	let v1 = vec![0x30,0x80,0x30,0x80,0x02,0x01,0x05,0x00,0x00,0x30,0x03,0x02,0x01,0x07,0x00,0x00,0x05,0x00];
+	let mut a2 :Asn1Seq<Asn1Seq<Asn1Integer>> = Asn1Seq::init_asn1();
+	let c = a2.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len() - 2);
+	assert!(a2.val.len() == 2 && a2.val[0].val[0].val == 5 && a2.val[1].val[0].val == 7);
+	let code = a2.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&[0x30,0x0a,0x30,0x03,0x02,0x01,0x05,0x30,0x03,0x02,0x01,0x07]));
+
+	let v1 = vec![0x31,0x80,0x02,0x01,0x05,0x00,0x00];
+	let mut a3 :Asn1Set<Asn1Integer> = Asn1Set::init_asn1();
+	let c = a3.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+
+	let v1 = vec![0x30,0x80,0x02,0x01,0x05,0x02,0x01,0x06];
+	let mut a1 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	assert!(a1.decode_asn1(&v1).is_err());
+
+	let v1 = vec![0xa0,0x80,0x02,0x01,0x05,0x00,0x00];
+	let mut b1 :Asn1Ndef<Asn1Integer,0> = Asn1Ndef::init_asn1();
+	let c = b1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(b1.val.as_ref().unwrap().val == 5);
+	let mut b2 :Asn1Exp<Asn1Seq<Asn1Integer>,0> = Asn1Exp::init_asn1();
+	let c = b2.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(b2.val.val.len() == 1 && b2.val.val[0].val == 5);
+	let mut b3 :Asn1ImpSet<Asn1Integer,0> = Asn1ImpSet::init_asn1();
+	let c = b3.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(b3.val.len() == 1 && b3.val[0].val == 5);
+
+	let v1 = vec![0x30,0x80,0x06,0x02,0x2a,0x03,0x02,0x01,0x05,0x13,0x02,0x61,0x62,0x00,0x00];
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let c = c1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(c1.elem.val.len() == 1);
+	assert!(c1.elem.val[0].ccv.get_value() == "1.2.3");
+	assert!(c1.elem.val[0].ddv.val == "ab");
+}
+
+#[test]
+fn test_a061() {
+	let v1 = vec![0x24,0x80,0x04,0x02,0xaa,0xbb,0x24,0x80,0x04,0x01,0xcc,0x00,0x00,0x00,0x00];
+	let mut a1 :Asn1OctData = Asn1OctData::init_asn1();
+	let c = a1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(check_equal_u8(&a1.data,&[0xaa,0xbb,0xcc]));
+	let code = a1.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&[0x04,0x03,0xaa,0xbb,0xcc]));
+	let v1 = vec![0x24,0x07,0x04,0x02,0x61,0x62,0x04,0x01,0x63];
+	let mut a2 :Asn1OctString = Asn1OctString::init_asn1();
+	let c = a2.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(a2.val == "abc");
+
+	let v1 = vec![0x23,0x09,0x03,0x02,0x00,0xaa,0x03,0x03,0x06,0xbb,0xc0];
+	let mut b1 :Asn1BitData = Asn1BitData::init_asn1();
+	let c = b1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(check_equal_u8(&b1.data,&[0xaa,0xbb,0xc0]));
+	let v1 = vec![0x23,0x08,0x03,0x02,0x04,0xa0,0x03,0x02,0x00,0xbb];
+	let mut b2 :Asn1BitData = Asn1BitData::init_asn1();
+	assert!(b2.decode_asn1(&v1).is_err());
+
+	let v1 = vec![0xa0,0x80,0x04,0x01,0xaa,0x04,0x01,0xbb,0x00,0x00];
+	let mut c1 :Asn1Imp<Asn1OctData,0> = Asn1Imp::init_asn1();
+	let c = c1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(check_equal_u8(&c1.val.data,&[0xaa,0xbb]));
+}
+
+#[test]
+fn test_a062() {
+	let mut a1 :Asn1Integer = Asn1Integer::init_asn1();
+	let v1 = vec![0x02,0x02,0x00,0x05];
+	let _ = a1.decode_asn1(&v1).unwrap();
+	assert!(a1.val == 5);
+	assert!(a1.decode_asn1_der(&v1).is_err());
+	assert!(a1.decode_asn1_der(&[0x02,0x02,0xff,0x80]).is_err());
+	let _ = a1.decode_asn1_der(&[0x02,0x02,0x00,0x80]).unwrap();
+	assert!(a1.val == 128);
+	assert!(!asn1obj_is_der());
+
+	let mut a2 :Asn1Boolean = Asn1Boolean::init_asn1();
+	let _ = a2.decode_asn1(&[0x01,0x01,0x01]).unwrap();
+	assert!(a2.val);
+	assert!(a2.decode_asn1_der(&[0x01,0x01,0x01]).is_err());
+	let _ = a2.decode_asn1_der(&[0x01,0x01,0xff]).unwrap();
+	assert!(a2.val);
+
+	let mut a3 :Asn1OctData = Asn1OctData::init_asn1();
+	let _ = a3.decode_asn1(&[0x04,0x81,0x01,0xaa]).unwrap();
+	assert!(a3.decode_asn1_der(&[0x04,0x81,0x01,0xaa]).is_err());
+	assert!(a3.decode_asn1_der(&[0x04,0x82,0x00,0x81,0xaa]).is_err());
+	assert!(a3.decode_asn1_der(&[0x24,0x03,0x04,0x01,0xaa]).is_err());
+
+	let mut a4 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let v1 = vec![0x30,0x80,0x02,0x01,0x05,0x00,0x00];
+	let _ = a4.decode_asn1(&v1).unwrap();
+	assert!(a4.decode_asn1_der(&v1).is_err());
+
+	let mut a5 :Asn1Set<Asn1Integer> = Asn1Set::init_asn1();
+	let v1 = vec![0x31,0x06,0x02,0x01,0x06,0x02,0x01,0x05];
+	let _ = a5.decode_asn1(&v1).unwrap();
+	assert!(a5.decode_asn1_der(&v1).is_err());
+	let v1 = vec![0x31,0x06,0x02,0x01,0x05,0x02,0x01,0x06];
+	let _ = a5.decode_asn1_der(&v1).unwrap();
+	assert!(a5.val.len() == 2);
+
+	let mut a6 :Asn1BitDataFlag = Asn1BitDataFlag::init_asn1();
+	let v1 = vec![0x03,0x02,0x03,0xa1];
+	let _ = a6.decode_asn1(&v1).unwrap();
+	assert!(a6.decode_asn1_der(&v1).is_err());
+	assert!(a6.decode_asn1_der(&[0x03,0x01,0x01]).is_err());
+
+	let mut a7 :Asn1Any = Asn1Any::init_asn1();
+	let v1 = vec![0x5f,0x1e,0x01,0x00];
+	let _ = a7.decode_asn1(&v1).unwrap();
+	assert!(a7.decode_asn1_der(&v1).is_err());
+
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let v1 = vec![0x30,0x0c,0x06,0x02,0x2a,0x03,0x02,0x02,0x00,0x05,0x13,0x02,0x61,0x62];
+	let _ = c1.decode_asn1(&v1).unwrap();
+	let ctx = Asn1DecodeCtx::der();
+	assert!(c1.decode_asn1_ctx(&v1,&ctx).is_err());
+	assert!(!asn1obj_is_der());
+}
+
+#[test]
+fn test_a063() {
+	let mut a1 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let mut iv :Asn1Integer = Asn1Integer::init_asn1();
+	iv.val = 6;
+	a1.val.push(iv.clone());
+	iv.val = 5;
+	a1.val.push(iv.clone());
+	let code = a1.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code,&[0x30,0x80,0x02,0x01,0x06,0x02,0x01,0x05,0x00,0x00]));
+	let code = a1.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&[0x30,0x06,0x02,0x01,0x06,0x02,0x01,0x05]));
+
+	let mut a2 :Asn1Set<Asn1Integer> = Asn1Set::init_asn1();
+	a2.val = a1.val.clone();
+	let code = a2.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code,&[0x31,0x80,0x02,0x01,0x05,0x02,0x01,0x06,0x00,0x00]));
+	let code = a2.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&[0x31,0x06,0x02,0x01,0x06,0x02,0x01,0x05]));
+
+	let mut b1 :Asn1OctData = Asn1OctData::init_asn1();
+	for i in 0..2500 {
+		b1.data.push((i % 251) as u8);
+	}
+	let code = b1.encode_asn1_cer().unwrap();
+	assert!(code.len() == 2516);
+	assert!(check_equal_u8(&code[0..6],&[0x24,0x80,0x04,0x82,0x03,0xe8]));
+	assert!(check_equal_u8(&code[1006..1010],&[0x04,0x82,0x03,0xe8]));
+	assert!(check_equal_u8(&code[2010..2014],&[0x04,0x82,0x01,0xf4]));
+	assert!(check_equal_u8(&code[2514..2516],&[0x00,0x00]));
+	let mut b2 :Asn1OctData = Asn1OctData::init_asn1();
+	let c = b2.decode_asn1(&code).unwrap();
+	assert!(c == code.len());
+	assert!(check_equal_u8(&b1.data,&b2.data));
+
+	let mut d1 :Asn1BitData = Asn1BitData::init_asn1();
+	for _ in 0..1500 {
+		d1.data.push(0x01);
+	}
+	let code = d1.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code[0..7],&[0x23,0x80,0x03,0x82,0x03,0xe8,0x00]));
+	assert!(check_equal_u8(&code[1006..1011],&[0x03,0x82,0x01,0xf6,0x00]));
+	let mut d2 :Asn1BitData = Asn1BitData::init_asn1();
+	let c = d2.decode_asn1(&code).unwrap();
+	assert!(c == code.len());
+	assert!(check_equal_u8(&d1.data,&d2.data));
+
+	let mut e1 :Asn1String = Asn1String::init_asn1();
+	e1.val = "a".repeat(1001);
+	let code = e1.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code[0..2],&[0x2c,0x80]));
+	let mut e2 :Asn1String = Asn1String::init_asn1();
+	let _ = e2.decode_asn1(&code).unwrap();
+	assert!(e2.val == e1.val);
+
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let v1 = vec![0x30,0x0b,0x06,0x02,0x2a,0x03,0x02,0x01,0x05,0x13,0x02,0x61,0x62];
+	let _ = c1.decode_asn1(&v1).unwrap();
+	let ctx = Asn1EncodeCtx::cer();
+	let code = c1.encode_asn1_ctx(&ctx).unwrap();
+	assert!(check_equal_u8(&code,&[0x30,0x80,0x06,0x02,0x2a,0x03,0x02,0x01,0x05,0x13,0x02,0x61,0x62,0x00,0x00]));
+	assert!(!asn1obj_is_cer());
+	let code = c1.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+}
+
+#[test]
+fn test_a064() {
+	/*the segments of the character string are in OCTET STRING*/
+	let mut a1 :Asn1String = Asn1String::init_asn1();
+	a1.val = "a".repeat(1001);
+	let mut v1 :Vec<u8> = vec![0x2c,0x80,0x04,0x82,0x03,0xe8];
+	v1.extend_from_slice(&[0x61;1000]);
+	v1.extend_from_slice(&[0x04,0x01,0x61,0x00,0x00]);
+	let code = a1.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+	let mut a2 :Asn1String = Asn1String::init_asn1();
+	let c = a2.decode_asn1(&code).unwrap();
+	assert!(c == v1.len());
+	assert!(a2.val == a1.val);
+
+	let v1 :Vec<u8> = vec![0x33,0x80,0x04,0x02,0x61,0x62,0x24,0x80,0x04,0x01,0x63,0x00,0x00,0x00,0x00];
+	let mut p1 :Asn1PrintableString = Asn1PrintableString::init_asn1();
+	let c = p1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(p1.val == "abc");
+
+	/*the segment in the string own tag is not valid*/
+	let v1 :Vec<u8> = vec![0x33,0x80,0x13,0x02,0x61,0x62,0x00,0x00];
+	let mut p2 :Asn1PrintableString = Asn1PrintableString::init_asn1();
+	assert!(p2.decode_asn1(&v1).is_err());
+
+	let mut b1 :Asn1BMPString = Asn1BMPString::init_asn1();
+	b1.val = "a".repeat(501);
+	let mut v1 :Vec<u8> = vec![0x3e,0x80,0x04,0x82,0x03,0xe8];
+	v1.extend_from_slice(&[0x00,0x61].repeat(500));
+	v1.extend_from_slice(&[0x04,0x02,0x00,0x61,0x00,0x00]);
+	let code = b1.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+	let mut b2 :Asn1BMPString = Asn1BMPString::init_asn1();
+	let _ = b2.decode_asn1(&code).unwrap();
+	assert!(b2.val == b1.val);
+}
+
+#[asn1_sequence()]
+struct CCRefInner<'a> {
+	pub ver :Asn1IntegerRef<'a>,
+	pub data :Asn1OctDataRef<'a>,
+	pub any :Asn1AnyRef<'a>,
+}
+
+#[asn1_sequence()]
+struct CCRefOuter<'a> {
+	pub elem :Asn1SeqRef<'a, Asn1SeqRef<'a, CCRefInner<'a>>>,
+}
+
+#[test]
+fn test_a065() {
+	let v1 = vec![0x30,0x0e,0x30,0x0c,0x02,0x01,0x05,0x04,0x02,0x61,0x62,0x0c,0x03,0x61,0x62,0x63];
+	let mut c1 :CCRefOuter = CCRefOuter::init_asn1();
+	let c = c1.decode_asn1(&v1).unwrap();
+	assert!(c == v1.len());
+	assert!(c1.elem.val.len() == 1);
+	assert!(c1.elem.val[0].val.len() == 1);
+	let inner = &c1.elem.val[0].val[0];
+	assert!(inner.ver.get_i64().unwrap() == 5);
+	assert!(inner.data.data.as_ptr() == v1[9..].as_ptr());
+	assert!(check_equal_u8(inner.data.data,&[0x61,0x62]));
+	assert!(inner.any.tag == ASN1_UTF8STRING_FLAG as u64);
+	assert!(check_equal_u8(inner.any.content,&[0x61,0x62,0x63]));
+	let code = c1.encode_asn1().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+	let mut w :Vec<u8> = Vec::new();
+	c1.print_asn1("outer",0,&mut w).unwrap();
+	assert!(w.len() > 0);
+
+	let mut i1 :Asn1IntegerRef = Asn1IntegerRef::init_asn1();
+	let _ = i1.decode_asn1(&[0x02,0x02,0xff,0x7f]).unwrap();
+	assert!(i1.is_negative());
+	assert!(i1.get_i64().unwrap() == -129);
+	assert!(i1.decode_asn1(&[0x02,0x02,0x00,0x7f]).is_ok());
+	{
+		let _guard = Asn1DecodeCtxGuard::new(&Asn1DecodeCtx::der());
+		assert!(i1.decode_asn1(&[0x02,0x02,0x00,0x7f]).is_err());
+	}
+	assert!(i1.decode_asn1(&[0x02,0x02,0x00,0x7f]).is_ok());
+
+	let mut o1 :Asn1OctDataRef = Asn1OctDataRef::init_asn1();
+	assert!(o1.decode_asn1(&[0x24,0x80,0x04,0x01,0x61,0x00,0x00]).is_err());
+}
+
+#[test]
+fn test_a066() {
+	let mut v1 :Vec<u8> = vec![0x30,0x80,0x06,0x03,0x2a,0x03,0x04,0xa0,0x80,0x04,0x82,0x27,0x10];
+	for i in 0..10000 {
+		v1.push((i % 251) as u8);
+	}
+	v1.extend_from_slice(&[0x00,0x00,0x02,0x01,0x07,0x00,0x00]);
+
+	let mut sd = Asn1StreamDecoder::new(std::io::Cursor::new(v1.clone()));
+	let mut hdrs :Vec<Asn1StreamHeader> = Vec::new();
+	let mut ends :Vec<u64> = Vec::new();
+	let mut content :Vec<u8> = Vec::new();
+	let mut chunks :usize = 0;
+	loop {
+		let ev = sd.next_event().unwrap();
+		match ev {
+			Some(Asn1StreamEvent::Header(h)) => {
+				hdrs.push(h);
+			},
+			Some(Asn1StreamEvent::Content(c)) => {
+				assert!(c.len() <= 4096);
+				chunks += 1;
+				content.extend_from_slice(&c);
+			},
+			Some(Asn1StreamEvent::End(t)) => {
+				ends.push(t);
+			},
+			None => {
+				break;
+			},
+		}
+	}
+	assert!(hdrs.len() == 5);
+	assert!(hdrs[0].tag == 0x30 && hdrs[0].length.is_none() && hdrs[0].depth == 0);
+	assert!(hdrs[2].tag == 0xa0 && hdrs[2].length.is_none() && hdrs[2].offset == 7);
+	assert!(hdrs[3].tag == 0x04 && hdrs[3].length == Some(10000) && hdrs[3].depth == 2 && hdrs[3].hdrlen == 4);
+	assert!(ends == vec![0x06,0x04,0xa0,0x02,0x30]);
+	assert!(chunks == 5);
+	assert!(content.len() == 10000 + 3 + 1);
+	assert!(check_equal_u8(&content[3..10003],&v1[13..10013]));
+	assert!(sd.offset() == v1.len() as u64);
+
+	let mut sd = Asn1StreamDecoder::new(std::io::Cursor::new(v1.clone()));
+	let _ = sd.next_event().unwrap();
+	let o :Asn1Object = sd.decode_next().unwrap().unwrap();
+	assert!(o.get_value() == "1.2.3.4");
+	let _ = sd.next_event().unwrap();
+	let _ = sd.next_event().unwrap();
+	let mut buf :[u8; 1000] = [0; 1000];
+	let mut total :usize = 0;
+	loop {
+		let c = sd.read_content(&mut buf).unwrap();
+		if c == 0 {
+			break;
+		}
+		assert!(check_equal_u8(&buf[0..c],&v1[(13+total)..(13+total+c)]));
+		total += c;
+	}
+	assert!(total == 10000);
+	assert!(matches!(sd.next_event().unwrap(),Some(Asn1StreamEvent::End(0x04))));
+	assert!(matches!(sd.next_event().unwrap(),Some(Asn1StreamEvent::End(0xa0))));
+	let i :Asn1Integer = sd.decode_next().unwrap().unwrap();
+	assert!(i.val == 7);
+	let n :Option<Asn1Integer> = sd.decode_next().unwrap();
+	assert!(n.is_none());
+	assert!(matches!(sd.next_event().unwrap(),Some(Asn1StreamEvent::End(0x30))));
+	assert!(sd.next_event().unwrap().is_none());
+
+	let mut sd = Asn1StreamDecoder::new(std::io::Cursor::new(v1.clone()));
+	let s :Asn1Any = sd.decode_next().unwrap().unwrap();
+	assert!(s.tag == 0x30);
+	assert!(sd.offset() == v1.len() as u64);
+
+	let mut sd = Asn1StreamDecoder::new(std::io::Cursor::new(v1[0..100].to_vec()));
+	let mut ores :Result<Option<Asn1StreamEvent>,Box<dyn Error>>;
+	loop {
+		ores = sd.next_event();
+		if ores.is_err() || ores.as_ref().unwrap().is_none() {
+			break;
+		}
+	}
+	assert!(ores.is_err());
+
+	let mut sd = Asn1StreamDecoder::new(std::io::Cursor::new(vec![0x30,0x03,0x02,0x02,0x01]));
+	let _ = sd.next_event().unwrap();
+	assert!(sd.next_event().is_err());
+}
+
+#[asn1_sequence()]
+#[derive(Clone)]
+struct CCWriteElem {
+	pub ver :Asn1Integer,
+	pub imp :Asn1Imp<Asn1OctData,1>,
+	pub exp :Asn1Exp<Asn1Seq<Asn1Integer>,2>,
+	pub hig :Asn1AppImp<Asn1Integer,300>,
+	pub opt :Asn1Opt<Asn1Boolean>,
+	pub ndef :Asn1Ndef<Asn1Integer,3>,
+	pub set :Asn1Set<Asn1Integer>,
+	pub impset :Asn1ImpSet<Asn1Integer,4>,
+	pub any :Asn1Any,
+}
+
+#[asn1_sequence()]
+struct CCWriteSeq {
+	pub elem :Asn1Seq<CCWriteElem>,
+}
+
+fn check_write_asn1<T :Asn1Op>(v :&T) {
+	let code = v.encode_asn1().unwrap();
+	assert!(v.encoded_len().unwrap() == code.len());
+	let mut w :Vec<u8> = Vec::with_capacity(code.len());
+	let c = v.write_asn1(&mut w).unwrap();
+	assert!(c == code.len());
+	assert!(w.capacity() == code.len());
+	assert!(check_equal_u8(&w,&code));
+}
+
+#[test]
+fn test_a067() {
+	let mut e1 :CCWriteElem = CCWriteElem::init_asn1();
+	e1.ver.val = 2;
+	for i in 0..300 {
+		e1.imp.val.data.push(i as u8);
+	}
+	let mut i1 :Asn1Integer = Asn1Integer::init_asn1();
+	i1.val = 0x1234;
+	e1.exp.val.val.push(i1.clone());
+	e1.hig.val.val = -5;
+	let mut b1 :Asn1Boolean = Asn1Boolean::init_asn1();
+	b1.val = true;
+	e1.opt.val = Some(b1);
+	e1.ndef.val = Some(i1.clone());
+	e1.set.val.push(i1.clone());
+	i1.val = 3;
+	e1.set.val.push(i1.clone());
+	e1.impset.val.push(i1.clone());
+	e1.any.tag = 0x0c;
+	e1.any.content = vec![0x61,0x62];
+	let mut s1 :CCWriteSeq = CCWriteSeq::init_asn1();
+	s1.elem.val.push(e1.clone());
+	e1.opt.val = None;
+	e1.impset.val = Vec::new();
+	s1.elem.val.push(e1.clone());
+	check_write_asn1(&s1);
+	check_write_asn1(&e1.imp);
+	check_write_asn1(&e1.hig);
+
+	let code = s1.encode_asn1().unwrap();
+	let mut buf :[u8; 2048] = [0; 2048];
+	let mut slice :&mut [u8] = &mut buf;
+	let c = s1.write_asn1(&mut slice).unwrap();
+	assert!(check_equal_u8(&buf[0..c],&code));
+	let mut small :[u8; 16] = [0; 16];
+	let mut slice :&mut [u8] = &mut small;
+	assert!(s1.write_asn1(&mut slice).is_err());
+
+	let mut s2 :CCWriteSeq = CCWriteSeq::init_asn1();
+	let _ = s2.decode_asn1(&code).unwrap();
+	assert!(s2.elem.val.len() == 2);
+	assert!(s2.elem.val[0].hig.val.val == -5);
+
+	let ctx = Asn1EncodeCtx::cer();
+	let oldctx = asn1obj_set_encode_ctx(&ctx);
+	check_write_asn1(&s1);
+	asn1obj_set_encode_ctx(&oldctx);
+	let code = s1.encode_asn1_cer().unwrap();
+	let mut w :Vec<u8> = Vec::new();
+	let oldctx = asn1obj_set_encode_ctx(&ctx);
+	let _ = s1.write_asn1(&mut w).unwrap();
+	asn1obj_set_encode_ctx(&oldctx);
+	assert!(check_equal_u8(&w,&code));
+
+	let mut a1 :BBTestautoSeq = BBTestautoSeq::init_asn1();
+	let val = serde_json::json!([{
+			"seltype" : "1.2.3",
+			"ccv" : "1.7.222"
+	},{
+			"seltype" : "1.2.4",
+			"bbv" : "22ddee0000000222"
+	}]);
+	let _ = a1.decode_json("",&val).unwrap();
+	check_write_asn1(&a1);
+
+	let mut n1 :IntTestautoSeq = IntTestautoSeq::init_asn1();
+	let mut n2 :IntTestauto = IntTestauto::init_asn1();
+	n2.seltype = 3;
+	n2.ddv.val = "hello".to_string();
+	n1.elem.val.push(n2);
+	check_write_asn1(&n1);
+}
+
+thread_local! {
+	static TEST_A068_WRITES : std::cell::Cell<usize> = std::cell::Cell::new(0);
+	static TEST_A068_LENS : std::cell::Cell<usize> = std::cell::Cell::new(0);
+	static TEST_A068_ENCODES : std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+/*Asn1Integer counting write_asn1 encoded_len and encode_asn1 calls*/
+#[derive(Clone)]
+struct A068CountInteger {
+	val :Asn1Integer,
+}
+
+impl Asn1Op for A068CountInteger {
+	fn decode_asn1(&mut self,code :&[u8]) -> Result<usize,Box<dyn Error>> {
+		return self.val.decode_asn1(code);
+	}
+	fn encode_asn1(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+		TEST_A068_ENCODES.with(|c| c.set(c.get() + 1));
+		return self.val.encode_asn1();
+	}
+	fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {
+		TEST_A068_LENS.with(|c| c.set(c.get() + 1));
+		return self.val.encoded_len();
+	}
+	fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {
+		return self.val.encoded_tag_len();
+	}
+	fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {
+		TEST_A068_WRITES.with(|c| c.set(c.get() + 1));
+		return self.val.write_asn1(iowriter);
+	}
+	fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {
+		return self.val.print_asn1(name,tab,iowriter);
+	}
+	fn init_asn1() -> Self {
+		A068CountInteger {
+			val : Asn1Integer::init_asn1(),
+		}
+	}
+	fn encode_json(&self, key :&str,val :&mut serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+		return self.val.encode_json(key,val);
+	}
+	fn decode_json(&mut self, key :&str, val :&serde_json::value::Value) -> Result<i32,Box<dyn Error>> {
+		return self.val.decode_json(key,val);
+	}
+}
+
+macro_rules! a068_wrap {
+	($prev:expr,$wrap:ident,$tag:expr) => {{
+		let mut c :$wrap<Asn1Seq<_>,$tag> = $wrap::init_asn1();
+		c.val.val.push($prev);
+		c
+	}};
+}
+
+#[test]
+fn test_a068() {
+	let mut leaf :A068CountInteger = A068CountInteger::init_asn1();
+	leaf.val.val = 5;
+	let v = a068_wrap!(leaf,Asn1Exp,1);
+	let v = a068_wrap!(v,Asn1Imp,2);
+	let v = a068_wrap!(v,Asn1Exp,3);
+	let v = a068_wrap!(v,Asn1Imp,4);
+	let v = a068_wrap!(v,Asn1Exp,5);
+	let v = a068_wrap!(v,Asn1Imp,6);
+	let v = a068_wrap!(v,Asn1Exp,7);
+	let v = a068_wrap!(v,Asn1Imp,8);
+	let v = a068_wrap!(v,Asn1Exp,9);
+	let v = a068_wrap!(v,Asn1Imp,10);
+	let v = a068_wrap!(v,Asn1Exp,11);
+	let v = a068_wrap!(v,Asn1Imp,12);
+	let v = a068_wrap!(v,Asn1Exp,13);
+	let v = a068_wrap!(v,Asn1Imp,14);
+	let v = a068_wrap!(v,Asn1Exp,15);
+	let v = a068_wrap!(v,Asn1Imp,40);
+	let code = v.encode_asn1().unwrap();
+	TEST_A068_WRITES.with(|c| c.set(0));
+	TEST_A068_LENS.with(|c| c.set(0));
+	TEST_A068_ENCODES.with(|c| c.set(0));
+	assert!(v.encoded_len().unwrap() == code.len());
+	/*the length is computed without writing or encoding*/
+	assert!(TEST_A068_WRITES.with(|c| c.get()) == 0);
+	assert!(TEST_A068_LENS.with(|c| c.get()) == 1);
+	assert!(TEST_A068_ENCODES.with(|c| c.get()) == 0);
+	TEST_A068_LENS.with(|c| c.set(0));
+	let mut w :Vec<u8> = Vec::new();
+	let wlen = v.write_asn1(&mut w).unwrap();
+	assert!(wlen == code.len());
+	assert!(w == code);
+	assert!(TEST_A068_WRITES.with(|c| c.get()) == 1);
+	/*every ancestor header takes the length computed once*/
+	assert!(TEST_A068_LENS.with(|c| c.get()) == 1);
+	assert!(TEST_A068_ENCODES.with(|c| c.get()) == 0);
+	assert!(asn1obj_tag_len(&code).unwrap() == 2);
+}
+
+/*the length given without encode_asn1 is the same as the encoded*/
+fn check_encoded_len<T :Asn1Op>(v :&T) {
+	let code = v.encode_asn1().unwrap();
+	assert!(v.encoded_len().unwrap() == code.len());
+	assert!(v.encoded_tag_len().unwrap() == asn1obj_tag_len(&code).unwrap());
+	let mut w :Vec<u8> = Vec::new();
+	assert!(v.write_asn1(&mut w).unwrap() == code.len());
+	assert!(w == code);
+}
+
+#[test]
+fn test_a069() {
+	let mut i1 :Asn1Integer = Asn1Integer::init_asn1();
+	let mut e1 :Asn1Enumerated = Asn1Enumerated::init_asn1();
+	for v in [0,1,127,128,-1,-128,-129,0x7fff,0x8000,-0x8000,-0x8001,0x7fffffffff,-0x8000000000] {
+		i1.val = v;
+		check_encoded_len(&i1);
+		e1.val = v;
+		check_encoded_len(&e1);
+	}
+	i1.val = 0x8000000000;
+	assert!(i1.encoded_len().is_err());
+	assert!(i1.encode_asn1().is_err());
+
+	let mut b1 :Asn1Boolean = Asn1Boolean::init_asn1();
+	b1.val = true;
+	check_encoded_len(&b1);
+	check_encoded_len(&Asn1Null::init_asn1());
+
+	let mut bs :Asn1BitString = Asn1BitString::init_asn1();
+	assert!(bs.encoded_len().is_err());
+	bs.val = "ab".to_string();
+	check_encoded_len(&bs);
+	let mut bd :Asn1BitData = Asn1BitData::init_asn1();
+	check_encoded_len(&bd);
+	bd.data = vec![0x1;200];
+	check_encoded_len(&bd);
+	let mut bf :Asn1BitDataFlag = Asn1BitDataFlag::init_asn1();
+	bf.data = vec![0x80];
+	check_encoded_len(&bf);
+
+	let mut os :Asn1OctString = Asn1OctString::init_asn1();
+	os.val = "x".repeat(300);
+	check_encoded_len(&os);
+	let mut od :Asn1OctData = Asn1OctData::init_asn1();
+	od.data = vec![0x2;70000];
+	check_encoded_len(&od);
+
+	let mut o1 :Asn1Object = Asn1Object::init_asn1();
+	for s in ["1.2","1.2.840.113549.1.1.11","2.5.4.3","1.2.0.127.128.16383.16384","2.25.340282366920938463463374607431768211455"] {
+		let _ = o1.set_value(s).unwrap();
+		check_encoded_len(&o1);
+	}
+	let mut r1 :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+	for s in ["0","8571.3.2","127.128.9223372036854775807.18446744073709551616"] {
+		let _ = r1.set_value(s).unwrap();
+		check_encoded_len(&r1);
+	}
+	let mut iri :Asn1OidIri = Asn1OidIri::init_asn1();
+	let _ = iri.set_value("/ISO/Registration-Authority").unwrap();
+	check_encoded_len(&iri);
+	let mut riri :Asn1RelativeOidIri = Asn1RelativeOidIri::init_asn1();
+	let _ = riri.set_value("Registration-Authority").unwrap();
+	check_encoded_len(&riri);
+
+	let mut u1 :Asn1String = Asn1String::init_asn1();
+	u1.val = "h\u{e9}llo \u{1f600}".to_string();
+	check_encoded_len(&u1);
+	let mut p1 :Asn1PrintableString = Asn1PrintableString::init_asn1();
+	p1.val = "hello".to_string();
+	check_encoded_len(&p1);
+	let mut a1 :Asn1IA5String = Asn1IA5String::init_asn1();
+	a1.val = "a@b".to_string();
+	check_encoded_len(&a1);
+	let mut n1 :Asn1NumericString = Asn1NumericString::init_asn1();
+	let _ = n1.set_value("12 34").unwrap();
+	check_encoded_len(&n1);
+	let mut un :Asn1UniversalString = Asn1UniversalString::init_asn1();
+	let _ = un.set_value("a\u{1f600}").unwrap();
+	check_encoded_len(&un);
+	let mut g1 :Asn1GeneralString = Asn1GeneralString::init_asn1();
+	let _ = g1.set_value("caf\u{e9}").unwrap();
+	check_encoded_len(&g1);
+
+	let mut t1 :Asn1Time = Asn1Time::init_asn1();
+	let _ = t1.set_value_str("2023-05-06 07:08:09").unwrap();
+	check_encoded_len(&t1);
+	let _ = t1.set_utag(ASN1_UTCTIME_FLAG).unwrap();
+	check_encoded_len(&t1);
+	let mut t2 :Asn1Time = Asn1Time::init_asn1();
+	let _ = t2.decode_asn1(&[0x18,0x0f,0x32,0x30,0x32,0x33,0x30,0x35,0x30,0x36,0x30,0x37,0x30,0x38,0x30,0x39,0x5a]).unwrap();
+	check_encoded_len(&t2);
+
+	let mut bn :Asn1BigNum = Asn1BigNum::init_asn1();
+	let mut bi :Asn1BigInteger = Asn1BigInteger::init_asn1();
+	for v in [0i64,1,127,128,255,256,0x7fff,0x8000,-1,-127,-128,-129,-255,-256,-257,-0x8000,-0x8001,-0x800000] {
+		let _ = bn.set_bigint(&BigInt::from(v));
+		check_encoded_len(&bn);
+		let _ = bi.set_value(v);
+		check_encoded_len(&bi);
+	}
+	let _ = bn.set_value(&[0xff;40]);
+	check_encoded_len(&bn);
+
+	let mut re :Asn1Real = Asn1Real::init_asn1();
+	for v in [0.0,-0.0,f64::NAN,f64::INFINITY,f64::NEG_INFINITY,1.0,-1.5,0.1,1e300,-1e-300,5e-324,f64::MAX] {
+		let _ = re.set_value(v);
+		check_encoded_len(&re);
+	}
+	let _ = re.set_decimal(ASN1_REAL_NR3,"1.25E2").unwrap();
+	check_encoded_len(&re);
+
+	let mut bm :Asn1BMPString = Asn1BMPString::init_asn1();
+	let _ = bm.set_value("a\u{1f600}b");
+	check_encoded_len(&bm);
+	bm.set_units(&[0x0061,0xd800],Some(0x62));
+	check_encoded_len(&bm);
+
+	let mut an :Asn1Any = Asn1Any::init_asn1();
+	let _ = an.decode_asn1(&[0xbf,0x81,0x00,0x03,0x02,0x01,0x05]).unwrap();
+	check_encoded_len(&an);
+}
+
+fn check_asn1_error(e :&Box<dyn Error>, kind :Asn1ErrorKind, offset :usize) {
+	let ae = e.downcast_ref::<Asn1Error>();
+	assert!(ae.is_some());
+	let ae = ae.unwrap();
+	assert!(ae.kind() == kind);
+	assert!(ae.offset() == offset);
+}
+
+#[test]
+fn test_a070() {
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	for _ in 0..2 {
+		let mut c2 :CCTestauto = CCTestauto::init_asn1();
+		c2.ccv.set_value("1.7.222").unwrap();
+		c2.bbv.val = BigUint::from(0x1234 as u32);
+		c2.ddv.val = "hello".to_string();
+		c1.elem.val.push(c2);
+	}
+	let code = c1.encode_asn1().unwrap();
+
+	let mut badcode = code.clone();
+	let pos = badcode.len() - 7;
+	assert!(badcode[pos] == ASN1_PRINTABLE_FLAG);
+	badcode[pos] = ASN1_OCT_STRING_FLAG;
+	let mut c3 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let e = c3.decode_asn1(&badcode).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::UnexpectedTag,pos);
+
+	let e = c3.decode_asn1(&code[0..(code.len() - 1)]).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::Truncated,0);
+
+	let mut i1 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let code :Vec<u8> = vec![0x30,0x07,0x02,0x01,0x01,0x02,0x02,0x00,0x01];
+	let _ = i1.decode_asn1(&code).unwrap();
+	let e = i1.decode_asn1_der(&code).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::ConstraintViolation,7);
+
+	let mut b1 :Asn1Seq<Asn1Boolean> = Asn1Seq::init_asn1();
+	let code :Vec<u8> = vec![0x30,0x07,0x01,0x01,0xff,0x01,0x02,0x00,0x00];
+	let e = b1.decode_asn1(&code).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidLength,5);
+	let s = format!("{}",e);
+	assert!(s.len() > 0);
+}
+
+#[test]
+fn test_a071() {
+	/*the generated choice code gives the kind error*/
+	let mut i1 :IntTestauto = IntTestauto::init_asn1();
+	let e = i1.decode_asn1(&[0x05,0x00]).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::UnexpectedTag,0);
+	i1.seltype = 9;
+	let e = i1.encode_asn1().err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidValue,0);
+	let e = i1.encoded_len().err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidValue,0);
+}
+
+#[test]
+fn test_a072() {
+	/*the inner identifier 1f 23 is longer than the implicit tag 80*/
+	let mut i1 :Asn1Imp<Asn1OidIri,0> = Asn1Imp::init_asn1();
+	let e = i1.decode_asn1(&[0x80,0x02,0x61,0xff]).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::InvalidValue);
+	assert!(ae.offset() == 2);
+	let mut i2 :Asn1Exp<Asn1OidIri,0> = Asn1Exp::init_asn1();
+	let e = i2.decode_asn1(&[0xa0,0x02,0x61,0xff]).err().unwrap();
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().offset() == 2);
+
+	/*the implicit tag 9f 1f is longer than the inner identifier 02*/
+	let mut i3 :Asn1Imp<Asn1Integer,31> = Asn1Imp::init_asn1();
+	let e = i3.decode_asn1_der(&[0x9f,0x1f,0x02,0x00,0x01]).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::ConstraintViolation,3);
+}
+
+#[test]
+fn test_a073() {
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	for _ in 0..2 {
+		let mut c2 :CCTestauto = CCTestauto::init_asn1();
+		c2.ccv.set_value("1.7.222").unwrap();
+		c2.bbv.val = BigUint::from(0x1234 as u32);
+		c2.ddv.val = "hello".to_string();
+		c1.elem.val.push(c2);
+	}
+	let mut code = c1.encode_asn1().unwrap();
+	let pos = code.len() - 7;
+	code[pos] = ASN1_OCT_STRING_FLAG;
+	let mut c3 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let e = c3.decode_asn1(&code).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.path() == "CCTestautoSeq.elem[1].ddv");
+	assert!(ae.offset() == pos);
+	let s = format!("{}",e);
+	assert!(s.contains("CCTestautoSeq.elem[1].ddv"));
+
+	let mut a1 :BBTestautoSeq = BBTestautoSeq::init_asn1();
+	let val = serde_json::json!([{
+			"seltype" : "1.2.3",
+			"ccv" : "1.7.222"
+	}]);
+	let _ = a1.decode_json("",&val).unwrap();
+	let mut code = a1.encode_asn1().unwrap();
+	assert!(code[6] == ASN1_OBJECT_FLAG);
+	code[6] = ASN1_OCT_STRING_FLAG;
+	let mut a2 :BBTestautoSeq = BBTestautoSeq::init_asn1();
+	let e = a2.decode_asn1(&code).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::UnexpectedTag,6);
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().path() == "BBTestautoSeq.elem[0].ccv");
+
+	let e = Asn1Error::new(Asn1ErrorKind::Truncated,0,"short");
+	assert!(e.path().len() == 0);
+}
+
+#[test]
+fn test_a074() {
+	let mut code :Vec<u8> = Vec::new();
+	for _ in 0..150 {
+		code.push(ASN1_OCT_STRING_FLAG | ASN1_CONSTRUCTED);
+		code.push(0x80);
+	}
+	code.push(ASN1_OCT_STRING_FLAG);
+	code.push(0x01);
+	code.push(0x41);
+	for _ in 0..150 {
+		code.push(0x00);
+		code.push(0x00);
+	}
+	let mut o1 :Asn1OctData = Asn1OctData::init_asn1();
+	let e = o1.decode_asn1(&code).err().unwrap();
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().kind() == Asn1ErrorKind::DepthExceeded);
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_depth = 200;
+	let c = o1.decode_asn1_ctx(&code,&ctx).unwrap();
+	assert!(c == code.len());
+	assert!(o1.data == vec![0x41]);
+
+	let code :Vec<u8> = vec![0x30,0x09,0x02,0x01,0x01,0x02,0x01,0x02,0x02,0x01,0x03];
+	let mut i1 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_elements = 2;
+	let e = i1.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::TooManyElements,8);
+	ctx.max_elements = 3;
+	let _ = i1.decode_asn1_ctx(&code,&ctx).unwrap();
+	assert!(i1.val.len() == 3);
+
+	let code :Vec<u8> = vec![0x04,0x05,0x01,0x02,0x03,0x04,0x05];
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_content = 4;
+	let e = o1.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::ContentTooLarge,0);
+	let code :Vec<u8> = vec![0x24,0x80,0x04,0x03,0x01,0x02,0x03,0x04,0x02,0x04,0x05,0x00,0x00];
+	let e = o1.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::ContentTooLarge,0);
+
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let mut c2 :CCTestauto = CCTestauto::init_asn1();
+	c2.ccv.set_value("1.7.222").unwrap();
+	c2.ddv.val = "hello".to_string();
+	c1.elem.val.push(c2);
+	let code = c1.encode_asn1().unwrap();
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_depth = 1;
+	let mut c3 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let e = c3.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::DepthExceeded);
+	assert!(ae.path() == "CCTestautoSeq.elem[0]");
+	ctx.max_depth = 3;
+	let _ = c3.decode_asn1_ctx(&code,&ctx).unwrap();
+}
+
+#[test]
+fn test_a075() {
+	/*the decode limit is not the absent optional value*/
+	let code :Vec<u8> = vec![0x30,0x05,0x30,0x03,0x02,0x01,0x05];
+	let mut a1 :Asn1Seq<Asn1Opt<Asn1Seq<Asn1Integer>>> = Asn1Seq::init_asn1();
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_depth = 1;
+	let e = a1.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::DepthExceeded,2);
+	ctx.max_depth = 2;
+	let e = a1.decode_asn1_ctx(&code,&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::DepthExceeded,4);
+	ctx.max_depth = 3;
+	let c = a1.decode_asn1_ctx(&code,&ctx).unwrap();
+	assert!(c == code.len());
+	assert!(a1.val[0].val.as_ref().unwrap().val[0].val == 5);
+
+	/*the element decoded in nothing does not go on*/
+	let code :Vec<u8> = vec![0x30,0x03,0x01,0x01,0xff];
+	let mut a2 :Asn1Seq<Asn1Opt<Asn1Integer>> = Asn1Seq::init_asn1();
+	let e = a2.decode_asn1(&code).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidLength,2);
+	let mut a3 :Asn1Set<Asn1Opt<Asn1Integer>> = Asn1Set::init_asn1();
+	let e = a3.decode_asn1(&[0x31,0x03,0x01,0x01,0xff]).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidLength,2);
+
+	/*the tagged value is in the depth too*/
+	let mut a4 :Asn1Imp<Asn1Seq<Asn1Integer>,1> = Asn1Imp::init_asn1();
+	let mut ctx = Asn1DecodeCtx::new();
+	ctx.max_depth = 1;
+	let e = a4.decode_asn1_ctx(&[0xa1,0x03,0x02,0x01,0x05],&ctx).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::DepthExceeded,2);
+	ctx.max_depth = 2;
+	let _ = a4.decode_asn1_ctx(&[0xa1,0x03,0x02,0x01,0x05],&ctx).unwrap();
+}
+
+#[test]
+fn test_a076() {
+	let code :Vec<u8> = vec![0x30,0x1a,
+		0x02,0x01,0x05,
+		0x04,0x05,0x30,0x03,0x01,0x01,0xff,
+		0x03,0x03,0x00,0x05,0x00,
+		0xa1,0x80,0x0c,0x01,0x61,0x00,0x00,
+		0x04,0x02,0x68,0x69];
+	let (n1,c) = Asn1Node::parse(&code).unwrap();
+	assert!(c == code.len());
+	assert!(n1.constructed);
+	assert!(n1.class == ASN1_CLASS_UNIVERSAL);
+	assert!(n1.number == 0x10);
+	assert!(n1.hdrlen == 2);
+	assert!(n1.content == (2..28));
+	assert!(n1.children.len() == 5);
+	assert!(n1.children[0].data == vec![0x05]);
+	assert!(n1.children[0].content == (4..5));
+	assert!(n1.children[1].encapsulated);
+	assert!(n1.children[1].children.len() == 1);
+	assert!(n1.children[1].children[0].offset == 7);
+	assert!(n1.children[1].children[0].children[0].tag == ASN1_BOOLEAN_FLAG as u64);
+	assert!(n1.children[2].encapsulated);
+	assert!(n1.children[2].children[0].tag == ASN1_NULL_FLAG as u64);
+	assert!(n1.children[3].class == ASN1_CLASS_CONTEXT);
+	assert!(n1.children[3].number == 1);
+	assert!(n1.children[3].eoclen == 2);
+	assert!(n1.children[3].children[0].data == vec![0x61]);
+	assert!(!n1.children[4].encapsulated);
+	assert!(n1.children[4].children.len() == 0);
+	assert!(check_equal_u8(&n1.encode(),&code));
+
+	let ns = Asn1Node::parse_all(&code[2..]).unwrap();
+	assert!(ns.len() == 5);
+	assert!(ns[3].offset == 15);
+
+	let e = Asn1Node::parse(&code[0..(code.len() - 1)]).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::Truncated,0);
+	let mut badcode = code.clone();
+	badcode[20] = 0x80;
+	let e = Asn1Node::parse(&badcode).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidLength,19);
+
+	let mut a1 :Asn1Any = Asn1Any::init_asn1();
+	let _ = a1.decode_asn1(&code).unwrap();
+	let n2 = a1.to_node().unwrap();
+	assert!(n2.children.len() == 5);
+	assert!(check_equal_u8(&n2.encode(),&code));
+}
+
+#[test]
+fn test_a077() {
+	let code :Vec<u8> = vec![0x30,0x1a,
+		0x02,0x01,0x05,
+		0x04,0x05,0x30,0x03,0x01,0x01,0xff,
+		0x03,0x03,0x00,0x05,0x00,
+		0xa1,0x80,0x0c,0x01,0x61,0x00,0x00,
+		0x04,0x02,0x68,0x69];
+	let mut w :Vec<u8> = Vec::new();
+	asn1obj_dump(&code,&mut w).unwrap();
+	let s = String::from_utf8(w).unwrap();
+	let lines :Vec<&str> = s.lines().collect();
+	assert!(lines.len() == 11);
+	assert!(lines[0] == "    0:d=0  hl=2 l=  26 cons: SEQUENCE          ");
+	assert!(lines[1] == "    2:d=1  hl=2 l=   1 prim:  INTEGER           :05");
+	assert!(lines[2] == "    5:d=1  hl=2 l=   5 prim:  OCTET STRING      ");
+	assert!(lines[3] == "    7:d=2  hl=2 l=   3 cons:   SEQUENCE          ");
+	assert!(lines[4] == "    9:d=3  hl=2 l=   1 prim:    BOOLEAN           :255");
+	assert!(lines[7] == "   17:d=1  hl=2 l=inf  cons:  cont [ 1 ]        ");
+	assert!(lines[8] == "   19:d=2  hl=2 l=   1 prim:   UTF8STRING        :a");
+	assert!(lines[9] == "   22:d=2  hl=2 l=   0 prim:   EOC               ");
+	assert!(lines[10] == "   24:d=1  hl=2 l=   2 prim:  OCTET STRING      [HEX DUMP]:6869");
+
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let mut c2 :CCTestauto = CCTestauto::init_asn1();
+	c2.ccv.set_value("1.7.222").unwrap();
+	c2.bbv.val = BigUint::from(0x1234 as u32);
+	c2.ddv.val = "hello".to_string();
+	c1.elem.val.push(c2);
+	let code = c1.encode_asn1().unwrap();
+	let mut c3 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let mut w :Vec<u8> = Vec::new();
+	let c = asn1obj_dump_typed(&mut c3,&code,&mut w).unwrap();
+	assert!(c == code.len());
+	assert!(c3.elem.val.len() == 1);
+	let s = String::from_utf8(w).unwrap();
+	let lines :Vec<&str> = s.lines().collect();
+	assert!(lines.len() == 4);
+	assert!(lines[0].ends_with("  -- elem"));
+	assert!(lines[1] == "    2:d=1  hl=2 l=   3 prim:  OBJECT            :1.7.222  -- elem[0].ccv");
+	assert!(lines[2].contains("INTEGER           :1234  -- elem[0].bbv"));
+	assert!(lines[3].contains(":hello  -- elem[0].ddv"));
+
+	let mut i1 :Asn1Integer = Asn1Integer::init_asn1();
+	i1.val = -129;
+	let mut w :Vec<u8> = Vec::new();
+	asn1obj_dump(&i1.encode_asn1().unwrap(),&mut w).unwrap();
+	assert!(String::from_utf8(w).unwrap().contains("INTEGER           :-81"));
+}
+
+#[test]
+fn test_a078() {
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	for i in 0..2 {
+		let mut c2 :CCTestauto = CCTestauto::init_asn1();
+		c2.ccv.set_value("1.7.222").unwrap();
+		c2.bbv.val = BigUint::from(0x1234 as u32);
+		c2.ddv.val = format!("hello world {}",i);
+		c1.elem.val.push(c2);
+	}
+	let code = c1.encode_asn1().unwrap();
+	let mut w :Vec<u8> = Vec::new();
+	let c = asn1obj_hexdump_typed(&c1,&code,&mut w).unwrap();
+	assert!(c == code.len());
+	let s = String::from_utf8(w).unwrap();
+	let lines :Vec<&str> = s.lines().collect();
+	assert!(lines.len() == 13);
+	assert!(lines[0] == format!("0x00000000:{:<48}    {:<16}    elem (header SEQUENCE)"," 30 30","00"));
+	assert!(lines[1].ends_with("    elem[0].ccv (header OBJECT)"));
+	assert!(lines[2].starts_with("0x00000004: 2f 81 5e"));
+	assert!(lines[2].ends_with("    elem[0].ccv (content)"));
+	assert!(lines[6].starts_with("0x0000000d: 68 65 6c 6c 6f 20 77 6f 72 6c 64 20 30"));
+	assert!(lines[6].ends_with("hello world 0       elem[0].ddv (content)"));
+	assert!(lines[12].ends_with("elem[1].ddv (content)"));
+
+	let mut c3 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	let mut w :Vec<u8> = Vec::new();
+	assert!(asn1obj_hexdump_typed(&c3,&code,&mut w).is_err());
+	let _ = c3.decode_asn1(&code).unwrap();
+	assert!(asn1obj_hexdump_typed(&c3,&code,&mut w).is_ok());
+
+	let s = asn1obj_format_vecs(&code[0..18],1);
+	assert!(s == format!("    30:30:06:03:2f:81:5e:02:02:12:34:13:0d:68:65:6c    00../.^...4..hel\n    6c:6f{:42}    lo\n",""));
+}
+
+#[asn1_sequence()]
+struct CCDumpInner {
+	pub ver :Asn1Integer,
+	pub name :Asn1PrintableString,
+}
+
+#[asn1_sequence()]
+struct CCDumpOuter {
+	pub exp :Asn1Exp<Asn1Seq<CCDumpInner>,1>,
+	pub imp :Asn1Imp<Asn1Integer,2>,
+}
+
+#[test]
+fn test_a079() {
+	let mut c1 :CCDumpOuter = CCDumpOuter::init_asn1();
+	let mut c2 :CCDumpInner = CCDumpInner::init_asn1();
+	c2.ver.val = 3;
+	c2.name.val = "ab".to_string();
+	c1.exp.val.val.push(c2);
+	c1.imp.val.val = 5;
+	let code = c1.encode_asn1().unwrap();
+	let mut c3 :CCDumpOuter = CCDumpOuter::init_asn1();
+	let mut w :Vec<u8> = Vec::new();
+	let c = asn1obj_dump_typed(&mut c3,&code,&mut w).unwrap();
+	assert!(c == code.len());
+	assert!(c3.exp.val.val[0].ver.val == 3);
+	let s = String::from_utf8(w).unwrap();
+	let lines :Vec<&str> = s.lines().collect();
+	assert!(lines.len() == 4);
+	assert!(lines[0].ends_with("  -- exp"));
+	/*the fields inside the tagged value are at the offset of code*/
+	assert!(lines[1] == "    2:d=1  hl=2 l=   1 prim:  INTEGER           :03  -- exp[0].ver");
+	assert!(lines[2].ends_with(":ab  -- exp[0].name"));
+	assert!(lines[3].ends_with("  -- imp"));
+
+	let mut w :Vec<u8> = Vec::new();
+	let c = asn1obj_hexdump_typed(&c1,&code,&mut w).unwrap();
+	assert!(c == code.len());
+	let s = String::from_utf8(w).unwrap();
+	let lines :Vec<&str> = s.lines().collect();
+	assert!(lines.len() == 7);
+	assert!(lines[1].starts_with("0x00000002: 02 01"));
+	assert!(lines[1].ends_with("    exp[0].ver (header INTEGER)"));
+	assert!(lines[4].starts_with("0x00000007: 61 62"));
+	assert!(lines[4].ends_with("    exp[0].name (content)"));
+	assert!(lines[6].ends_with("    imp (content)"));
+}
+
+#[test]
+fn test_a080() {
+	let ber :Vec<u8> = vec![0x30,0x81,0x12,
+		0x06,0x81,0x03,0x2f,0x81,0x5e,
+		0x02,0x02,0x12,0x34,
+		0x13,0x81,0x05,0x68,0x65,0x6c,0x6c,0x6f];
+	let mut c1 :CCTestautoSeq = CCTestautoSeq::init_asn1();
+	assert!(c1.get_raw_asn1().is_none());
+	let c = c1.decode_asn1(&ber).unwrap();
+	assert!(c == ber.len());
+	assert!(c1.get_raw_asn1().unwrap() == ber);
+	assert!(c1.elem.val[0].ddv.get_raw_asn1().unwrap() == ber[13..].to_vec());
+	assert!(c1.elem.val[0].get_raw_asn1().unwrap() == ber[3..].to_vec());
+
+	let der = c1.encode_asn1().unwrap();
+	assert!(der.len() == ber.len() - 3);
+	assert!(der[1] == 0x10);
+	assert!(c1.encode_asn1_preserve().unwrap() == ber);
+	{
+		let _guard = Asn1EncodeCtxGuard::new(&Asn1EncodeCtx::preserve());
+		let mut w :Vec<u8> = Vec::new();
+		assert!(c1.write_asn1(&mut w).unwrap() == ber.len());
+		assert!(w == ber);
+		assert!(c1.encoded_len().unwrap() == ber.len());
+	}
+
+	/*only the changed value and the headers around it are encoded again*/
+	c1.elem.val[0].bbv.val = BigUint::from(0x123456 as u32);
+	let code = c1.encode_asn1_preserve().unwrap();
+	assert!(code == vec![0x30,0x13,
+		0x06,0x81,0x03,0x2f,0x81,0x5e,
+		0x02,0x03,0x12,0x34,0x56,
+		0x13,0x81,0x05,0x68,0x65,0x6c,0x6c,0x6f]);
+	assert!(c1.encode_asn1().unwrap()[1] == 0x11);
+
+	let mut a1 :BBTestauto = BBTestauto::init_asn1();
+	let val = serde_json::json!({
+		"seltype" : "1.2.5",
+		"ddv" : {"printablestring" : "hi"}
+	});
+	let _ = a1.decode_json("",&val).unwrap();
+	assert!(a1.get_raw_asn1().is_none());
+	let code = a1.encode_asn1().unwrap();
+	let mut a2 :BBTestauto = BBTestauto::init_asn1();
+	let _ = a2.decode_asn1(&code).unwrap();
+	assert!(a2.get_raw_asn1().unwrap() == code);
+
+	let mut i1 :IntTestauto = IntTestauto::init_asn1();
+	let _ = i1.decode_asn1(&ber[13..]).unwrap();
+	assert!(i1.get_raw_asn1().unwrap() == ber[13..].to_vec());
+
+	let mut o1 :Asn1OctData = Asn1OctData::init_asn1();
+	let seg :Vec<u8> = vec![0x24,0x80,0x04,0x01,0x41,0x04,0x01,0x42,0x00,0x00];
+	let _ = o1.decode_asn1(&seg).unwrap();
+	assert!(o1.data == vec![0x41,0x42]);
+	assert!(o1.get_raw_asn1().unwrap() == seg);
+	assert!(o1.encode_asn1_preserve().unwrap() == seg);
+	o1.data.push(0x43);
+	assert!(o1.encode_asn1_preserve().unwrap() == vec![0x04,0x03,0x41,0x42,0x43]);
+
+	let mut p1 :Asn1Opt<Asn1Integer> = Asn1Opt::init_asn1();
+	let _ = p1.decode_asn1(&ber[0..2]).unwrap();
+	assert!(p1.get_raw_asn1().unwrap().len() == 0);
+	assert!(p1.encode_asn1_preserve().unwrap().len() == 0);
+}
+
+#[test]
+fn test_a081() {
+	let ber :Vec<u8> = vec![0x30,0x81,0x0a,
+		0xa1,0x81,0x07,0x02,0x01,0x05,0x02,0x81,0x01,0x07];
+	let mut s1 :Asn1Seq<Asn1Exp<Asn1Seq<Asn1Integer>,1>> = Asn1Seq::init_asn1();
+	let c = s1.decode_asn1(&ber).unwrap();
+	assert!(c == ber.len());
+	{
+		/*the DER decode context at encode does not drop the BER octets*/
+		let _guard = Asn1DecodeCtxGuard::new(&Asn1DecodeCtx::der());
+		assert!(s1.encode_asn1_preserve().unwrap() == ber);
+		s1.val[0].val.val[0].val = 6;
+		let code = s1.encode_asn1_preserve().unwrap();
+		assert!(code == vec![0x30,0x09,
+			0xa1,0x07,0x02,0x01,0x06,0x02,0x81,0x01,0x07]);
+		assert!(asn1obj_is_der());
+	}
+
+	let inf :Vec<u8> = vec![0x30,0x80,0x02,0x01,0x05,0x00,0x00];
+	let mut s2 :Asn1Seq<Asn1Integer> = Asn1Seq::init_asn1();
+	let _ = s2.decode_asn1(&inf).unwrap();
+	assert!(s2.encode_asn1_preserve().unwrap() == inf);
+	s2.val.push(Asn1Integer::init_asn1());
+	assert!(s2.encode_asn1_preserve().unwrap() == vec![0x30,0x06,0x02,0x01,0x05,0x02,0x01,0x00]);
+}
+
+#[test]
+fn test_a082() {
+	let serial :Vec<u8> = vec![0x02,0x11,0x00,0x8f,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08,0x09,0x0a,0x0b,0x0c,0x0d,0x0e,0x0f];
+	let mut a1 :Asn1BigInteger = Asn1BigInteger::init_asn1();
+	let c = a1.decode_asn1_der(&serial).unwrap();
+	assert!(c == serial.len());
+	assert!(a1.val == BigInt::from_str_radix("8f0102030405060708090a0b0c0d0e0f",16).unwrap());
+	assert!(a1.get_value::<i64>().is_err());
+	assert!(a1.encode_asn1().unwrap() == serial);
+
+	let mut cv = serde_json::json!({});
+	let _ = a1.encode_json("serial",&mut cv).unwrap();
+	assert!(cv["serial"] == serde_json::json!("190084836497849212631295365015820635663"));
+	let mut a2 :Asn1BigInteger = Asn1BigInteger::init_asn1();
+	let _ = a2.decode_json("serial",&cv).unwrap();
+	assert!(a2.equal_asn1(&a1));
+
+	let mut w :Vec<u8> = Vec::new();
+	a1.print_asn1("serial",0,&mut w).unwrap();
+	let s = String::from_utf8(w).unwrap();
+	assert!(s.contains("serial: ASN1_INTEGER"));
+	assert!(s.contains("8f:01:02:03:04:05:06:07:08:09:0a:0b:0c:0d:0e:0f"));
+
+	let checks :Vec<(i64,Vec<u8>)> = vec![(0,vec![0x02,0x01,0x00]),
+		(127,vec![0x02,0x01,0x7f]),
+		(128,vec![0x02,0x02,0x00,0x80]),
+		(-128,vec![0x02,0x01,0x80]),
+		(-129,vec![0x02,0x02,0xff,0x7f]),
+		(i64::MIN,vec![0x02,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00])];
+	for (v,code) in checks.iter() {
+		let a3 :Asn1BigInteger = Asn1BigInteger::from(*v);
+		assert!(a3.encode_asn1().unwrap() == *code);
+		let mut a4 :Asn1BigInteger = Asn1BigInteger::init_asn1();
+		let _ = a4.decode_asn1(code).unwrap();
+		assert!(i64::try_from(&a4).unwrap() == *v);
+		let mut cv = serde_json::json!({});
+		let _ = a4.encode_json("",&mut cv).unwrap();
+		assert!(cv == serde_json::json!(*v));
+	}
+
+	let mut a5 :Asn1BigInteger = Asn1BigInteger::from(u64::MAX);
+	assert!(a5.get_value::<u64>().unwrap() == u64::MAX);
+	assert!(u32::try_from(&a5).is_err());
+	let old = a5.set_value(-5i32);
+	assert!(old == BigInt::from(u64::MAX));
+	assert!(a5.get_value::<i8>().unwrap() == -5);
+	assert!(a5.get_value::<u8>().is_err());
+	let _ = a5.decode_json("",&serde_json::json!("-0x10")).unwrap();
+	assert!(a5.get_value::<i32>().unwrap() == -16);
+	assert!(a5.decode_json("",&serde_json::json!("12z")).is_err());
+
+	let mut a6 :Asn1BigInteger = Asn1BigInteger::init_asn1();
+	assert!(a6.decode_asn1_der(&[0x02,0x02,0x00,0x7f]).is_err());
+	assert!(a6.decode_asn1(&[0x02,0x02,0x00,0x7f]).is_ok());
+	assert!(a6.get_value::<u8>().unwrap() == 0x7f);
+}
+
+#[test]
+fn test_a083() {
+	let code :Vec<u8> = vec![0x02,0x09,0xf4,0x01,0x02,0x03,0x04,0x05,0x06,0x07,0x08];
+	let mut a1 :Asn1BigNum = Asn1BigNum::init_asn1();
+	let _ = a1.decode_asn1(&code).unwrap();
+	assert!(a1.is_negative());
+	assert!(a1.to_bigint() == BigInt::from_signed_bytes_be(&code[2..]));
+	assert!(a1.encode_asn1().unwrap() == code);
+	let mut cv = serde_json::json!({});
+	let _ = a1.encode_json("",&mut cv).unwrap();
+	assert!(cv == serde_json::json!("-bfefdfcfbfaf9f8f8"));
+	let mut a2 :Asn1BigNum = Asn1BigNum::init_asn1();
+	let _ = a2.decode_json("",&cv).unwrap();
+	assert!(a2.is_negative());
+	assert!(a2.encode_asn1().unwrap() == code);
+	let mut w :Vec<u8> = Vec::new();
+	a2.print_asn1("serial",0,&mut w).unwrap();
+	assert!(String::from_utf8(w).unwrap().contains("serial: ASN1_BIGNUM (Negative)"));
+
+	let _ = a2.set_bigint(&BigInt::from(-129));
+	assert!(a2.encode_asn1().unwrap() == vec![0x02,0x02,0xff,0x7f]);
+	let _ = a2.set_value(&[0x80]);
+	assert!(!a2.is_negative());
+	assert!(a2.encode_asn1().unwrap() == vec![0x02,0x02,0x00,0x80]);
+
+	/*non-minimal forms from lenient decode are given back only in preserve encode*/
+	let lenient :Vec<(Vec<u8>,Vec<u8>)> = vec![(vec![0x02,0x03,0x00,0x00,0x7f],vec![0x02,0x01,0x7f]),(vec![0x02,0x03,0xff,0xff,0x80],vec![0x02,0x01,0x80])];
+	for (c,m) in lenient.iter() {
+		let mut a3 :Asn1BigNum = Asn1BigNum::init_asn1();
+		assert!(a3.decode_asn1_der(c).is_err());
+		let _ = a3.decode_asn1(c).unwrap();
+		assert!(a3.encode_asn1().unwrap() == *m);
+		assert!(a3.encode_asn1_cer().unwrap() == *m);
+		assert!(a3.encode_asn1_ctx(&Asn1EncodeCtx::preserve()).unwrap() == *c);
+		a3.val = a3.val.clone() + BigUint::from(1 as u32);
+		let ec = a3.encode_asn1_ctx(&Asn1EncodeCtx::preserve()).unwrap();
+		assert!(ec.len() < c.len());
+	}
+}
+
+#[test]
+fn test_a084() {
+	let checks :Vec<(f64,Vec<u8>)> = vec![(1.0,vec![0x09,0x03,0x80,0x00,0x01]),
+		(0.5,vec![0x09,0x03,0x80,0xff,0x01]),
+		(-2.5,vec![0x09,0x03,0xc0,0xff,0x05]),
+		(0.0,vec![0x09,0x00]),
+		(-0.0,vec![0x09,0x01,0x43]),
+		(f64::INFINITY,vec![0x09,0x01,0x40]),
+		(f64::NEG_INFINITY,vec![0x09,0x01,0x41]),
+		(f64::MAX,vec![0x09,0x0a,0x81,0x03,0xcb,0x1f,0xff,0xff,0xff,0xff,0xff,0xff]),
+		(5e-324,vec![0x09,0x04,0x81,0xfb,0xce,0x01])];
+	for (v,code) in checks.iter() {
+		let mut a1 :Asn1Real = Asn1Real::init_asn1();
+		let _ = a1.set_value(*v);
+		assert!(a1.encode_asn1().unwrap() == *code);
+		let mut a2 :Asn1Real = Asn1Real::init_asn1();
+		let c = a2.decode_asn1_der(code).unwrap();
+		assert!(c == code.len());
+		assert!(a2.val.to_bits() == v.to_bits());
+	}
+
+	let mut a1 :Asn1Real = Asn1Real::init_asn1();
+	let _ = a1.decode_asn1(&[0x09,0x01,0x42]).unwrap();
+	assert!(a1.val.is_nan());
+	let mut cv = serde_json::json!({});
+	let _ = a1.encode_json("r",&mut cv).unwrap();
+	assert!(cv["r"] == serde_json::json!(ASN1_REAL_NOT_A_NUMBER));
+	let mut a2 :Asn1Real = Asn1Real::init_asn1();
+	let _ = a2.decode_json("r",&cv).unwrap();
+	assert!(a2.val.is_nan());
+	assert!(a2.encode_asn1().unwrap() == vec![0x09,0x01,0x42]);
+
+	/*base 16 is decoded, and encoded again in DER base 2*/
+	let code :Vec<u8> = vec![0x09,0x03,0xa0,0x01,0x01];
+	assert!(a1.decode_asn1_der(&code).is_err());
+	let _ = a1.decode_asn1(&code).unwrap();
+	assert!(a1.val == 16.0);
+	assert!(a1.encode_asn1().unwrap() == vec![0x09,0x03,0x80,0x04,0x01]);
+
+	let code :Vec<u8> = vec![0x09,0x07,0x03,0x31,0x2e,0x35,0x45,0x2d,0x33];
+	let _ = a1.decode_asn1(&code).unwrap();
+	assert!(a1.val == 0.0015);
+	assert!(a1.form == ASN1_REAL_NR3);
+	assert!(a1.decimal == "1.5E-3");
+	assert!(a1.encode_asn1().unwrap() == code);
+	let mut cv = serde_json::json!({});
+	let _ = a1.encode_json("",&mut cv).unwrap();
+	assert!(cv == serde_json::json!("1.5E-3"));
+	let mut w :Vec<u8> = Vec::new();
+	a1.print_asn1("r",0,&mut w).unwrap();
+	assert!(String::from_utf8(w).unwrap() == "r: ASN1_REAL 0.0015 NR3 \"1.5E-3\"\n");
+	a1.val = 0.25;
+	assert!(a1.encode_asn1().unwrap() == vec![0x09,0x03,0x80,0xfe,0x01]);
+
+	let _ = a1.decode_json("",&serde_json::json!("3,14")).unwrap();
+	assert!(a1.form == ASN1_REAL_NR2);
+	assert!(a1.val == 3.14);
+	assert!(a1.encode_asn1().unwrap() == vec![0x09,0x05,0x02,0x33,0x2c,0x31,0x34]);
+	let _ = a1.decode_json("",&serde_json::json!(-7.75)).unwrap();
+	assert!(a1.form == ASN1_REAL_BINARY);
+	assert!(a1.encode_asn1().unwrap() == vec![0x09,0x03,0xc0,0xfe,0x1f]);
+	assert!(a1.decode_json("",&serde_json::json!("1.5x")).is_err());
+	assert!(a1.decode_asn1(&[0x09,0x02,0x40,0x00]).is_err());
+	assert!(a1.decode_asn1(&[0x09,0x03,0x04,0x31,0x32]).is_err());
+	/*base 16 with the 8 octets exponent out of i64 after scaling*/
+	let _ = a1.decode_asn1(&[0x09,0x0b,0xa3,0x08,0x7f,0xff,0xff,0xff,0xff,0xff,0xff,0xff,0x01]).unwrap();
+	assert!(a1.val == f64::INFINITY);
+	let _ = a1.decode_asn1(&[0x09,0x0b,0xa3,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x01]).unwrap();
+	assert!(a1.val == 0.0);
+}
+
+#[test]
+fn test_a085() {
+	let code :Vec<u8> = vec![0x0d,0x04,0xc2,0x7b,0x03,0x02];
+	let mut r1 :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+	let c = r1.decode_asn1(&code).unwrap();
+	assert!(c == code.len());
+	assert!(r1.get_value() == "8571.3.2");
+	assert!(r1.encode_asn1().unwrap() == code);
+	assert!(r1.set_value("1.2.").is_err());
+	assert!(r1.set_value("").is_err());
+	assert!(r1.set_value(".1").is_err());
+	assert!(r1.set_value("1..2").is_err());
+	assert!(r1.set_value("1.a").is_err());
+	assert!(r1.get_value() == "8571.3.2");
+	let mut r4 :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+	assert!(r4.set_value("7").is_ok());
+	assert!(r1.decode_asn1(&[0x0d,0x00]).is_err());
+	assert!(r1.decode_asn1(&[0x0d,0x01,0x81]).is_err());
+
+	let mut o1 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o1.set_value("1.3.6.1.4.1").unwrap();
+	let o2 = o1.join_relative(&r1).unwrap();
+	assert!(o2.get_value() == "1.3.6.1.4.1.8571.3.2");
+	let r2 = o2.relative_to(&o1).unwrap();
+	assert!(r2 == r1);
+	assert!(o1.relative_to(&o2).is_err());
+	let _ = o1.set_value("1.3.6.1.4").unwrap();
+	assert!(o2.relative_to(&o1).unwrap().get_value() == "1.8571.3.2");
+	let _ = o1.set_value("1.3.6.1.41").unwrap();
+	assert!(o2.relative_to(&o1).is_err());
+
+	/*the relative content is the tail of the absolute content*/
+	let ocode = o2.encode_asn1().unwrap();
+	assert!(ocode[ocode.len()-4..] == code[2..]);
+
+	let mut cv = serde_json::json!({});
+	let _ = r1.encode_json("rel",&mut cv).unwrap();
+	assert!(cv["rel"] == serde_json::json!("8571.3.2"));
+	let mut r3 :Asn1RelativeOid = Asn1RelativeOid::init_asn1();
+	let _ = r3.decode_json("rel",&cv).unwrap();
+	assert!(r3 == r1);
+
+	let mut i1 :Asn1OidIri = Asn1OidIri::init_asn1();
+	let _ = i1.set_value("/ISO/Registration-Authority").unwrap();
+	let mut i2 :Asn1RelativeOidIri = Asn1RelativeOidIri::init_asn1();
+	let _ = i2.set_value("19785.CBEFF/Organizations").unwrap();
+	let i3 = i1.join_relative(&i2).unwrap();
+	assert!(i3.get_value() == "/ISO/Registration-Authority/19785.CBEFF/Organizations");
+	let code = i1.encode_asn1().unwrap();
+	assert!(code[0..3] == [0x1f,0x23,0x1b]);
+	let mut i4 :Asn1OidIri = Asn1OidIri::init_asn1();
+	let _ = i4.decode_asn1(&code).unwrap();
+	assert!(i4.get_value() == i1.get_value());
+	let code = i2.encode_asn1().unwrap();
+	assert!(code[0..3] == [0x1f,0x24,0x19]);
+	assert!(i4.decode_asn1(&code).is_err());
+	let mut i5 :Asn1RelativeOidIri = Asn1RelativeOidIri::init_asn1();
+	let _ = i5.decode_asn1(&code).unwrap();
+	assert!(i5.get_value() == "19785.CBEFF/Organizations");
+	assert!(i1.set_value("ISO").is_err());
+	assert!(i1.set_value("/ISO//A").is_err());
+	assert!(i5.set_value("/ISO").is_err());
+
+	let mut w :Vec<u8> = Vec::new();
+	let mut all :Vec<u8> = vec![0x30,0x00];
+	all.extend_from_slice(&r1.encode_asn1().unwrap());
+	all.extend_from_slice(&i1.encode_asn1().unwrap());
+	all[1] = (all.len() - 2) as u8;
+	asn1obj_dump(&all,&mut w).unwrap();
+	let s = String::from_utf8(w).unwrap();
+	assert!(s.contains("prim:  RELATIVE OID      :8571.3.2"));
+	assert!(s.contains("prim:  OID-IRI           :/ISO/Registration-Authority"));
+}
+
+#[test]
+fn test_a086() {
+	let mut n1 :Asn1NumericString = Asn1NumericString::init_asn1();
+	let _ = n1.set_value("123 456").unwrap();
+	let code = n1.encode_asn1().unwrap();
+	assert!(code == vec![0x12,0x07,0x31,0x32,0x33,0x20,0x34,0x35,0x36]);
+	let mut n2 :Asn1NumericString = Asn1NumericString::init_asn1();
+	let _ = n2.decode_asn1(&code).unwrap();
+	assert!(n2.get_value() == "123 456");
+	assert!(n2.set_value("12a").is_err());
+	let e = n2.decode_asn1(&[0x12,0x03,0x31,0x41,0x32]).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::ConstraintViolation);
+	assert!(ae.offset() == 3);
+	n2.val = "x".to_string();
+	assert!(n2.encode_asn1().is_err());
+
+	let mut v1 :Asn1VisibleString = Asn1VisibleString::init_asn1();
+	let _ = v1.set_value("Hello ~").unwrap();
+	assert!(v1.set_value("a\tb").is_err());
+	let code = v1.encode_asn1().unwrap();
+	assert!(code[0] == 0x1a);
+	assert!(Asn1NumericString::init_asn1().decode_asn1(&code).is_err());
+
+	let mut t1 :Asn1TeletexString = Asn1TeletexString::init_asn1();
+	let _ = t1.decode_asn1(&[0x14,0x03,0x41,0xe9,0x0d]).unwrap();
+	assert!(t1.get_value() == "A\u{e9}\r");
+	assert!(t1.encode_asn1().unwrap() == vec![0x14,0x03,0x41,0xe9,0x0d]);
+	assert!(t1.decode_asn1(&[0x14,0x01,0x01]).is_err());
+	assert!(t1.set_value("\u{20ac}").is_err());
+	let mut x1 :Asn1VideotexString = Asn1VideotexString::init_asn1();
+	let _ = x1.set_value("\x1b(B").unwrap();
+	assert!(x1.encode_asn1().unwrap()[0] == 0x15);
+
+	let mut g1 :Asn1GraphicString = Asn1GraphicString::init_asn1();
+	assert!(g1.decode_asn1(&[0x19,0x02,0x41,0x0a]).is_err());
+	assert!(g1.decode_asn1(&[0x19,0x02,0x41,0x85]).is_err());
+	let _ = g1.decode_asn1(&[0x19,0x02,0x41,0xa9]).unwrap();
+	assert!(g1.get_value() == "A\u{a9}");
+	let mut e1 :Asn1GeneralString = Asn1GeneralString::init_asn1();
+	let _ = e1.decode_asn1(&[0x1b,0x02,0x00,0x85]).unwrap();
+	assert!(e1.get_value() == "\u{0}\u{85}");
+
+	let mut u1 :Asn1UniversalString = Asn1UniversalString::init_asn1();
+	let _ = u1.set_value("A\u{1f600}").unwrap();
+	let code = u1.encode_asn1().unwrap();
+	assert!(code == vec![0x1c,0x08,0x00,0x00,0x00,0x41,0x00,0x01,0xf6,0x00]);
+	let mut u2 :Asn1UniversalString = Asn1UniversalString::init_asn1();
+	let _ = u2.decode_asn1(&code).unwrap();
+	assert!(u2.get_value() == "A\u{1f600}");
+	assert!(u2.decode_asn1(&[0x1c,0x03,0x00,0x00,0x41]).is_err());
+	let e = u2.decode_asn1(&[0x1c,0x08,0x00,0x00,0x00,0x41,0x00,0x00,0xd8,0x00]).err().unwrap();
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().offset() == 6);
+
+	/*constructed BER form*/
+	let mut n3 :Asn1NumericString = Asn1NumericString::init_asn1();
+	let _ = n3.decode_asn1(&[0x32,0x80,0x04,0x01,0x31,0x04,0x01,0x32,0x00,0x00]).unwrap();
+	assert!(n3.get_value() == "12");
+	let mut n5 :Asn1VisibleString = Asn1VisibleString::init_asn1();
+	let _ = n5.set_value(&"b".repeat(1001)).unwrap();
+	let mut v1 :Vec<u8> = vec![0x3a,0x80,0x04,0x82,0x03,0xe8];
+	v1.extend_from_slice(&[0x62;1000]);
+	v1.extend_from_slice(&[0x04,0x01,0x62,0x00,0x00]);
+	let code = n5.encode_asn1_cer().unwrap();
+	assert!(check_equal_u8(&code,&v1));
+	let mut n6 :Asn1VisibleString = Asn1VisibleString::init_asn1();
+	let c = n6.decode_asn1(&code).unwrap();
+	assert!(c == v1.len());
+	assert!(n6.get_value() == n5.get_value());
+	assert!(n6.decode_asn1(&[0x3a,0x80,0x1a,0x01,0x31,0x00,0x00]).is_err());
+
+	let mut cv = serde_json::json!({});
+	let _ = u1.encode_json("u",&mut cv).unwrap();
+	assert!(cv["u"] == serde_json::json!("A\u{1f600}"));
+	let _ = n3.encode_json("n",&mut cv).unwrap();
+	let mut n4 :Asn1NumericString = Asn1NumericString::init_asn1();
+	let _ = n4.decode_json("n",&cv).unwrap();
+	assert!(n4.get_value() == "12");
+	assert!(n4.decode_json("u",&cv).is_err());
+
+	let mut w :Vec<u8> = Vec::new();
+	n1.print_asn1("n",0,&mut w).unwrap();
+	let s = String::from_utf8(w).unwrap();
+	assert!(s.contains("n: ASN1_NUMERICSTRING 123 456"));
+}
+
+#[test]
+fn test_a087() {
+	let code :Vec<u8> = vec![0x0c,0x04,0x41,0xff,0x42,0x43];
+	let mut s1 :Asn1String = Asn1String::init_asn1();
+	let _ = s1.decode_asn1(&code).unwrap();
+	assert!(s1.val == "A\u{fffd}BC");
+	assert!(s1.get_bytes() == vec![0x41,0xff,0x42,0x43]);
+	let e = s1.decode_asn1_ctx(&code,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::InvalidValue);
+	assert!(ae.offset() == 3);
+	assert!(s1.get_bytes() == vec![0x41,0xff,0x42,0x43]);
+	assert!(s1.get_raw_asn1().unwrap() == code);
+	let _ = s1.decode_asn1_ctx(&[0x0c,0x02,0xc3,0xa9],&Asn1DecodeCtx::strict_string()).unwrap();
+	assert!(s1.val == "\u{e9}");
+	s1.val = "xy".to_string();
+	assert!(s1.get_bytes() == b"xy".to_vec());
+
+	let code :Vec<u8> = vec![0x13,0x03,0x41,0x2a,0x42];
+	let mut p1 :Asn1PrintableString = Asn1PrintableString::init_asn1();
+	let _ = p1.decode_asn1(&code).unwrap();
+	assert!(p1.val == "A*B");
+	let e = p1.decode_asn1_ctx(&code,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::ConstraintViolation);
+	assert!(ae.offset() == 3);
+	assert!(p1.get_bytes() == vec![0x41,0x2a,0x42]);
+	let _ = p1.decode_asn1_ctx(&[0x13,0x05,0x41,0x27,0x3d,0x3f,0x20],&Asn1DecodeCtx::strict_string()).unwrap();
+	assert!(p1.val == "A'=? ");
+	let _ = p1.decode_asn1_ctx(&[0x14,0x02,0x41,0xe9],&Asn1DecodeCtx::strict_string()).unwrap();
+	assert!(p1.val == "A\u{e9}");
+	assert!(p1.get_bytes() == vec![0x41,0xe9]);
+
+	let code :Vec<u8> = vec![0x16,0x03,0x61,0x40,0x80];
+	let mut i1 :Asn1IA5String = Asn1IA5String::init_asn1();
+	let _ = i1.decode_asn1(&code).unwrap();
+	let e = i1.decode_asn1_ctx(&code,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().offset() == 4);
+	assert!(i1.get_bytes() == vec![0x61,0x40,0x80]);
+	let _ = i1.decode_asn1_ctx(&[0x16,0x02,0x61,0x40],&Asn1DecodeCtx::strict_string()).unwrap();
+	assert!(i1.val == "a@");
+
+	/*the error in the constructed form is at the constructed element*/
+	let code :Vec<u8> = vec![0x36,0x80,0x04,0x01,0x61,0x04,0x01,0x80,0x00,0x00];
+	let _ = i1.decode_asn1(&code).unwrap();
+	let e = i1.decode_asn1_ctx(&code,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::ConstraintViolation,0);
+	let code :Vec<u8> = vec![0x2c,0x80,0x04,0x02,0x41,0xff,0x00,0x00];
+	let e = s1.decode_asn1_ctx(&code,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	check_asn1_error(&e,Asn1ErrorKind::InvalidValue,0);
+
+	/*the rejected bytes are given back in preserve encode*/
+	let mut s2 :Asn1String = Asn1String::init_asn1();
+	let _ = s2.decode_asn1(&[0x0c,0x02,0x41,0xff]).unwrap();
+	assert!(s2.encode_asn1_preserve().unwrap() == vec![0x0c,0x02,0x41,0xff]);
+}
+
+#[test]
+fn test_a088() {
+	/*U+1F600 as surrogate pair*/
+	let pair :Vec<u8> = vec![0x1e,0x06,0x00,0x41,0xd8,0x3d,0xde,0x00];
+	let mut a1 :Asn1BMPString = Asn1BMPString::init_asn1();
+	let _ = a1.decode_asn1(&pair).unwrap();
+	assert!(a1.val == "A\u{1f600}");
+	assert!(a1.encode_asn1().unwrap() == pair);
+	let e = a1.decode_asn1_ctx(&pair,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	let ae = e.downcast_ref::<Asn1Error>().unwrap();
+	assert!(ae.kind() == Asn1ErrorKind::ConstraintViolation);
+	assert!(ae.offset() == 4);
+
+	/*lone high surrogate*/
+	let lone :Vec<u8> = vec![0x1e,0x04,0xd8,0x3d,0x00,0x42];
+	let _ = a1.decode_asn1(&lone).unwrap();
+	assert!(a1.val == "\u{fffd}B");
+	assert!(a1.encode_asn1().unwrap() == vec![0x1e,0x04,0xff,0xfd,0x00,0x42]);
+	let _ = a1.decode_asn1_ctx(&lone,&Asn1DecodeCtx::lossless_string()).unwrap();
+	assert!(a1.val == "\u{fffd}B");
+	assert!(a1.get_units() == (vec![0xd83d,0x0042],None));
+	assert!(a1.encode_asn1().unwrap() == lone);
+	assert!(a1.decode_asn1_ctx(&lone,&Asn1DecodeCtx::strict_string()).is_err());
+
+	/*odd length*/
+	let odd :Vec<u8> = vec![0x1e,0x03,0x00,0x43,0x44];
+	assert!(a1.decode_asn1(&odd).is_err());
+	let e = a1.decode_asn1_ctx(&odd,&Asn1DecodeCtx::strict_string()).err().unwrap();
+	assert!(e.downcast_ref::<Asn1Error>().unwrap().kind() == Asn1ErrorKind::InvalidLength);
+	let _ = a1.decode_asn1_ctx(&odd,&Asn1DecodeCtx::lossless_string()).unwrap();
+	assert!(a1.val == "C\u{fffd}");
+	assert!(a1.get_units() == (vec![0x0043],Some(0x44)));
+	assert!(a1.encode_asn1().unwrap() == odd);
+
+	/*the kept units are dropped once val is changed*/
+	a1.val = "C".to_string();
+	assert!(a1.encode_asn1().unwrap() == vec![0x1e,0x02,0x00,0x43]);
+	let mut a2 :Asn1BMPString = Asn1BMPString::init_asn1();
+	a2.set_units(&[0xdc00,0x0041],None);
+	assert!(a2.val == "\u{fffd}A");
+	assert!(a2.encode_asn1().unwrap() == vec![0x1e,0x04,0xdc,0x00,0x00,0x41]);
+	let _ = a2.set_value("x");
+	assert!(a2.get_units() == (vec![0x0078],None));
+}
+
+#[test]
+fn test_a089() {
+	assert!(asn1obj_oid_to_name("1.2.840.113549.1.1.11").unwrap() == "sha256WithRSAEncryption");
+	assert!(asn1obj_name_to_oid("sha256").unwrap() == "2.16.840.1.101.3.4.2.1");
+	assert!(asn1obj_name_to_oid("SPC_INDIRECT_DATA").unwrap() == "1.3.6.1.4.1.311.2.1.4");
+	assert!(asn1obj_name_to_oid("noSuchName").is_none());
+
+	let mut o1 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o1.set_name("sha256WithRSAEncryption").unwrap();
+	assert!(o1.get_value() == "1.2.840.113549.1.1.11");
+	assert!(o1.set_name("noSuchName").is_err());
+
+	let mut w :Vec<u8> = Vec::new();
+	o1.print_asn1("alg",0,&mut w).unwrap();
+	assert!(String::from_utf8(w).unwrap().contains("alg: ASN1_OBJECT 1.2.840.113549.1.1.11"));
+	let mut cv = serde_json::json!({});
+	let _ = o1.encode_json("alg",&mut cv).unwrap();
+	assert!(cv["alg"] == serde_json::json!("1.2.840.113549.1.1.11"));
+
+	let oldctx = asn1obj_set_encode_ctx(&Asn1EncodeCtx::oid_names());
+	let mut w :Vec<u8> = Vec::new();
+	o1.print_asn1("alg",0,&mut w).unwrap();
+	let s = String::from_utf8(w).unwrap();
+	let mut cv = serde_json::json!({});
+	let _ = o1.encode_json("alg",&mut cv).unwrap();
+	let code = o1.encode_asn1().unwrap();
+	let _ = asn1obj_set_encode_ctx(&oldctx);
+	assert!(s.contains("alg: ASN1_OBJECT sha256WithRSAEncryption (1.2.840.113549.1.1.11)"));
+	assert!(cv["alg"] == serde_json::json!("sha256WithRSAEncryption (1.2.840.113549.1.1.11)"));
+	assert!(code[0..3] == [0x06,0x09,0x2a]);
+
+	let mut o2 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o2.decode_json("alg",&cv).unwrap();
+	assert!(o2.get_value() == "1.2.840.113549.1.1.11");
+	let _ = o2.decode_json("",&serde_json::json!("ecdsa-with-SHA256")).unwrap();
+	assert!(o2.get_value() == "1.2.840.10045.4.3.2");
+	assert!(o2.decode_json("",&serde_json::json!("noSuchName")).is_err());
+
+	/*runtime extension, the old mapping of the oid is replaced*/
+	asn1obj_register_oid("testA080Oid","1.3.6.1.4.1.99999.80").unwrap();
+	assert!(asn1obj_oid_to_name("1.3.6.1.4.1.99999.80").unwrap() == "testA080Oid");
+	asn1obj_register_oid("testA080Oid2","1.3.6.1.4.1.99999.80").unwrap();
+	assert!(asn1obj_name_to_oid("testA080Oid").is_none());
+	let _ = o2.set_name("testA080Oid2").unwrap();
+	assert!(o2.get_name().unwrap() == "testA080Oid2");
+	assert!(asn1obj_register_oid("bad name","1.2.3").is_err());
+	assert!(asn1obj_register_oid("1.2","1.2.3").is_err());
+	assert!(asn1obj_register_oid("testA080Bad","3.1").is_err());
+}
+
+const TEST_A090_RSADSI :Asn1StaticOid = oid!("1.2.840.113549");
+const TEST_A090_SHA256 :Asn1StaticOid = oid!("2.16.840.1.101.3.4.2.1");
+
+/*oid! does not need Asn1StaticOid in scope*/
+mod test_a090_scope {
+	use asn1obj_codegen::{oid};
+
+	pub fn rsadsi_len() -> usize {
+		return oid!("1.2.840.113549").get_content().len();
+	}
+}
+
+#[test]
+fn test_a090() {
+	assert!(test_a090_scope::rsadsi_len() == 6);
+	assert!(TEST_A090_RSADSI.get_value() == "1.2.840.113549");
+	assert!(TEST_A090_RSADSI.get_content() == &[0x2a,0x86,0x48,0x86,0xf7,0x0d]);
+	let o1 :Asn1Object = TEST_A090_SHA256.into();
+	let code = o1.encode_asn1().unwrap();
+	assert!(code[2..] == *TEST_A090_SHA256.get_content());
+	assert!(o1 == TEST_A090_SHA256);
+	assert!(TEST_A090_SHA256 == o1);
+	assert!(o1 != TEST_A090_RSADSI);
+	assert!(o1.eq_encoded(&code));
+	assert!(!o1.eq_encoded(&code[0..code.len()-1]));
+	assert!(!o1.eq_content(&code[2..code.len()-1]));
+	assert!(!o1.eq_content(&[0x60,0x80,0x86,0x48]));
+	/*the first subidentifier 2*40+u64::MAX is out of u64*/
+	let mut o4 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o4.decode_asn1(&[0x06,0x0a,0x82,0x80,0x80,0x80,0x80,0x80,0x80,0x80,0x80,0x4f]).unwrap();
+	assert!(o4.get_value() == "2.18446744073709551615");
+	assert!(!o4.eq_content(&[0x82,0x80,0x80,0x80,0x80,0x80,0x80,0x80,0x80,0x4f]));
+	assert!(!o4.eq_content(&[0x2a]));
+
+	let mut o2 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o2.set_value("1.2.840.113549.1.1.11").unwrap();
+	assert!(o2.get_arcs().unwrap() == vec![1,2,840,113549,1,1,11]);
+	assert!(o2.arc_count() == 7);
+	let rsadsi :Asn1Object = TEST_A090_RSADSI.to_object();
+	assert!(o2.starts_with(&rsadsi));
+	assert!(o2.starts_with(&o2));
+	assert!(!rsadsi.starts_with(&o2));
+	let mut o3 :Asn1Object = Asn1Object::init_asn1();
+	let _ = o3.set_value("1.2.84").unwrap();
+	assert!(!o3.starts_with(&rsadsi));
+	let _ = o3.set_value("1.2.8").unwrap();
+	assert!(!rsadsi.starts_with(&o3));
+
+	let p = o2.parent().unwrap();
+	assert!(p.get_value() == "1.2.840.113549.1.1");
+	assert!(p.is_parent_of(&o2));
+	assert!(o2.is_child_of(&p));
+	assert!(!rsadsi.is_parent_of(&o2));
+	assert!(p.child(11).get_value() == o2.get_value());
+	let _ = o3.set_value("1.2").unwrap();
+	assert!(o3.parent().is_none());
+	let _ = o3.set_arcs(&[2,5,29,19]).unwrap();
+	assert!(o3.get_value() == "2.5.29.19");
+	assert!(o3.set_arcs(&[3,1]).is_err());
+
+	/*the arc larger than u64*/
+	let _ = o3.set_value("2.25.340282366920938463463374607431768211455").unwrap();
+	assert!(o3.get_arcs().is_err());
+	let code = o3.encode_asn1().unwrap();
+	assert!(o3.eq_encoded(&code));
+	assert!(!o3.eq_encoded(&[0x06,0x01,0x69]));
+	assert!(o3.set_value("1.2.a").is_err());
+	assert!(o3.set_value("").is_err());
+}
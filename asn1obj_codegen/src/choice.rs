@@ -127,7 +127,7 @@ impl ChoiceSyn {
 		}
 
 		rets.push_str(&format_tab_line(tab + 1,""));
-		rets.push_str(&format_tab_line(tab + 1,&format!("retv += self.{}.decode_asn1(&code[retv.._endsize])?;",self.selname)));
+		rets.push_str(&format_tab_line(tab + 1,&format!("retv += self.{}.decode_asn1_field(&code[0.._endsize],retv,\"{}\",\"{}\")?;",self.selname,self.sname,self.selname)));
 		if self.debugenable {
 			rets.push_str(&format_tab_line(tab + 1, &format!("_outs = format!(\"decode {} retv [{{}}]\\n\",retv);",self.selname)));
 			rets.push_str(&format_tab_line(tab + 1, "let _ = _outf.write(_outs.as_bytes())?;"));
@@ -150,7 +150,7 @@ impl ChoiceSyn {
 				} else {
 					rets.push_str(&format_tab_line(tab + 1,&format!("}} else if k == \"{}\" {{", self.parsenames[idx])));
 				}
-				rets.push_str(&format_tab_line(tab + 2,&format!("retv += self.{}.decode_asn1(&code[retv.._endsize])?;", self.parsenames[idx])));
+				rets.push_str(&format_tab_line(tab + 2,&format!("retv += self.{}.decode_asn1_field(&code[0.._endsize],retv,\"{}\",\"{}\")?;", self.parsenames[idx],self.sname,self.parsenames[idx])));
 				if self.debugenable {
 					rets.push_str(&format_tab_line(tab + 2, &format!("_outs = format!(\"decode {} retv [{{}}]\\n\",retv);",self.parsenames[idx])));
 					rets.push_str(&format_tab_line(tab + 2, "let _ = _outf.write(_outs.as_bytes())?;"));
@@ -162,10 +162,10 @@ impl ChoiceSyn {
 
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab + 1,"} else {"));
-			rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{ {}, \"can not find [{{}}] selector\", k}}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not find [{{}}] selector\", k}}", self.errname)));
 			rets.push_str(&format_tab_line(tab + 1,"}"));
 		} else {
-			rets.push_str(&format_tab_line(tab + 1,&format!("asn1obj_new_error!{{ {}, \"can not find [{{}}] selector\", k}}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 1,&format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not find [{{}}] selector\", k}}", self.errname)));
 		}
 		rets.push_str(&format_tab_line(tab + 1,""));
 
@@ -213,10 +213,10 @@ impl ChoiceSyn {
 
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab + 1, "} else {"));
-			rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{ {}, \"can not support [{{}}]\", k }}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
 			rets.push_str(&format_tab_line(tab + 1, "}"));
 		} else {
-			rets.push_str(&format_tab_line(tab + 1, &format!("asn1obj_new_error!{{ {}, \"can not support [{{}}]\", k }}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
 		}
 
 		rets.push_str(&format_tab_line(tab + 1, "retv = Vec::new();"));
@@ -231,6 +231,127 @@ impl ChoiceSyn {
 		return rets;
 	}
 
+	fn format_get_raw_asn1(&self, tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		let mut sidx :usize;
+		let mut idx :usize;
+		rets.push_str(&format_tab_line(tab,"fn get_raw_asn1(&self) -> Option<Vec<u8>> {"));
+		rets.push_str(&format_tab_line(tab + 1,&format!("let ores = self.{}.get_raw_asn1();", self.selname)));
+		rets.push_str(&format_tab_line(tab + 1,"if ores.is_none() {"));
+		rets.push_str(&format_tab_line(tab + 2,"return None;"));
+		rets.push_str(&format_tab_line(tab + 1,"}"));
+		rets.push_str(&format_tab_line(tab + 1,"let mut retv :Vec<u8> = ores.unwrap();"));
+		rets.push_str(&format_tab_line(tab + 1 ,&format!("let kres = self.{}.encode_select();",self.selname)));
+		rets.push_str(&format_tab_line(tab + 1,"if kres.is_err() {"));
+		rets.push_str(&format_tab_line(tab + 2,"return None;"));
+		rets.push_str(&format_tab_line(tab + 1,"}"));
+		rets.push_str(&format_tab_line(tab + 1,"let k = kres.unwrap();"));
+		rets.push_str(&format_tab_line(tab + 1,"let ores :Option<Vec<u8>>;"));
+		sidx = 0;
+		idx = 0;
+		while idx < self.parsenames.len() {
+			if self.parsenames[idx] != self.selname {
+				if sidx == 0 {
+					rets.push_str(&format_tab_line(tab + 1, &format!("if k == \"{}\" {{", self.parsenames[idx])));
+				} else {
+					rets.push_str(&format_tab_line(tab + 1, &format!("}} else if k == \"{}\" {{", self.parsenames[idx])));
+				}
+				rets.push_str(&format_tab_line(tab + 2, &format!("ores = self.{}.get_raw_asn1();", self.parsenames[idx])));
+				sidx += 1;
+			}
+			idx += 1;
+		}
+		if sidx > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "} else {"));
+			rets.push_str(&format_tab_line(tab + 2, "ores = None;"));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let _ = k;"));
+			rets.push_str(&format_tab_line(tab + 1, "ores = None;"));
+		}
+		rets.push_str(&format_tab_line(tab + 1,"if ores.is_none() {"));
+		rets.push_str(&format_tab_line(tab + 2,"return None;"));
+		rets.push_str(&format_tab_line(tab + 1,"}"));
+		rets.push_str(&format_tab_line(tab + 1,"retv.extend_from_slice(&ores.unwrap());"));
+		rets.push_str(&format_tab_line(tab + 1,"Some(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encode_len_write(&self, tab :i32, fname :&str, fproto :&str, fcall :&str) -> String {
+		let mut rets :String = "".to_string();
+		let mut sidx :usize;
+		let mut idx :usize;
+		rets.push_str(&format_tab_line(tab,fproto));
+		rets.push_str(&format_tab_line(tab + 1,"let mut retv : usize;"));
+		rets.push_str(&format_tab_line(tab + 1,""));
+		rets.push_str(&format_tab_line(tab + 1,&format!("retv = self.{}.{}({})?;", self.selname,fname,fcall)));
+		rets.push_str(&format_tab_line(tab + 1 ,&format!("let k = self.{}.encode_select()?;",self.selname)));
+		sidx = 0;
+		idx = 0;
+		while idx < self.parsenames.len() {
+			if self.parsenames[idx] != self.selname {
+				if sidx == 0 {
+					rets.push_str(&format_tab_line(tab + 1, &format!("if k == \"{}\" {{", self.parsenames[idx])));
+				} else {
+					rets.push_str(&format_tab_line(tab + 1, &format!("}} else if k == \"{}\" {{", self.parsenames[idx])));
+				}
+				rets.push_str(&format_tab_line(tab + 2, &format!("retv += self.{}.{}({})?;", self.parsenames[idx],fname,fcall)));
+				sidx += 1;
+			}
+			idx += 1;
+		}
+
+		if sidx > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "} else {"));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
+		}
+
+		rets.push_str(&format_tab_line(tab + 1,""));
+		rets.push_str(&format_tab_line(tab + 1,"Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encoded_len(&self, tab :i32) -> String {
+		return self.format_encode_len_write(tab,"encoded_len","fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {","");
+	}
+
+	/*the identifier of the selector when it is encoded, otherwise the selected one*/
+	fn format_encoded_tag_len(&self, tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		let mut sidx :usize = 0;
+		rets.push_str(&format_tab_line(tab,"fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {"));
+		rets.push_str(&format_tab_line(tab + 1,&format!("if self.{}.encoded_len()? > 0 {{", self.selname)));
+		rets.push_str(&format_tab_line(tab + 2,&format!("return self.{}.encoded_tag_len();", self.selname)));
+		rets.push_str(&format_tab_line(tab + 1,"}"));
+		rets.push_str(&format_tab_line(tab + 1 ,&format!("let k = self.{}.encode_select()?;",self.selname)));
+		for k in self.parsenames.iter() {
+			if *k != self.selname {
+				if sidx == 0 {
+					rets.push_str(&format_tab_line(tab + 1, &format!("if k == \"{}\" {{", k)));
+				} else {
+					rets.push_str(&format_tab_line(tab + 1, &format!("}} else if k == \"{}\" {{", k)));
+				}
+				rets.push_str(&format_tab_line(tab + 2, &format!("return self.{}.encoded_tag_len();", k)));
+				sidx += 1;
+			}
+		}
+		if sidx > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+		}
+		rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_write_asn1(&self, tab :i32) -> String {
+		return self.format_encode_len_write(tab,"write_asn1","fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {","iowriter");
+	}
+
 	fn format_print_asn1(&self, tab :i32) -> String {
 		let mut rets :String = "".to_string();
 		let mut sidx :usize;
@@ -263,10 +384,10 @@ impl ChoiceSyn {
 		}
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab + 1, "} else {"));
-			rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{ {}, \"can not support [{{}}]\", k }}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
 			rets.push_str(&format_tab_line(tab + 1, "}"));
 		} else {
-			rets.push_str(&format_tab_line(tab + 1, &format!("asn1obj_new_error!{{ {}, \"can not support [{{}}]\", k }}", self.errname)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{ {},InvalidValue,0, \"can not support [{{}}]\", k }}", self.errname)));
 		}
 
 		rets.push_str(&format_tab_line(tab + 1,"Ok(())"));
@@ -332,10 +453,10 @@ impl ChoiceSyn {
 		}
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab +1 ,"} else {"));
-			rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{{},\"not support [{{}}]\",c}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support [{{}}]\",c}}",self.errname)));
 			rets.push_str(&format_tab_line(tab +1 ,"}"));
 		} else{
-			rets.push_str(&format_tab_line(tab + 1,&format!("asn1obj_new_error!{{{},\"not support [{{}}]\",c}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 1,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support [{{}}]\",c}}",self.errname)));
 		}
 
 		rets.push_str(&format_tab_line(tab + 1," "));
@@ -373,7 +494,7 @@ impl ChoiceSyn {
 		rets.push_str(&format_tab_line(tab + 1,"}"));
 		rets.push_str(&format_tab_line(tab + 1," "));
 		rets.push_str(&format_tab_line(tab + 1,"if !mainv.is_object() {"));
-		rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{{},\"not object to decode\"}}",self.errname)));
+		rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not object to decode\"}}",self.errname)));
 		rets.push_str(&format_tab_line(tab + 1,"}"));
 		rets.push_str(&format_tab_line(tab + 1," "));
 		let jsonk = self._get_json_alias(&self.selname);
@@ -413,10 +534,10 @@ impl ChoiceSyn {
 
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab +1 ,"} else {"));
-			rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{{},\"not support [{{}}]\",c}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support [{{}}]\",c}}",self.errname)));
 			rets.push_str(&format_tab_line(tab +1 ,"}"));
 		} else{
-			rets.push_str(&format_tab_line(tab + 1,&format!("asn1obj_new_error!{{{},\"not support [{{}}]\",c}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 1,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support [{{}}]\",c}}",self.errname)));
 		}
 
 		rets.push_str(&format_tab_line(tab + 1," "));
@@ -457,6 +578,14 @@ impl ChoiceSyn {
 		rets.push_str(&format_tab_line(1,""));		
 		rets.push_str(&self.format_encode_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_get_raw_asn1(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_encoded_len(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_encoded_tag_len(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_write_asn1(1));
+		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&self.format_print_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
 
@@ -603,11 +732,15 @@ impl IntChoiceSyn {
 
 	pub fn set_attr_name(&mut self, _k :&str, _v :&str) -> Result<(),Box<dyn Error>> {
 		let iv :i64;
-		if _k == "debug" && (_v == "enable" || _v == "disable") {
+		/*debug=N as the debug level, such as the debug=3 in the tests*/
+		if _k == "debug" {
 			if _v == "enable" {
 				self.debugenable = true;
-			} else {
+			} else if _v == "disable" {
 				self.debugenable = false;
+			} else {
+				iv = self.parse_value(_v)?;
+				self.debugenable = iv > 0;
 			}
 		}else if _k.eq("selector") {
 			self.seltypename = format!("{}",_v);
@@ -729,7 +862,7 @@ impl IntChoiceSyn {
 			rets.push_str(&format_tab_line(tab+1," "));
 		}
 
-		rets.push_str(&format_tab_line(tab + 1,&format!("asn1obj_new_error!{{{},\"not supported type\"}}",self.errname)));
+		rets.push_str(&format_tab_line(tab + 1,&format!("::asn1obj::asn1obj_new_kind_error!{{{},UnexpectedTag,0,\"not supported type\"}}",self.errname)));
 		rets.push_str(&format_tab_line(tab, "}"));
 		Ok(rets)
 	}
@@ -753,7 +886,7 @@ impl IntChoiceSyn {
 			asn1_gen_new_error!{ChoiceSynError,"no type insert"}
 		}
 		rets.push_str(&format_tab_line(tab + 1 ,"} else {"));
-		rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{{},\"not supported type {{}}\", self.{}}}",self.errname,self.seltypename)));
+		rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not supported type {{}}\", self.{}}}",self.errname,self.seltypename)));
 		rets.push_str(&format_tab_line(tab+1,"}"));
 		rets.push_str(&format_tab_line(tab+1," "));
 		rets.push_str(&format_tab_line(tab+1,"Ok(retv)"));
@@ -761,6 +894,58 @@ impl IntChoiceSyn {
 		Ok(rets)
 	}
 
+	fn format_get_raw_asn1(&self, tab :i32) -> Result<String,Box<dyn Error>> {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab,"fn get_raw_asn1(&self) -> Option<Vec<u8>> {"));
+		for (k,v) in self.typmaps.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("if self.{} == {} {{", self.seltypename,v)));
+			rets.push_str(&format_tab_line(tab + 2,&format!("return self.{}.get_raw_asn1();", k)));
+			rets.push_str(&format_tab_line(tab + 1,"}"));
+		}
+		rets.push_str(&format_tab_line(tab+1,"None"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		Ok(rets)
+	}
+
+	fn format_encode_len_write(&self, tab :i32, fname :&str, fproto :&str, fcall :&str) -> Result<String,Box<dyn Error>> {
+		let mut rets :String = "".to_string();
+		let mut idx :i32 = 0;
+		rets.push_str(&format_tab_line(tab,fproto));
+		rets.push_str(&format_tab_line(tab + 1, "let retv :usize;"));
+		rets.push_str(&format_tab_line(tab + 1, " "));
+		for (k,v) in self.typmaps.iter() {
+			if idx == 0 {
+				rets.push_str(&format_tab_line(tab + 1, &format!("if self.{} == {} {{", self.seltypename,v)));	
+			} else {
+				rets.push_str(&format_tab_line(tab + 1, &format!("}} else if self.{} == {} {{", self.seltypename,v)));	
+			}
+			rets.push_str(&format_tab_line(tab + 2,&format!("retv = self.{}.{}({})?;", k,fname,fcall)));
+			idx += 1;
+		}
+		if idx == 0 {
+			asn1_gen_new_error!{ChoiceSynError,"no type insert"}
+		}
+		rets.push_str(&format_tab_line(tab + 1 ,"} else {"));
+		rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not supported type {{}}\", self.{}}}",self.errname,self.seltypename)));
+		rets.push_str(&format_tab_line(tab+1,"}"));
+		rets.push_str(&format_tab_line(tab+1," "));
+		rets.push_str(&format_tab_line(tab+1,"Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		Ok(rets)
+	}
+
+	fn format_encoded_len(&self, tab :i32) -> Result<String,Box<dyn Error>> {
+		return self.format_encode_len_write(tab,"encoded_len","fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {","");
+	}
+
+	fn format_encoded_tag_len(&self, tab :i32) -> Result<String,Box<dyn Error>> {
+		return self.format_encode_len_write(tab,"encoded_tag_len","fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {","");
+	}
+
+	fn format_write_asn1(&self, tab :i32) -> Result<String,Box<dyn Error>> {
+		return self.format_encode_len_write(tab,"write_asn1","fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {","iowriter");
+	}
+
 	fn format_print_asn1(&self, tab :i32) -> Result<String,Box<dyn Error>> {
 		let mut rets :String = "".to_string();
 		let mut idx :i32 = 0;
@@ -783,7 +968,7 @@ impl IntChoiceSyn {
 			asn1_gen_new_error!{ChoiceSynError,"no type insert"}
 		}
 		rets.push_str(&format_tab_line(tab + 1 ,"} else {"));
-		rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{{},\"not supported type {{}}\", self.{}}}",self.errname,self.seltypename)));
+		rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not supported type {{}}\", self.{}}}",self.errname,self.seltypename)));
 		rets.push_str(&format_tab_line(tab+1,"}"));
 		rets.push_str(&format_tab_line(tab+1," "));
 		rets.push_str(&format_tab_line(tab+1,"Ok(())"));
@@ -849,17 +1034,17 @@ impl IntChoiceSyn {
 				rets.push_str(&format_tab_line(tab + 1,&format!("if self.{} == {} {{",self.seltypename,v)));
 			}
 			if self.debugenable {
-				rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.{}.encode_json(\\\"{}\\\",val)\",key);",self.sname,k,jsonk)));
+				rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.{}.encode_json(\\\"{}\\\",val) key [{{}}]\",key);",self.sname,k,jsonk)));
 			}
 			rets.push_str(&format_tab_line(tab + 2, &format!("idx += self.{}.encode_json(\"{}\",&mut mainv)?;",k,jsonk)));	
 			idx += 1;
 		}
 		if idx > 0 {
 			rets.push_str(&format_tab_line(tab + 1, &format!("}} else {{")));
-			rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{{},\"not support {{}} value\",self.{}}}",self.errname,self.seltypename)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support {{}} value\",self.{}}}",self.errname,self.seltypename)));
 			rets.push_str(&format_tab_line(tab + 1, &format!("}}")));	
 		} else {
-			rets.push_str(&format_tab_line(tab + 1, &format!("asn1obj_new_error!{{{},\"not support {{}} value\",self.{}}}",self.errname,self.seltypename)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support {{}} value\",self.{}}}",self.errname,self.seltypename)));
 		}
 		rets.push_str(&format_tab_line(tab + 1, " "));
 		rets.push_str(&format_tab_line(tab + 1, "if key.len() > 0 {"));
@@ -906,12 +1091,12 @@ impl IntChoiceSyn {
 		rets.push_str(&format_tab_line(tab+1,"}"));
 		rets.push_str(&format_tab_line(tab+1," "));
 		rets.push_str(&format_tab_line(tab+1,"if !mainv.is_object() {"));
-		rets.push_str(&format_tab_line(tab+2,&format!("asn1obj_new_error!{{{},\"not object to decode\"}}",self.errname)));
+		rets.push_str(&format_tab_line(tab+2,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not object to decode\"}}",self.errname)));
 		rets.push_str(&format_tab_line(tab+1,"}"));
 		rets.push_str(&format_tab_line(tab+1," "));
 		let jsonk = self._get_json_alias(&self.seltypename);
 		if self.debugenable {
-			rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.decode_json(\\\"{}\\\",val)\",key);",self.sname,jsonk)));
+			rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.decode_json(\\\"{}\\\",val) key [{{}}]\",key);",self.sname,jsonk)));
 		}
 		rets.push_str(&format_tab_line(tab+1,&format!("idx += cint.decode_json(\"{}\",&mainv)?;",jsonk)));
 		rets.push_str(&format_tab_line(tab+1,&format!("self.{} = cint.val as i32;",self.seltypename)));
@@ -925,17 +1110,17 @@ impl IntChoiceSyn {
 				rets.push_str(&format_tab_line(tab + 1,&format!("if self.{} == {} {{",self.seltypename,v)));
 			}
 			if self.debugenable {
-				rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.{}.decode_json(\\\"{}\\\",val)\",key);",self.sname,k,jsonk)));
+				rets.push_str(&format_tab_line(tab + 1,&format!("println!(\"{}.{}.decode_json(\\\"{}\\\",val) key [{{}}]\",key);",self.sname,k,jsonk)));
 			}
 			rets.push_str(&format_tab_line(tab + 2, &format!("idx += self.{}.decode_json(\"{}\",&mainv)?;",k,jsonk)));	
 			idx += 1;
 		}
 		if idx > 0 {
 			rets.push_str(&format_tab_line(tab + 1, &format!("}} else {{")));
-			rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{{},\"not support {{}} value decode\",self.{}}}",self.errname,self.seltypename)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support {{}} value decode\",self.{}}}",self.errname,self.seltypename)));
 			rets.push_str(&format_tab_line(tab + 1, &format!("}}")));	
 		} else {
-			rets.push_str(&format_tab_line(tab + 1, &format!("asn1obj_new_error!{{{},\"not support {{}} value decode\",self.{}}}",self.errname,self.seltypename)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not support {{}} value decode\",self.{}}}",self.errname,self.seltypename)));
 		}
 
 		rets.push_str(&format_tab_line(tab+1," "));
@@ -972,6 +1157,22 @@ impl IntChoiceSyn {
 		rets.push_str(&c);
 		rets.push_str(&format_tab_line(1,""));
 
+		let c = self.format_get_raw_asn1(1)?;
+		rets.push_str(&c);
+		rets.push_str(&format_tab_line(1,""));
+
+		let c = self.format_encoded_len(1)?;
+		rets.push_str(&c);
+		rets.push_str(&format_tab_line(1,""));
+
+		let c = self.format_encoded_tag_len(1)?;
+		rets.push_str(&c);
+		rets.push_str(&format_tab_line(1,""));
+
+		let c = self.format_write_asn1(1)?;
+		rets.push_str(&c);
+		rets.push_str(&format_tab_line(1,""));
+
 		let c = self.format_print_asn1(1)?;
 		rets.push_str(&c);
 
@@ -16,6 +16,7 @@ mod asn1ext;
 mod selector;
 mod choice;
 mod seq;
+mod oid;
 
 
 
@@ -37,10 +38,11 @@ pub fn asn1_obj_selector(_attr :proc_macro::TokenStream,item :proc_macro::TokenS
 ///  example
 /// ```rust
 /// use asn1obj_codegen::{asn1_sequence,asn1_obj_selector,asn1_choice};
-/// use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+/// use asn1obj::{asn1obj_error_class};
 /// use asn1obj::base::*;
 /// use asn1obj::complex::*;
 /// use asn1obj::asn1impl::{Asn1Op,Asn1Selector};
+/// use asn1obj::dump::asn1obj_format_vecs;
 /// use asn1obj::strop::asn1_format_line;
 /// 
 /// use num_bigint::{BigUint};
@@ -91,20 +93,6 @@ pub fn asn1_obj_selector(_attr :proc_macro::TokenStream,item :proc_macro::TokenS
 /// 	pub elem :Asn1Seq<Asn1X509PubkeyElem>,
 /// }
 /// 
-/// fn format_vecs(buf :&[u8], tab :i32) -> String {
-/// 	let mut outs :String = "".to_string();
-/// 	let mut lasti : usize = 0;
-/// 	let mut ki :usize;
-/// 	for i in 0..buf.len() {
-/// 		if (i%16) == 0 {
-/// 			if i > 0 {
-/// 				outs.push_str("    ");
-/// 				while lasti != i {
-/// 					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-/// 						outs.push(buf[lasti] as char);
-/// 					} else {
-/// 						outs.push_str(".");
-/// 					}
 /// 					lasti += 1;
 /// 				}
 /// 				outs.push_str("\n");
@@ -156,7 +144,7 @@ pub fn asn1_obj_selector(_attr :proc_macro::TokenStream,item :proc_macro::TokenS
 /// 	pubelem.rsa.val = pubkey.clone();
 /// 	x509pub.elem.val.push(pubelem);	
 /// 	let outd = x509pub.encode_asn1()?;
-/// 	let outs = format!("output encode data\n{}",format_vecs(&outd,1));
+/// 	let outs = format!("output encode data\n{}",asn1obj_format_vecs(&outd,1));
 /// 	std::io::stdout().write(outs.as_bytes())?;
 /// 	let mut outf  = std::io::stdout();
 /// 	x509pub.print_asn1("X509 Public Key",0,&mut outf)?;
@@ -300,7 +288,7 @@ pub fn asn1_obj_selector(_attr :proc_macro::TokenStream,item :proc_macro::TokenS
 ///         }
 ///         
 ///         if !mainv.is_object() {
-///             asn1obj_new_error!{Asn1RsaPubkeyElemError5ls5zG4eMwGUwrRfuWz9,"not object to decode"}
+///             ::asn1obj::asn1obj_new_kind_error!{Asn1RsaPubkeyElemError5ls5zG4eMwGUwrRfuWz9,InvalidValue,0,"not object to decode"}
 ///         }
 ///         
 ///         idx += self.n.decode_json("n",&mainv)?;
@@ -321,14 +309,14 @@ pub fn asn1_obj_selector(_attr :proc_macro::TokenStream,item :proc_macro::TokenS
 ///         let mut retv :usize = 0;
 ///         let mut _endsize :usize = code.len();
 ///         
-///         let ro = self.n.decode_asn1(&code[retv.._endsize]);
+///         let ro = self.n.decode_asn1_field(&code[0.._endsize],retv,"Asn1RsaPubkeyElem","n");
 ///         if ro.is_err() {
 ///             let e = ro.err().unwrap();
 ///             return Err(e);
 ///         }
 ///         retv += ro.unwrap();
 ///         
-///         let ro = self.e.decode_asn1(&code[retv.._endsize]);
+///         let ro = self.e.decode_asn1_field(&code[0.._endsize],retv,"Asn1RsaPubkeyElem","e");
 ///         if ro.is_err() {
 ///             let e = ro.err().unwrap();
 ///             return Err(e);
@@ -385,10 +373,11 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  exampl
 ///  ```rust
 ///  use asn1obj_codegen::{asn1_sequence,asn1_int_choice};
-///  use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+///  use asn1obj::{asn1obj_error_class};
 ///  use asn1obj::base::*;
 ///  use asn1obj::complex::*;
 ///  use asn1obj::asn1impl::{Asn1Op};
+///  use asn1obj::dump::asn1obj_format_vecs;
 ///  use asn1obj::strop::asn1_format_line;
 ///  
 ///  use std::error::Error;
@@ -421,20 +410,6 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  	pub file :Asn1ImpSet<SpcString,2>,
 ///  }
 ///  
-///  fn format_vecs(buf :&[u8], tab :i32) -> String {
-///  	let mut outs :String = "".to_string();
-///  	let mut lasti : usize = 0;
-///  	let mut ki :usize;
-///  	for i in 0..buf.len() {
-///  		if (i%16) == 0 {
-///  			if i > 0 {
-///  				outs.push_str("    ");
-///  				while lasti != i {
-///  					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-///  						outs.push(buf[lasti] as char);
-///  					} else {
-///  						outs.push_str(".");
-///  					}
 ///  					lasti += 1;
 ///  				}
 ///  				outs.push_str("\n");
@@ -481,7 +456,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  	spl.file.val.push(sps.clone());
 ///  	let outd = spl.encode_asn1()?;
 ///  	let mut outf = std::io::stdout();
-///  	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+///  	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 ///  	outf.write(outs.as_bytes())?;
 ///  	spl.print_asn1("SpcLink",0,&mut outf)?;
 ///  	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -496,7 +471,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  	spl.file.val.push(sps.clone());
 ///  	let outd = spl.encode_asn1()?;
 ///  	let mut outf = std::io::stdout();
-///  	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+///  	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 ///  	outf.write(outs.as_bytes())?;
 ///  	spl.print_asn1("SpcLink",0,&mut outf)?;
 ///  	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -512,7 +487,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  	spl.stype = 1;
 ///  	spl.moniker.val.push(sps.clone());
 ///  	let outd = spl.encode_asn1()?;
-///  	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+///  	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 ///  	outf.write(outs.as_bytes())?;
 ///  	spl.print_asn1("SpcLink",0,&mut outf)?;
 ///  	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -526,7 +501,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///  	spl.stype = 0;
 ///  	spl.url.val.push(sps.clone());
 ///  	let outd = spl.encode_asn1()?;
-///  	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+///  	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 ///  	outf.write(outs.as_bytes())?;
 ///  	spl.print_asn1("SpcLink",0,&mut outf)?;
 ///  	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -638,7 +613,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 1 {
 ///              idx += self.ascii.encode_json("ascii",&mut mainv)?;
 ///          } else {
-///              asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not support {} value",self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,InvalidValue,0,"not support {} value",self.stype}
 ///          }
 ///           
 ///          if key.len() > 0 {
@@ -669,7 +644,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          }
 ///           
 ///          if !mainv.is_object() {
-///              asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not object to decode"}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,InvalidValue,0,"not object to decode"}
 ///          }
 ///           
 ///          idx += cint.decode_json("stype",&mainv)?;
@@ -680,7 +655,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 1 {
 ///              idx += self.ascii.decode_json("ascii",&mainv)?;
 ///          } else {
-///              asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not support {} value decode",self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,InvalidValue,0,"not support {} value decode",self.stype}
 ///          }
 ///           
 ///          return Ok(idx);
@@ -709,7 +684,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///              return Ok(ores.unwrap());
 ///          }
 ///           
-///          asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not supported type"}
+///          ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,UnexpectedTag,0,"not supported type"}
 ///      }
 ///      
 ///      fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
@@ -720,7 +695,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 1 {
 ///              retv = self.ascii.encode_asn1()?;
 ///          } else {
-///              asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not supported type {}", self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,InvalidValue,0,"not supported type {}", self.stype}
 ///          }
 ///           
 ///          Ok(retv)
@@ -737,7 +712,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 1 {
 ///              self.ascii.print_asn1("ascii",tab+1,iowriter)?;
 ///          } else {
-///              asn1obj_new_error!{SpcStringoBxglRxcBmbANpbzError,"not supported type {}", self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcStringoBxglRxcBmbANpbzError,InvalidValue,0,"not supported type {}", self.stype}
 ///          }
 ///           
 ///          Ok(())
@@ -786,7 +761,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          }
 ///          
 ///          if !mainv.is_object() {
-///              asn1obj_new_error!{SpcSerializedObjectErrorVn7V9sV9PRMPpFhGypOd,"not object to decode"}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcSerializedObjectErrorVn7V9sV9PRMPpFhGypOd,InvalidValue,0,"not object to decode"}
 ///          }
 ///          
 ///          idx += self.classid.decode_json("classid",&mainv)?;
@@ -806,14 +781,14 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          let mut retv :usize = 0;
 ///          let mut _endsize :usize = code.len();
 ///          
-///          let ro = self.classid.decode_asn1(&code[retv.._endsize]);
+///          let ro = self.classid.decode_asn1_field(&code[0.._endsize],retv,"SpcSerializedObject","classid");
 ///          if ro.is_err() {
 ///              let e = ro.err().unwrap();
 ///              return Err(e);
 ///          }
 ///          retv += ro.unwrap();
 ///          
-///          let ro = self.serializeddata.decode_asn1(&code[retv.._endsize]);
+///          let ro = self.serializeddata.decode_asn1_field(&code[0.._endsize],retv,"SpcSerializedObject","serializeddata");
 ///          if ro.is_err() {
 ///              let e = ro.err().unwrap();
 ///              return Err(e);
@@ -884,7 +859,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 2 {
 ///              idx += self.file.encode_json("file",&mut mainv)?;
 ///          } else {
-///              asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not support {} value",self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,InvalidValue,0,"not support {} value",self.stype}
 ///          }
 ///           
 ///          if key.len() > 0 {
@@ -916,7 +891,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          }
 ///           
 ///          if !mainv.is_object() {
-///              asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not object to decode"}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,InvalidValue,0,"not object to decode"}
 ///          }
 ///           
 ///          idx += cint.decode_json("stype",&mainv)?;
@@ -929,7 +904,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 2 {
 ///              idx += self.file.decode_json("file",&mainv)?;
 ///          } else {
-///              asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not support {} value decode",self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,InvalidValue,0,"not support {} value decode",self.stype}
 ///          }
 ///           
 ///          return Ok(idx);
@@ -965,7 +940,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///              return Ok(ores.unwrap());
 ///          }
 ///           
-///          asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not supported type"}
+///          ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,UnexpectedTag,0,"not supported type"}
 ///      }
 ///      
 ///      fn encode_asn1(&self) -> Result<Vec<u8>,Box<dyn Error>> {
@@ -978,7 +953,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 2 {
 ///              retv = self.file.encode_asn1()?;
 ///          } else {
-///              asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not supported type {}", self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,InvalidValue,0,"not supported type {}", self.stype}
 ///          }
 ///           
 ///          Ok(retv)
@@ -997,7 +972,7 @@ pub fn asn1_choice(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream)
 ///          } else if self.stype == 2 {
 ///              self.file.print_asn1("file",tab+1,iowriter)?;
 ///          } else {
-///              asn1obj_new_error!{SpcLinkfsdJjYNtcxy2KBuyError,"not supported type {}", self.stype}
+///              ::asn1obj::asn1obj_new_kind_error!{SpcLinkfsdJjYNtcxy2KBuyError,InvalidValue,0,"not supported type {}", self.stype}
 ///          }
 ///           
 ///          Ok(())
@@ -1014,10 +989,11 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 ///  example
 /// ```rust
 /// use asn1obj_codegen::{asn1_sequence,asn1_ext};
-/// use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+/// use asn1obj::{asn1obj_error_class};
 /// use asn1obj::base::*;
 /// use asn1obj::complex::*;
 /// use asn1obj::asn1impl::Asn1Op;
+/// use asn1obj::dump::asn1obj_format_vecs;
 ///  use asn1obj::strop::asn1_format_line;
 /// 
 /// use num_bigint::{BigUint};
@@ -1045,20 +1021,6 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 /// 	pub elem :Asn1Seq<Asn1RsaPubkeyElem>,
 /// }
 /// 
-/// fn format_vecs(buf :&[u8], tab :i32) -> String {
-/// 	let mut outs :String = "".to_string();
-/// 	let mut lasti : usize = 0;
-/// 	let mut ki :usize;
-/// 	for i in 0..buf.len() {
-/// 		if (i%16) == 0 {
-/// 			if i > 0 {
-/// 				outs.push_str("    ");
-/// 				while lasti != i {
-/// 					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-/// 						outs.push(buf[lasti] as char);
-/// 					} else {
-/// 						outs.push_str(".");
-/// 					}
 /// 					lasti += 1;
 /// 				}
 /// 				outs.push_str("\n");
@@ -1106,7 +1068,7 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 /// 	pubkeyelem.e.val = BigUint::from_bytes_be(&ne);
 /// 	pubkey.elem.val.push(pubkeyelem);
 /// 	let outd = pubkey.encode_asn1()?;
-/// 	let s = format_vecs(&outd,1);
+/// 	let s = asn1obj_format_vecs(&outd,1);
 /// 	let mut outs :String = "".to_string();
 /// 	let mut outf = std::io::stdout();
 /// 	outs.push_str("outs\n");
@@ -1223,7 +1185,7 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 ///         }
 ///         
 ///         if !mainv.is_object() {
-///             asn1obj_new_error!{Asn1RsaPubkeyElemErrorCDrzpsmb4YFcGAwXvxP4,"not object to decode"}
+///             ::asn1obj::asn1obj_new_kind_error!{Asn1RsaPubkeyElemErrorCDrzpsmb4YFcGAwXvxP4,InvalidValue,0,"not object to decode"}
 ///         }
 ///         
 ///         idx += self.n.decode_json("n",&mainv)?;
@@ -1244,14 +1206,14 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 ///         let mut retv :usize = 0;
 ///         let mut _endsize :usize = code.len();
 ///         
-///         let ro = self.n.decode_asn1(&code[retv.._endsize]);
+///         let ro = self.n.decode_asn1_field(&code[0.._endsize],retv,"Asn1RsaPubkeyElem","n");
 ///         if ro.is_err() {
 ///             let e = ro.err().unwrap();
 ///             return Err(e);
 ///         }
 ///         retv += ro.unwrap();
 ///         
-///         let ro = self.e.decode_asn1(&code[retv.._endsize]);
+///         let ro = self.e.decode_asn1_field(&code[0.._endsize],retv,"Asn1RsaPubkeyElem","e");
 ///         if ro.is_err() {
 ///             let e = ro.err().unwrap();
 ///             return Err(e);
@@ -1323,7 +1285,7 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 ///         let mut retv :usize = 0;
 ///         let mut _endsize :usize = code.len();
 ///         
-///         let ro = self.elem.decode_asn1(&code[retv.._endsize]);
+///         let ro = self.elem.decode_asn1_field(&code[0.._endsize],retv,"Asn1RsaPubkey","elem");
 ///         if ro.is_err() {
 ///             let e = ro.err().unwrap();
 ///             return Err(e);
@@ -1357,4 +1319,26 @@ pub fn asn1_int_choice(_attr :proc_macro::TokenStream, item :proc_macro::TokenSt
 #[proc_macro_attribute]
 pub fn asn1_sequence(_attr :proc_macro::TokenStream,item :proc_macro::TokenStream) -> proc_macro::TokenStream {
 	return seq::asn1_sequence(_attr,item);
-}
\ No newline at end of file
+}
+
+///  macro to check and encode the object identifier at compile time
+///  it gives Asn1StaticOid, to compare with Asn1Object without parse
+///  example
+/// ```ignore
+/// use asn1obj_codegen::{oid};
+/// use asn1obj::base::*;
+/// use asn1obj::asn1impl::{Asn1Op};
+///
+/// const RSA_ENCRYPTION :Asn1StaticOid = oid!("1.2.840.113549.1.1.1");
+///
+/// fn main() {
+///     let mut o :Asn1Object = Asn1Object::init_asn1();
+///     o.set_value("1.2.840.113549.1.1.1").unwrap();
+///     assert!(o == RSA_ENCRYPTION);
+///     assert!(RSA_ENCRYPTION.get_content() == &[0x2a,0x86,0x48,0x86,0xf7,0x0d,0x01,0x01,0x01]);
+/// }
+/// ```
+#[proc_macro]
+pub fn oid(input :proc_macro::TokenStream) -> proc_macro::TokenStream {
+	return oid::oid(input);
+}
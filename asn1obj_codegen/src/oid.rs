@@ -0,0 +1,66 @@
+
+use quote::{quote};
+
+/*
+    the same rules as Asn1Object::set_value, and the arcs must be in u64,
+    return the content octets of the OBJECT IDENTIFIER
+*/
+fn encode_oid(val :&str) -> Result<Vec<u8>,String> {
+	let mut retv :Vec<u8> = Vec::new();
+	let mut arcs :Vec<u64> = Vec::new();
+	for s in val.split(".") {
+		if s.len() == 0 || !s.chars().all(|c| c.is_ascii_digit()) {
+			return Err(format!("[{}] not valid oid at arc [{}]",val,s));
+		}
+		match u64::from_str_radix(s,10) {
+			Ok(cn) => {
+				arcs.push(cn);
+			},
+			Err(_e) => {
+				return Err(format!("[{}] arc [{}] not in u64",val,s));
+			}
+		}
+	}
+	if arcs.len() < 2 {
+		return Err(format!("[{}] need at least 2 arcs",val));
+	}
+	if arcs[0] != 1 && arcs[0] != 2 {
+		return Err(format!("[{}] must start 1. or 2.",val));
+	}
+	if arcs[0] == 1 && arcs[1] >= 40 {
+		return Err(format!("[{}] second arc [{}] >= 40 under 1",val,arcs[1]));
+	}
+	let first :u128 = (arcs[0] as u128) * 40 + (arcs[1] as u128);
+	let mut subids :Vec<u128> = vec![first];
+	for a in arcs[2..].iter() {
+		subids.push(*a as u128);
+	}
+	for cn in subids.iter() {
+		let mut maxidx :usize = 1;
+		while (*cn >> (maxidx * 7)) != 0 {
+			maxidx += 1;
+		}
+		while maxidx > 1 {
+			retv.push((((*cn >> ((maxidx - 1) * 7)) & 0x7f) as u8) | 0x80);
+			maxidx -= 1;
+		}
+		retv.push((*cn & 0x7f) as u8);
+	}
+	Ok(retv)
+}
+
+pub fn oid(input :proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let lit = syn::parse_macro_input!(input as syn::LitStr);
+	let val :String = lit.value();
+	match encode_oid(&val) {
+		Ok(code) => {
+			let toks = quote! {
+				::asn1obj::base::Asn1StaticOid::from_encoded(#val,&[#(#code),*])
+			};
+			return toks.into();
+		},
+		Err(e) => {
+			return syn::Error::new(lit.span(),&e).to_compile_error().into();
+		}
+	}
+}
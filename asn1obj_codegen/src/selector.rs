@@ -152,7 +152,7 @@ impl ObjSelectorSyn {
 				rets.push_str(&format_tab_line(tab + 1, &format!("_outs = format!(\"decode {}.{} will decode at {{}}\\n\",retv);",self.sname,k)));
 				rets.push_str(&format_tab_line(tab + 1, "let _ = _outf.write(_outs.as_bytes())?;"));
 			}
-			rets.push_str(&format_tab_line(tab + 1, &format!("let ro = self.{}.decode_asn1(&code[retv.._endsize]);",k)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("let ro = self.{}.decode_asn1_field(&code[0.._endsize],retv,\"{}\",\"{}\");",k,self.sname,k)));
 			rets.push_str(&format_tab_line(tab + 1, "if ro.is_err() {"));
 			rets.push_str(&format_tab_line(tab + 2, &format!("let e = ro.err().unwrap();")));
 			if self.debugenable {
@@ -257,6 +257,72 @@ impl ObjSelectorSyn {
 		return rets;
 	}
 
+	fn format_get_raw_asn1(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn get_raw_asn1(&self) -> Option<Vec<u8>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :Vec<u8> = Vec::new();"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :Vec<u8> = Vec::new();"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("let ores = self.{}.get_raw_asn1();",k)));
+			rets.push_str(&format_tab_line(tab + 1, "if ores.is_none() {"));
+			rets.push_str(&format_tab_line(tab + 2, "return None;"));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+			rets.push_str(&format_tab_line(tab + 1, "retv.extend_from_slice(&ores.unwrap());"));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Some(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encoded_len(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :usize = 0;"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :usize = 0;"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("retv += self.{}.encoded_len()?;",k)));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encoded_tag_len(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {"));
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("if self.{}.encoded_len()? > 0 {{",k)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("return self.{}.encoded_tag_len();",k)));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(0)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_write_asn1(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :usize = 0;"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :usize = 0;"));
+			rets.push_str(&format_tab_line(tab + 1, "let _ = iowriter;"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("retv += self.{}.write_asn1(iowriter)?;",k)));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
 	fn format_print_asn1(&self, tab :i32) -> String {
 		let mut rets :String = "".to_string();
 		rets.push_str(&format_tab_line(tab , "fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {"));
@@ -344,7 +410,7 @@ impl ObjSelectorSyn {
 
 			rets.push_str(&format_tab_line(tab + 1, " "));
 			rets.push_str(&format_tab_line(tab + 1,"if !mainv.is_object() {"));
-			rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{{},\"[{{}}] not valid object\",key}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"[{{}}] not valid object\",key}}",self.errname)));
 			rets.push_str(&format_tab_line(tab + 1,"}"));
 
 			rets.push_str(&format_tab_line(tab + 1, " "));
@@ -423,7 +489,7 @@ impl ObjSelectorSyn {
 		if sidx > 0 {
 			rets.push_str(&format_tab_line(tab + 1, "} else {"));
 			if self.defname.len() == 0 {
-				rets.push_str(&format_tab_line(tab + 2,&format!("asn1obj_new_error!{{ {} , \"not support [{{}}]\",k}}",self.errname)));
+				rets.push_str(&format_tab_line(tab + 2,&format!("::asn1obj::asn1obj_new_kind_error!{{ {} ,InvalidValue,0, \"not support [{{}}]\",k}}",self.errname)));
 			} else {
 				rets.push_str(&format_tab_line(tab + 2,&(format!("retv = format!(\"{}\");",self.defname))));
 			}
@@ -431,7 +497,7 @@ impl ObjSelectorSyn {
 
 		} else {
 			if self.defname.len() == 0 {
-				rets.push_str(&format_tab_line(tab + 1,&format!("asn1obj_new_error!{{ {} , \"not support [{{}}]\",k}}",self.errname)));	
+				rets.push_str(&format_tab_line(tab + 1,&format!("::asn1obj::asn1obj_new_kind_error!{{ {} ,InvalidValue,0, \"not support [{{}}]\",k}}",self.errname)));	
 			} else {
 				rets.push_str(&format_tab_line(tab + 2,&(format!("retv = format!(\"{}\");",self.defname))));
 			}
@@ -504,6 +570,14 @@ impl ObjSelectorSyn {
 		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&self.format_encode_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_get_raw_asn1(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_encoded_len(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_encoded_tag_len(1));
+		rets.push_str(&format_tab_line(1,""));
+		rets.push_str(&self.format_write_asn1(1));
+		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&self.format_print_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&format_tab_line(0,"}"));
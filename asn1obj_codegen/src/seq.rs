@@ -16,6 +16,7 @@ asn1_gen_error_class!{SequenceSynError}
 struct SequenceSyn {
 	debugenable : bool,
 	sname :String,
+	lifetime :String,
 	errname :String,
 	omitnames :Vec<String>,
 	parsenames :Vec<String>,
@@ -36,6 +37,7 @@ impl SequenceSyn {
 		SequenceSyn{
 			debugenable : dbgval,
 			sname : "".to_string(),
+			lifetime : "".to_string(),
 			errname : "".to_string(),
 			omitnames :Vec::new(),
 			parsenames : Vec::new(),
@@ -51,6 +53,11 @@ impl SequenceSyn {
 		return;
 	}
 
+	pub fn set_lifetime(&mut self, n :&str) {
+		self.lifetime = format!("{}",n);
+		return;
+	}
+
 	pub fn set_attr(&mut self, k :&str, v :&str) -> Result<(),Box<dyn Error>> {
 		if k == "debug" && (v == "enable" || v == "disable") {
 			if v == "enable" {
@@ -110,7 +117,11 @@ impl SequenceSyn {
 
 	fn format_decode_asn1(&self,tab :i32) -> String {
 		let mut rets :String = "".to_string();
-		rets.push_str(&format_tab_line(tab , "fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {"));
+		if self.lifetime.len() > 0 {
+			rets.push_str(&format_tab_line(tab , &format!("fn decode_asn1(&mut self, code :&{} [u8]) -> Result<usize,Box<dyn Error>> {{",self.lifetime)));
+		} else {
+			rets.push_str(&format_tab_line(tab , "fn decode_asn1(&mut self, code :&[u8]) -> Result<usize,Box<dyn Error>> {"));
+		}
 		rets.push_str(&format_tab_line(tab + 1, "let mut retv :usize = 0;"));
 		rets.push_str(&format_tab_line(tab + 1, "let mut _endsize :usize = code.len();"));
 		if self.debugenable {
@@ -129,7 +140,7 @@ impl SequenceSyn {
 				rets.push_str(&format_tab_line(tab + 1, &format!("_outs = format!(\"decode {}.{} will decode at {{}}\\n\",retv);",self.sname,k)));
 				rets.push_str(&format_tab_line(tab + 1, "let _ = _outf.write(_outs.as_bytes())?;"));
 			}
-			rets.push_str(&format_tab_line(tab + 1, &format!("let ro = self.{}.decode_asn1(&code[retv.._endsize]);",k)));
+			rets.push_str(&format_tab_line(tab + 1, &format!("let ro = self.{}.decode_asn1_field(&code[0.._endsize],retv,\"{}\",\"{}\");",k,self.sname,k)));
 			rets.push_str(&format_tab_line(tab + 1, "if ro.is_err() {"));
 			rets.push_str(&format_tab_line(tab + 2, &format!("let e = ro.err().unwrap();")));
 			if self.debugenable {
@@ -268,6 +279,72 @@ impl SequenceSyn {
 		return rets;
 	}
 
+	fn format_get_raw_asn1(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn get_raw_asn1(&self) -> Option<Vec<u8>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :Vec<u8> = Vec::new();"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :Vec<u8> = Vec::new();"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("let ores = self.{}.get_raw_asn1();",k)));
+			rets.push_str(&format_tab_line(tab + 1, "if ores.is_none() {"));
+			rets.push_str(&format_tab_line(tab + 2, "return None;"));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+			rets.push_str(&format_tab_line(tab + 1, "retv.extend_from_slice(&ores.unwrap());"));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Some(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encoded_len(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn encoded_len(&self) -> Result<usize,Box<dyn Error>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :usize = 0;"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :usize = 0;"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("retv += self.{}.encoded_len()?;",k)));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_encoded_tag_len(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn encoded_tag_len(&self) -> Result<usize,Box<dyn Error>> {"));
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("if self.{}.encoded_len()? > 0 {{",k)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("return self.{}.encoded_tag_len();",k)));
+			rets.push_str(&format_tab_line(tab + 1, "}"));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(0)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
+	fn format_write_asn1(&self,tab :i32) -> String {
+		let mut rets :String = "".to_string();
+		rets.push_str(&format_tab_line(tab , "fn write_asn1<W :Write>(&self, iowriter :&mut W) -> Result<usize,Box<dyn Error>> {"));
+		if self.parsenames.len() > 0 {
+			rets.push_str(&format_tab_line(tab + 1, "let mut retv :usize = 0;"));
+		} else {
+			rets.push_str(&format_tab_line(tab + 1, "let retv :usize = 0;"));
+			rets.push_str(&format_tab_line(tab + 1, "let _ = iowriter;"));
+		}
+		for k in self.parsenames.iter() {
+			rets.push_str(&format_tab_line(tab + 1, &format!("retv += self.{}.write_asn1(iowriter)?;",k)));
+		}
+		rets.push_str(&format_tab_line(tab + 1, "Ok(retv)"));
+		rets.push_str(&format_tab_line(tab,"}"));
+		return rets;
+	}
+
 	fn format_print_asn1(&self,tab :i32) -> String {
 		let mut rets :String = "".to_string();
 		rets.push_str(&format_tab_line(tab , "fn print_asn1<U :Write>(&self,name :&str,tab :i32, iowriter :&mut U) -> Result<(),Box<dyn Error>> {"));
@@ -391,7 +468,7 @@ impl SequenceSyn {
 			rets.push_str(&format_tab_line(tab + 1, "}"));
 			rets.push_str(&format_tab_line(tab + 1, ""));
 			rets.push_str(&format_tab_line(tab + 1, "if !mainv.is_object() {"));
-			rets.push_str(&format_tab_line(tab + 2, &format!("asn1obj_new_error!{{{},\"not object to decode\"}}",self.errname)));
+			rets.push_str(&format_tab_line(tab + 2, &format!("::asn1obj::asn1obj_new_kind_error!{{{},InvalidValue,0,\"not object to decode\"}}",self.errname)));
 			rets.push_str(&format_tab_line(tab + 1, "}"));
 			rets.push_str(&format_tab_line(tab + 1, ""));
 			for k in self.parsenames.iter() {
@@ -422,18 +499,36 @@ impl SequenceSyn {
 			rets.push_str(&format_tab_line(0,""));
 		}
 
-		rets.push_str(&format_tab_line(0,&format!("impl Asn1Op for {} {{", self.sname)));
-		rets.push_str(&format_tab_line(1,""));
-		rets.push_str(&self.format_encode_json(1));
-		rets.push_str(&format_tab_line(1,""));
-		rets.push_str(&self.format_decode_json(1));
-		rets.push_str(&format_tab_line(1,""));
+		if self.lifetime.len() > 0 {
+			/*borrowed struct only to implement Asn1RefOp*/
+			rets.push_str(&format_tab_line(0,&format!("impl<{}> Asn1RefOp<{}> for {}<{}> {{", self.lifetime,self.lifetime,self.sname,self.lifetime)));
+			rets.push_str(&format_tab_line(1,""));
+		} else {
+			rets.push_str(&format_tab_line(0,&format!("impl Asn1Op for {} {{", self.sname)));
+			rets.push_str(&format_tab_line(1,""));
+			rets.push_str(&self.format_encode_json(1));
+			rets.push_str(&format_tab_line(1,""));
+			rets.push_str(&self.format_decode_json(1));
+			rets.push_str(&format_tab_line(1,""));
+		}
 		rets.push_str(&self.format_init_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&self.format_decode_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&self.format_encode_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
+		if self.lifetime.len() == 0 {
+			rets.push_str(&self.format_get_raw_asn1(1));
+			rets.push_str(&format_tab_line(1,""));
+		}
+		if self.lifetime.len() == 0 {
+			rets.push_str(&self.format_encoded_len(1));
+			rets.push_str(&format_tab_line(1,""));
+			rets.push_str(&self.format_encoded_tag_len(1));
+			rets.push_str(&format_tab_line(1,""));
+			rets.push_str(&self.format_write_asn1(1));
+			rets.push_str(&format_tab_line(1,""));
+		}
 		rets.push_str(&self.format_print_asn1(1));
 		rets.push_str(&format_tab_line(1,""));
 		rets.push_str(&format_tab_line(0,"}"));
@@ -520,6 +615,14 @@ pub fn asn1_sequence(_attr :proc_macro::TokenStream,item :proc_macro::TokenStrea
 	sname = format!("{}",co.ident);
 	//asn1_gen_log_trace!("sname [{}]",sname);
 	cs.set_struct_name(&sname);
+	{
+		let lts :Vec<&syn::LifetimeDef> = co.generics.lifetimes().collect();
+		if lts.len() > 1 {
+			asn1_syn_error_fmt!("only accept one lifetime in\n{}",item.to_string());
+		} else if lts.len() == 1 {
+			cs.set_lifetime(&format!("{}",lts[0].lifetime));
+		}
+	}
 
 	for a in &co.attrs {
 		asn1_gen_log_trace!("path [{}]",a.path.get_ident().unwrap().to_string());
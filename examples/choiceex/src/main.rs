@@ -1,8 +1,9 @@
 use asn1obj_codegen::{asn1_sequence,asn1_obj_selector,asn1_choice};
-use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+use asn1obj::{asn1obj_error_class};
 use asn1obj::base::*;
 use asn1obj::complex::*;
 use asn1obj::asn1impl::{Asn1Op,Asn1Selector};
+use asn1obj::dump::asn1obj_format_vecs;
 use asn1obj::strop::asn1_format_line;
 
 use num_bigint::{BigUint};
@@ -59,57 +60,6 @@ pub struct Asn1X509Pubkey {
 	pub elem :Asn1Seq<Asn1X509PubkeyElem>,
 }
 
-fn format_vecs(buf :&[u8], tab :i32) -> String {
-	let mut outs :String = "".to_string();
-	let mut lasti : usize = 0;
-	let mut ki :usize;
-	for i in 0..buf.len() {
-		if (i%16) == 0 {
-			if i > 0 {
-				outs.push_str("    ");
-				while lasti != i {
-					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-						outs.push(buf[lasti] as char);
-					} else {
-						outs.push_str(".");
-					}
-					lasti += 1;
-				}
-				outs.push_str("\n");
-			}
-
-			for _j in 0..tab {
-				outs.push_str("    ");
-			}
-		}
-		if (i % 16) == 0 {
-			outs.push_str(&format!("{:02x}", buf[i]));	
-		} else {
-			outs.push_str(&format!(":{:02x}", buf[i]));	
-		}
-		
-	}
-
-	if lasti != buf.len() {
-		ki = buf.len();
-		while (ki % 16) != 0 {
-			outs.push_str("   ");
-			ki += 1;
-		}
-		outs.push_str("    ");
-		while lasti != buf.len() {
-			if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-				outs.push(buf[lasti] as char);
-			} else {
-				outs.push_str(".");
-			}
-			lasti += 1;
-		}
-	}
-	outs.push_str("\n");
-	return outs;
-}
-
 fn main() -> Result<(),Box<dyn Error>> {
 	let mut pubkey :Asn1RsaPubkey = Asn1RsaPubkey::init_asn1();
 	let mut pubkeyelem :Asn1RsaPubkeyElem = Asn1RsaPubkeyElem::init_asn1();
@@ -124,7 +74,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	pubelem.rsa.val = pubkey.clone();
 	x509pub.elem.val.push(pubelem);	
 	let outd = x509pub.encode_asn1()?;
-	let outs = format!("output encode data\n{}",format_vecs(&outd,1));
+	let outs = format!("output encode data\n{}",asn1obj_format_vecs(&outd,1));
 	std::io::stdout().write(outs.as_bytes())?;
 	let mut outf  = std::io::stdout();
 	x509pub.print_asn1("X509 Public Key",0,&mut outf)?;
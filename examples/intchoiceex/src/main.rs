@@ -1,8 +1,9 @@
 use asn1obj_codegen::{asn1_sequence,asn1_int_choice};
-use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+use asn1obj::{asn1obj_error_class};
 use asn1obj::base::*;
 use asn1obj::complex::*;
 use asn1obj::asn1impl::{Asn1Op};
+use asn1obj::dump::asn1obj_format_vecs;
 use asn1obj::strop::asn1_format_line;
 
 use std::error::Error;
@@ -40,57 +41,6 @@ pub struct SpcLink {
 	pub file :Asn1ImpSet<SpcString,2>,
 }
 
-fn format_vecs(buf :&[u8], tab :i32) -> String {
-	let mut outs :String = "".to_string();
-	let mut lasti : usize = 0;
-	let mut ki :usize;
-	for i in 0..buf.len() {
-		if (i%16) == 0 {
-			if i > 0 {
-				outs.push_str("    ");
-				while lasti != i {
-					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-						outs.push(buf[lasti] as char);
-					} else {
-						outs.push_str(".");
-					}
-					lasti += 1;
-				}
-				outs.push_str("\n");
-			}
-
-			for _j in 0..tab {
-				outs.push_str("    ");
-			}
-		}
-		if (i % 16) == 0 {
-			outs.push_str(&format!("{:02x}", buf[i]));	
-		} else {
-			outs.push_str(&format!(":{:02x}", buf[i]));	
-		}
-		
-	}
-
-	if lasti != buf.len() {
-		ki = buf.len();
-		while (ki % 16) != 0 {
-			outs.push_str("   ");
-			ki += 1;
-		}
-		outs.push_str("    ");
-		while lasti != buf.len() {
-			if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-				outs.push(buf[lasti] as char);
-			} else {
-				outs.push_str(".");
-			}
-			lasti += 1;
-		}
-	}
-	outs.push_str("\n");
-	return outs;
-}
-
 fn main() -> Result<(),Box<dyn Error>> {
 	let mut sps :SpcString = SpcString::init_asn1();
 	sps.stype = 0;
@@ -100,7 +50,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	spl.file.val.push(sps.clone());
 	let outd = spl.encode_asn1()?;
 	let mut outf = std::io::stdout();
-	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 	outf.write(outs.as_bytes())?;
 	spl.print_asn1("SpcLink",0,&mut outf)?;
 	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -115,7 +65,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	spl.file.val.push(sps.clone());
 	let outd = spl.encode_asn1()?;
 	let mut outf = std::io::stdout();
-	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 	outf.write(outs.as_bytes())?;
 	spl.print_asn1("SpcLink",0,&mut outf)?;
 	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -131,7 +81,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	spl.stype = 1;
 	spl.moniker.val.push(sps.clone());
 	let outd = spl.encode_asn1()?;
-	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 	outf.write(outs.as_bytes())?;
 	spl.print_asn1("SpcLink",0,&mut outf)?;
 	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -145,7 +95,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	spl.stype = 0;
 	spl.url.val.push(sps.clone());
 	let outd = spl.encode_asn1()?;
-	let outs = format!("outdata\n{}",format_vecs(&outd,1));
+	let outs = format!("outdata\n{}",asn1obj_format_vecs(&outd,1));
 	outf.write(outs.as_bytes())?;
 	spl.print_asn1("SpcLink",0,&mut outf)?;
 	let mut outspl :SpcLink = SpcLink::init_asn1();
@@ -1,8 +1,9 @@
 use asn1obj_codegen::{asn1_sequence};
-use asn1obj::{asn1obj_error_class,asn1obj_new_error};
+use asn1obj::{asn1obj_error_class};
 use asn1obj::base::*;
 use asn1obj::complex::*;
 use asn1obj::asn1impl::Asn1Op;
+use asn1obj::dump::asn1obj_format_vecs;
  use asn1obj::strop::asn1_format_line;
 
 use num_bigint::{BigUint};
@@ -30,57 +31,6 @@ pub struct Asn1RsaPubkey {
 	pub elem :Asn1Seq<Asn1RsaPubkeyElem>,
 }
 
-fn format_vecs(buf :&[u8], tab :i32) -> String {
-	let mut outs :String = "".to_string();
-	let mut lasti : usize = 0;
-	let mut ki :usize;
-	for i in 0..buf.len() {
-		if (i%16) == 0 {
-			if i > 0 {
-				outs.push_str("    ");
-				while lasti != i {
-					if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-						outs.push(buf[lasti] as char);
-					} else {
-						outs.push_str(".");
-					}
-					lasti += 1;
-				}
-				outs.push_str("\n");
-			}
-
-			for _j in 0..tab {
-				outs.push_str("    ");
-			}
-		}
-		if (i % 16) == 0 {
-			outs.push_str(&format!("{:02x}", buf[i]));	
-		} else {
-			outs.push_str(&format!(":{:02x}", buf[i]));	
-		}
-		
-	}
-
-	if lasti != buf.len() {
-		ki = buf.len();
-		while (ki % 16) != 0 {
-			outs.push_str("   ");
-			ki += 1;
-		}
-		outs.push_str("    ");
-		while lasti != buf.len() {
-			if buf[lasti] >= 0x20 && buf[lasti] <= 0x7e {
-				outs.push(buf[lasti] as char);
-			} else {
-				outs.push_str(".");
-			}
-			lasti += 1;
-		}
-	}
-	outs.push_str("\n");
-	return outs;
-}
-
 
 fn main() -> Result<(),Box<dyn Error>> {
 	let mut pubkey :Asn1RsaPubkey = Asn1RsaPubkey::init_asn1();
@@ -91,7 +41,7 @@ fn main() -> Result<(),Box<dyn Error>> {
 	pubkeyelem.e.val = BigUint::from_bytes_be(&ne);
 	pubkey.elem.val.push(pubkeyelem);
 	let outd = pubkey.encode_asn1()?;
-	let s = format_vecs(&outd,1);
+	let s = asn1obj_format_vecs(&outd,1);
 	let mut outs :String = "".to_string();
 	let mut outf = std::io::stdout();
 	outs.push_str("outs\n");